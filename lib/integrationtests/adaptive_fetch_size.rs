@@ -0,0 +1,49 @@
+use futures::TryStreamExt as _;
+use neo4rs::*;
+
+mod container;
+
+#[tokio::test]
+async fn adaptive_fetch_size() {
+    let config = ConfigBuilder::default().fetch_size(1);
+    let neo4j = container::Neo4jContainer::from_config(config).await;
+    let graph = neo4j.graph();
+
+    let qry = "UNWIND range(1, 1000) AS x RETURN x";
+
+    let rows = graph
+        .execute(query(qry).fetch_size(100))
+        .await
+        .unwrap()
+        .column_into_stream::<i64>("x")
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(rows, (1..=1000).collect::<Vec<_>>());
+
+    let adaptive = AdaptiveFetchSize::new(64 * 1024).with_min(10).with_max(500);
+    let rows = graph
+        .execute(query(qry).adaptive_fetch_size(adaptive))
+        .await
+        .unwrap()
+        .column_into_stream::<i64>("x")
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(rows, (1..=1000).collect::<Vec<_>>());
+
+    let large = "UNWIND range(1, 20) AS x RETURN x, reduce(s = '', i IN range(1, 10000) | s + 'x') AS s";
+    let mut txn = graph.start_txn().await.unwrap();
+    let mut stream = txn
+        .execute(query(large).fetch_size(20))
+        .await
+        .unwrap()
+        .with_adaptive_fetch_size(AdaptiveFetchSize::new(32 * 1024));
+    let mut count = 0;
+    while let Some(row) = stream.next(txn.handle()).await.unwrap() {
+        assert_eq!(row.get::<String>("s").unwrap().len(), 10000);
+        count += 1;
+    }
+    assert_eq!(count, 20);
+    txn.commit().await.unwrap();
+}
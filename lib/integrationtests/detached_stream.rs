@@ -0,0 +1,59 @@
+use futures::{Stream, StreamExt as _, TryStreamExt as _};
+use neo4rs::*;
+
+mod container;
+
+fn numbers(graph: &Graph) -> impl std::future::Future<Output = Result<DetachedRowStream>> + '_ {
+    graph.execute(query("UNWIND range(1, 10) AS x RETURN x"))
+}
+
+async fn typed_numbers(graph: Graph) -> impl Stream<Item = Result<i64>> + Send + 'static {
+    numbers(&graph).await.unwrap().into_typed::<i64>()
+}
+
+#[tokio::test]
+async fn detached_stream() {
+    let config = ConfigBuilder::default().fetch_size(3).max_connections(1);
+    let neo4j = container::Neo4jContainer::from_config(config).await;
+    let graph = neo4j.graph();
+
+    // the stream owns its connection and can be consumed by value
+    let rows = numbers(&graph)
+        .await
+        .unwrap()
+        .map_ok(|row| row.get::<i64>("x").unwrap())
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(rows, (1..=10).collect::<Vec<_>>());
+
+    let rows = typed_numbers(graph.clone())
+        .await
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(rows, (1..=10).collect::<Vec<_>>());
+
+    // dropping a stream part-way releases the connection so that it can be reused
+    let mut stream = numbers(&graph).await.unwrap();
+    assert_eq!(
+        stream.next().await.unwrap().unwrap().get::<i64>("x").unwrap(),
+        1
+    );
+    drop(stream);
+
+    let mut stream = typed_numbers(graph.clone()).await.boxed();
+    assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+    drop(stream);
+
+    let count = graph
+        .execute(query("RETURN 42 AS x"))
+        .await
+        .unwrap()
+        .single()
+        .await
+        .unwrap()
+        .get::<i64>("x")
+        .unwrap();
+    assert_eq!(count, 42);
+}
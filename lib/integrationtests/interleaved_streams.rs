@@ -0,0 +1,48 @@
+use neo4rs::*;
+
+mod container;
+
+#[tokio::test]
+async fn interleaved_streams() {
+    let config = ConfigBuilder::default().fetch_size(2).max_connections(2);
+    let neo4j = container::Neo4jContainer::from_config(config).await;
+    let graph = neo4j.graph();
+
+    let qry = "UNWIND range(1, 5) AS x RETURN x";
+
+    let mut txn = graph.start_txn().await.unwrap();
+
+    // the next batch of stream_one is requested right away
+    let mut stream_one = txn.execute(qry).await.unwrap().with_prefetch(2);
+    assert_eq!(stream_one.next_as::<i64>(txn.handle()).await.unwrap(), 1);
+
+    // the prefetched batch of stream_one is received before stream_two is executed
+    let mut stream_two = txn.execute(qry).await.unwrap().with_prefetch(2);
+    assert_eq!(stream_two.next_as::<i64>(txn.handle()).await.unwrap(), 1);
+
+    // and before stream_two is consumed further
+    let mut two = vec![1];
+    while let Some(row) = stream_two.next(txn.handle()).await.unwrap() {
+        two.push(row.to::<i64>().unwrap());
+    }
+    assert_eq!(two, [1, 2, 3, 4, 5]);
+
+    let mut one = vec![1];
+    while let Some(row) = stream_one.next(txn.handle()).await.unwrap() {
+        one.push(row.to::<i64>().unwrap());
+    }
+    assert_eq!(one, [1, 2, 3, 4, 5]);
+
+    // a stream can only be consumed within its own transaction
+    let mut stream_three = txn.execute(qry).await.unwrap();
+    let mut other = graph.start_txn().await.unwrap();
+    let _ = stream_three.next(txn.handle()).await.unwrap();
+    let _ = stream_three.next(txn.handle()).await.unwrap();
+    assert!(matches!(
+        stream_three.next(other.handle()).await,
+        Err(Error::TransactionMismatch)
+    ));
+    other.rollback().await.unwrap();
+
+    txn.commit().await.unwrap();
+}
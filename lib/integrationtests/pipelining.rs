@@ -0,0 +1,43 @@
+use neo4rs::*;
+
+mod container;
+
+#[tokio::test]
+async fn pipelining() {
+    let config = ConfigBuilder::default().max_connections(1);
+    let neo4j = container::Neo4jContainer::from_config(config).await;
+    let graph = neo4j.graph();
+
+    // a failed RUN skips the responses of the pipelined PULL
+    assert!(graph.execute(query("RETURN syntax error")).await.is_err());
+    assert!(graph.run(query("RETURN syntax error")).await.is_err());
+
+    // BEGIN, RUN, and PULL are sent together, the error of the BEGIN is returned by the first query
+    let mut txn = graph.start_txn_on("does-not-exist").await.unwrap();
+    assert!(txn.run(query("RETURN 1")).await.is_err());
+    drop(txn);
+
+    let mut txn = graph.start_txn().await.unwrap();
+    let value = txn
+        .execute(query("RETURN 42 AS x"))
+        .await
+        .unwrap()
+        .single_as::<i64>(txn.handle())
+        .await;
+    assert_eq!(value.unwrap(), 42);
+    txn.commit().await.unwrap();
+
+    // a transaction without queries sends its BEGIN on commit
+    let txn = graph.start_txn().await.unwrap();
+    txn.rollback().await.unwrap();
+
+    let value = graph
+        .execute(query("RETURN 42 AS x"))
+        .await
+        .unwrap()
+        .single()
+        .await
+        .unwrap()
+        .get::<i64>("x");
+    assert_eq!(value.unwrap(), 42);
+}
@@ -0,0 +1,47 @@
+use futures::TryStreamExt as _;
+use neo4rs::*;
+
+mod container;
+
+#[tokio::test]
+async fn prefetch() {
+    let config = ConfigBuilder::default().fetch_size(3).max_connections(1);
+    let neo4j = container::Neo4jContainer::from_config(config).await;
+    let graph = neo4j.graph();
+
+    let qry = "UNWIND range(1, 10) AS x RETURN x";
+
+    let rows = graph
+        .execute(qry)
+        .await
+        .unwrap()
+        .with_prefetch(2)
+        .column_into_stream::<i64>("x")
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(rows, (1..=10).collect::<Vec<_>>());
+
+    // a prefetched batch is read before the connection is used again
+    let mut stream = graph.execute(qry).await.unwrap().with_prefetch(3);
+    let row = stream.next().await.unwrap().unwrap();
+    assert_eq!(row.get::<i64>("x").unwrap(), 1);
+    drop(stream);
+
+    let mut txn = graph.start_txn().await.unwrap();
+    let mut stream = txn.execute(qry).await.unwrap().with_prefetch(3);
+    let row = stream.next(txn.handle()).await.unwrap().unwrap();
+    assert_eq!(row.get::<i64>("x").unwrap(), 1);
+    stream.finish(txn.handle()).await.unwrap();
+
+    let rows = txn
+        .execute(qry)
+        .await
+        .unwrap()
+        .with_prefetch(3)
+        .into_stream_as::<i64>(txn.handle())
+        .try_collect::<Vec<_>>()
+        .await;
+    assert_eq!(rows.unwrap(), (1..=10).collect::<Vec<_>>());
+    txn.commit().await.unwrap();
+}
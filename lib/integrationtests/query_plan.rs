@@ -0,0 +1,39 @@
+use neo4rs::*;
+
+mod container;
+
+#[tokio::test]
+async fn query_plan() {
+    let neo4j = container::Neo4jContainer::new().await;
+    let graph = neo4j.graph();
+
+    graph
+        .run("CREATE INDEX plan_person_id IF NOT EXISTS FOR (p:PlanPerson) ON (p.id)")
+        .await
+        .unwrap();
+    graph.run("CALL db.awaitIndexes()").await.unwrap();
+
+    let summary = graph
+        .run(query("EXPLAIN MATCH (p:PlanPerson {id: $id}) RETURN p").param("id", 42))
+        .await
+        .unwrap();
+    let plan = summary.plan().expect("EXPLAIN returns a plan");
+    assert!(summary.profile().is_none());
+    assert_eq!(plan.operator_name(), "ProduceResults");
+    assert!(plan.contains_operator("NodeIndexSeek"));
+    assert!(!plan.contains_operator("AllNodesScan"));
+    assert!(plan.to_string().contains("+NodeIndexSeek"));
+    assert!(plan.identifiers.contains(&"p".to_owned()));
+
+    let summary = graph
+        .run("PROFILE UNWIND range(1, 10) AS x CREATE (:PlanPerson {id: x})")
+        .await
+        .unwrap();
+    let profile = summary.profile().expect("PROFILE returns a profile");
+    assert!(summary.plan().is_none());
+    assert!(profile.total_db_hits() > 0);
+    assert!(profile.to_string().ends_with(&format!(
+        "Total database accesses: {}",
+        profile.total_db_hits()
+    )));
+}
@@ -1,4 +1,3 @@
-#![cfg(feature = "unstable-result-summary")]
 use neo4rs::*;
 
 mod container;
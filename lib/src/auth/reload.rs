@@ -0,0 +1,260 @@
+use super::{ClientCertificate, ConnectionTLSConfig, MutualTLS};
+use crate::{connection::ConnectionInfo, errors::Result};
+use log::warn;
+use rustls::ClientConfig;
+use std::{
+    fmt::{self, Debug, Formatter},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
+type Load = dyn Fn() -> Result<Arc<ClientConfig>> + Send + Sync;
+
+/// A TLS configuration that is reloaded while the driver is running,
+/// so that rotated client certificates and CA bundles are used without a restart,
+/// see [`crate::ConfigBuilder::with_reloadable_tls`].
+///
+/// The configuration is reloaded when [`ReloadableTls::reload`] is called,
+/// or, before a new connection is opened, when one of the watched files has been modified
+/// or the reload interval has passed. Connections that are already open are not affected.
+/// If a reload fails, the previous configuration is used and a warning is logged.
+///
+/// The configuration is used as is, also for the `bolt+ssc` and `neo4j+ssc` schemes.
+#[derive(Clone)]
+pub struct ReloadableTls {
+    load: Arc<Load>,
+    files: Vec<PathBuf>,
+    interval: Option<Duration>,
+    state: Arc<Mutex<State>>,
+}
+
+struct State {
+    config: Arc<ClientConfig>,
+    loaded_at: Instant,
+    modified: Vec<Option<SystemTime>>,
+}
+
+impl ReloadableTls {
+    /// Creates a configuration that is loaded with the given function, which is called right away.
+    pub fn new(
+        load: impl Fn() -> Result<Arc<ClientConfig>> + Send + Sync + 'static,
+    ) -> Result<Self> {
+        let config = load()?;
+        Ok(ReloadableTls {
+            load: Arc::new(load),
+            files: Vec::new(),
+            interval: None,
+            state: Arc::new(Mutex::new(State {
+                config,
+                loaded_at: Instant::now(),
+                modified: Vec::new(),
+            })),
+        })
+    }
+
+    /// Creates a configuration for mutual TLS that is reloaded when one of its files is modified.
+    pub fn from_mutual_tls(mutual_tls: MutualTLS) -> Result<Self> {
+        let files = [mutual_tls.cert_file.as_ref().and_then(|c| c.file())]
+            .into_iter()
+            .flatten()
+            .chain(mutual_tls.client_cert.file())
+            .chain(mutual_tls.client_key.file())
+            .map(Path::to_path_buf)
+            .collect::<Vec<_>>();
+        let tls_config = ConnectionTLSConfig::MutualTLS(mutual_tls);
+        Ok(Self::new(move || ConnectionInfo::client_config(&tls_config))?.watch_files(files))
+    }
+
+    /// Creates a configuration with CA certificates that is reloaded when their file is modified.
+    pub fn from_client_certificate(certificate: ClientCertificate) -> Result<Self> {
+        let files = certificate.certs.file().map(Path::to_path_buf);
+        let tls_config = ConnectionTLSConfig::ClientCACertificate(certificate);
+        Ok(Self::new(move || ConnectionInfo::client_config(&tls_config))?.watch_files(files))
+    }
+
+    /// Reloads the configuration when one of the given files has been modified.
+    pub fn watch_files(mut self, files: impl IntoIterator<Item = impl AsRef<Path>>) -> Self {
+        self.files
+            .extend(files.into_iter().map(|f| f.as_ref().to_path_buf()));
+        self.lock().modified = modification_times(&self.files);
+        self
+    }
+
+    /// Reloads the configuration when it is older than the given interval,
+    /// for example to pick up certificates that are issued by the load function.
+    pub fn reload_interval(self, interval: Duration) -> Self {
+        ReloadableTls {
+            interval: Some(interval),
+            ..self
+        }
+    }
+
+    /// Loads the configuration now, which is used for all connections that are opened afterwards.
+    pub fn reload(&self) -> Result<()> {
+        let mut state = self.lock();
+        self.reload_state(&mut state)
+    }
+
+    /// The current configuration, which is reloaded first if it is out of date.
+    ///
+    /// The files are checked and the configuration is loaded on a blocking thread,
+    /// so that a slow file system or load function does not block the runtime.
+    pub(crate) async fn current(&self) -> Arc<ClientConfig> {
+        let tls = self.clone();
+        match tokio::task::spawn_blocking(move || tls.refresh()).await {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("Failed to reload the TLS configuration, using the previous one: {e}");
+                self.lock().config.clone()
+            }
+        }
+    }
+
+    /// Reloads the configuration if it is out of date and returns it.
+    /// This blocks while the files are checked and the configuration is loaded.
+    pub(crate) fn refresh(&self) -> Arc<ClientConfig> {
+        let mut state = self.lock();
+        let expired = self
+            .interval
+            .is_some_and(|interval| state.loaded_at.elapsed() >= interval);
+        if expired || (!self.files.is_empty() && state.modified != modification_times(&self.files))
+        {
+            if let Err(e) = self.reload_state(&mut state) {
+                warn!("Failed to reload the TLS configuration, using the previous one: {e}");
+            }
+        }
+        state.config.clone()
+    }
+
+    fn reload_state(&self, state: &mut State) -> Result<()> {
+        let modified = modification_times(&self.files);
+        state.config = (self.load)()?;
+        state.loaded_at = Instant::now();
+        state.modified = modified;
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn modification_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| std::fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+impl PartialEq for ReloadableTls {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Debug for ReloadableTls {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReloadableTls")
+            .field("files", &self.files)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting() -> (
+        Arc<AtomicUsize>,
+        impl Fn() -> Result<Arc<ClientConfig>> + Send + Sync,
+    ) {
+        let loads = Arc::new(AtomicUsize::new(0));
+        let counter = loads.clone();
+        let load = move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let config = ClientConfig::builder()
+                .with_root_certificates(rustls::RootCertStore::empty())
+                .with_no_client_auth();
+            Ok(Arc::new(config))
+        };
+        (loads, load)
+    }
+
+    #[test]
+    fn should_reload_when_file_is_modified() {
+        let path = std::env::temp_dir().join(format!("neo4rs-reload-{}.pem", std::process::id()));
+        std::fs::write(&path, "").unwrap();
+        let (loads, load) = counting();
+        let tls = ReloadableTls::new(load).unwrap().watch_files([&path]);
+
+        let config = tls.refresh();
+        assert!(Arc::ptr_eq(&config, &tls.refresh()));
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        assert!(!Arc::ptr_eq(&config, &tls.refresh()));
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_reload_after_interval() {
+        let (loads, load) = counting();
+        let tls = ReloadableTls::new(load)
+            .unwrap()
+            .reload_interval(Duration::ZERO);
+
+        tls.refresh();
+        tls.refresh();
+        assert_eq!(loads.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn should_reload_on_a_blocking_thread() {
+        let threads = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let (_, load) = counting();
+        let tls = ReloadableTls::new({
+            let threads = threads.clone();
+            move || {
+                threads.lock().unwrap().push(std::thread::current().id());
+                load()
+            }
+        })
+        .unwrap()
+        .reload_interval(Duration::ZERO);
+
+        let config = tls.current().await;
+        assert!(Arc::ptr_eq(&config, &tls.lock().config));
+        let threads = threads.lock().unwrap();
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0], std::thread::current().id());
+        assert_ne!(threads[1], std::thread::current().id());
+    }
+
+    #[test]
+    fn should_keep_previous_config_if_reload_fails() {
+        let fail = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let (_, load) = counting();
+        let tls = ReloadableTls::new({
+            let fail = fail.clone();
+            move || match fail.load(Ordering::SeqCst) {
+                true => Err(Error::ConnectionError),
+                false => load(),
+            }
+        })
+        .unwrap()
+        .reload_interval(Duration::ZERO);
+        let config = tls.lock().config.clone();
+
+        fail.store(true, Ordering::SeqCst);
+        assert!(tls.reload().is_err());
+        assert!(Arc::ptr_eq(&config, &tls.refresh()));
+    }
+}
@@ -0,0 +1,142 @@
+use rustls::{
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::{
+        verify_tls12_signature, verify_tls13_signature, CryptoProvider, WebPkiSupportedAlgorithms,
+    },
+    pki_types::{CertificateDer, ServerName, UnixTime},
+    CertificateError, DigitallySignedStruct, Error, SignatureScheme,
+};
+use std::sync::Arc;
+
+/// Trusts a server if the SHA-256 fingerprint of the public key (SPKI) of its certificate
+/// matches one of the pinned fingerprints, see [`crate::ConfigBuilder::with_pinned_certificates`].
+///
+/// The certificate does not have to be signed by a known CA, so a self-signed certificate can be
+/// trusted without turning off the validation. The host name and validity period of the
+/// certificate are not checked, the key is trusted no matter which certificate it is part of.
+///
+/// The fingerprint of a certificate can be computed with
+/// `openssl x509 -in cert.pem -pubkey -noout | openssl pkey -pubin -outform der | openssl dgst -sha256`
+/// or with [`PinnedCertificateVerifier::fingerprint`].
+#[derive(Debug)]
+pub struct PinnedCertificateVerifier {
+    fingerprints: Vec<[u8; 32]>,
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl PinnedCertificateVerifier {
+    pub fn new(fingerprints: impl IntoIterator<Item = [u8; 32]>) -> Self {
+        let provider = CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::ring::default_provider()));
+        PinnedCertificateVerifier {
+            fingerprints: fingerprints.into_iter().collect(),
+            algorithms: provider.signature_verification_algorithms,
+        }
+    }
+
+    /// The SHA-256 fingerprint of the public key of the given DER encoded certificate.
+    pub fn fingerprint(certificate: &CertificateDer<'_>) -> Result<[u8; 32], Error> {
+        let certificate = webpki::EndEntityCert::try_from(certificate)
+            .map_err(|_| Error::InvalidCertificate(CertificateError::BadEncoding))?;
+        let spki = certificate.subject_public_key_info();
+        let digest = ring::digest::digest(&ring::digest::SHA256, spki.as_ref());
+        let mut fingerprint = [0; 32];
+        fingerprint.copy_from_slice(digest.as_ref());
+        Ok(fingerprint)
+    }
+}
+
+impl ServerCertVerifier for PinnedCertificateVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, Error> {
+        let fingerprint = Self::fingerprint(end_entity)?;
+        if self.fingerprints.contains(&fingerprint) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustls::pki_types::pem::PemObject;
+
+    // a self-signed ed25519 certificate for localhost
+    const CERT: &str = "-----BEGIN CERTIFICATE-----
+MIIBPjCB8aADAgECAhQlT+yVZOp3NRrSbcex3UGMpO6oFzAFBgMrZXAwFDESMBAG
+A1UEAwwJbG9jYWxob3N0MCAXDTI2MTAxODE4MTA1NVoYDzIxMjYwOTI0MTgxMDU1
+WjAUMRIwEAYDVQQDDAlsb2NhbGhvc3QwKjAFBgMrZXADIQDcWJm4+zK6dV+aGpFP
+BONTmWQ5NKIbMdT7dZkS434736NTMFEwHQYDVR0OBBYEFKHTmhQTOgU0VUdUACOz
+iO2hvq9WMB8GA1UdIwQYMBaAFKHTmhQTOgU0VUdUACOziO2hvq9WMA8GA1UdEwEB
+/wQFMAMBAf8wBQYDK2VwA0EAN2bCGAcgMUuDWoUhwpqxp9EoyLMFdFlDmj3Iqfya
+66Q99FL2UM7iYs1KOMpUMIp8qMrj2HTQIFfDirw4KlcuCw==
+-----END CERTIFICATE-----";
+
+    // openssl x509 -in cert.pem -pubkey -noout | openssl pkey -pubin -outform der | openssl dgst -sha256
+    const FINGERPRINT: [u8; 32] = [
+        0x4b, 0xc9, 0x15, 0xd7, 0x8a, 0x8a, 0x1c, 0x06, 0x40, 0x97, 0x51, 0xff, 0xbe, 0x08, 0x79,
+        0x60, 0x15, 0x69, 0xe3, 0xa9, 0xa6, 0x77, 0x01, 0x2b, 0x0d, 0x1b, 0xee, 0x12, 0x0c, 0x50,
+        0xe0, 0xf6,
+    ];
+
+    fn verify(verifier: &PinnedCertificateVerifier) -> Result<ServerCertVerified, Error> {
+        let cert = CertificateDer::from_pem_slice(CERT.as_bytes()).unwrap();
+        let server_name = ServerName::try_from("localhost").unwrap();
+        verifier.verify_server_cert(&cert, &[], &server_name, &[], UnixTime::now())
+    }
+
+    #[test]
+    fn should_compute_fingerprint() {
+        let cert = CertificateDer::from_pem_slice(CERT.as_bytes()).unwrap();
+        let fingerprint = PinnedCertificateVerifier::fingerprint(&cert).unwrap();
+        assert_eq!(fingerprint, FINGERPRINT);
+    }
+
+    #[test]
+    fn should_trust_pinned_certificate() {
+        let verifier = PinnedCertificateVerifier::new([[0; 32], FINGERPRINT]);
+        assert!(verify(&verifier).is_ok());
+    }
+
+    #[test]
+    fn should_reject_unpinned_certificate() {
+        let verifier = PinnedCertificateVerifier::new([[0; 32]]);
+        assert_eq!(
+            verify(&verifier).unwrap_err(),
+            Error::InvalidCertificate(CertificateError::ApplicationVerificationFailure)
+        );
+    }
+}
@@ -14,8 +14,8 @@ mod structs;
 mod summary;
 
 pub use request::{
-    Begin, Commit, ConnectionsHints, Discard, Goodbye, Hello, HelloBuilder, Pull, Reset, Rollback,
-    WrapExtra,
+    Begin, Commit, ConnectionsHints, Discard, Goodbye, Hello, HelloBuilder, Logon, Pull, Reset,
+    Rollback, WrapExtra,
 };
 pub use structs::{
     Bolt, BoltRef, Date, DateDuration, DateTime, DateTimeZoneId, DateTimeZoneIdRef, Duration,
@@ -64,7 +64,7 @@ pub(crate) trait ExpectedResponse {
     type Response: MessageResponse;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Response<R, S> {
     Detail(R),
     Success(S),
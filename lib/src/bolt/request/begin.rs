@@ -1,4 +1,5 @@
 use crate::bolt::{ExpectedResponse, Summary};
+use crate::notification::{NotificationFilter, NotificationValue};
 use crate::{Database, Version};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
@@ -32,9 +33,7 @@ pub struct BeginMeta<'a> {
     pub(crate) tx_metadata: Option<TxMetadata>,
     pub(crate) mode: &'a str,
     pub(crate) extra: BeginExtra<'a>,
-    // To be added when implementing protocol version 5.2
-    // pub(crate) notifications_minimum_severity: &'a str,
-    // pub(crate) notifications_disabled_categories: Vec<String>
+    pub(crate) notifications: Vec<(&'static str, NotificationValue)>,
 }
 
 pub struct BeginBuilder<'a> {
@@ -44,6 +43,7 @@ pub struct BeginBuilder<'a> {
     mode: &'a str,
     db: Option<&'a str>,
     imp_user: Option<&'a str>,
+    notifications: Option<&'a NotificationFilter>,
 }
 
 impl<'a> BeginBuilder<'a> {
@@ -55,6 +55,7 @@ impl<'a> BeginBuilder<'a> {
             mode: "w", // default is write mode
             db,
             imp_user: None,
+            notifications: None,
         }
     }
 
@@ -86,7 +87,15 @@ impl<'a> BeginBuilder<'a> {
         self
     }
 
+    pub(crate) fn with_notifications(mut self, notifications: &'a NotificationFilter) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
     pub fn build(self, version: Version) -> Begin<'a> {
+        let notifications = self
+            .notifications
+            .map_or_else(Vec::new, |n| n.entries(version));
         match version.cmp(&Version::V4_4) {
             std::cmp::Ordering::Less => Begin {
                 metadata: BeginMeta {
@@ -95,6 +104,7 @@ impl<'a> BeginBuilder<'a> {
                     tx_metadata: self.tx_metadata,
                     mode: self.mode,
                     extra: BeginExtra::V4(self.db),
+                    notifications,
                 },
             },
             _ => Begin {
@@ -107,6 +117,7 @@ impl<'a> BeginBuilder<'a> {
                         db: self.db,
                         imp_user: self.imp_user,
                     }),
+                    notifications,
                 },
             },
         }
@@ -155,7 +166,7 @@ impl Serialize for BeginMeta<'_> {
     where
         S: serde::Serializer,
     {
-        let mut fields_count = 2; // minimum number of fields for the map
+        let mut fields_count = 2 + self.notifications.len(); // minimum number of fields for the map
         if self.tx_metadata.is_some() {
             fields_count += 1;
         }
@@ -203,6 +214,9 @@ impl Serialize for BeginMeta<'_> {
                 }
             }
         }
+        for (key, value) in &self.notifications {
+            map.serialize_entry(key, value)?;
+        }
         map.end()
     }
 }
@@ -211,8 +225,9 @@ impl Serialize for BeginMeta<'_> {
 mod tests {
     use super::Begin;
     use crate::bolt::Message;
+    use crate::notification::NotificationFilter;
     use crate::packstream::bolt;
-    use crate::{Database, Version};
+    use crate::{Database, NotificationClassification, NotificationSeverity, Version};
 
     #[test]
     fn serialize() {
@@ -271,4 +286,74 @@ mod tests {
 
         assert_eq!(bytes, expected);
     }
+
+    #[test]
+    fn serialize_with_notification_filters() {
+        let notifications = NotificationFilter {
+            minimum_severity: Some(NotificationSeverity::Off),
+            disabled_categories: Some(vec![
+                NotificationClassification::Deprecation,
+                NotificationClassification::Performance,
+            ]),
+        };
+        let begin = Begin::builder(None)
+            .with_notifications(&notifications)
+            .build(Version::V5_5);
+        let bytes = begin.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x11)
+            .tiny_map(4)
+            .tiny_string("bookmarks")
+            .tiny_list(0)
+            .tiny_string("mode")
+            .tiny_string("w")
+            .string8("notifications_minimum_severity")
+            .tiny_string("OFF")
+            .string8("notifications_disabled_classifications")
+            .tiny_list(2)
+            .tiny_string("DEPRECATION")
+            .tiny_string("PERFORMANCE")
+            .build();
+
+        assert_eq!(bytes, expected);
+
+        let begin = Begin::builder(None)
+            .with_notifications(&notifications)
+            .build(Version::V5_2);
+        let bytes = begin.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x11)
+            .tiny_map(4)
+            .tiny_string("bookmarks")
+            .tiny_list(0)
+            .tiny_string("mode")
+            .tiny_string("w")
+            .string8("notifications_minimum_severity")
+            .tiny_string("OFF")
+            .string8("notifications_disabled_categories")
+            .tiny_list(2)
+            .tiny_string("DEPRECATION")
+            .tiny_string("PERFORMANCE")
+            .build();
+
+        assert_eq!(bytes, expected);
+
+        let begin = Begin::builder(None)
+            .with_notifications(&notifications)
+            .build(Version::V4_4);
+        let bytes = begin.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x11)
+            .tiny_map(2)
+            .tiny_string("bookmarks")
+            .tiny_list(0)
+            .tiny_string("mode")
+            .tiny_string("w")
+            .build();
+
+        assert_eq!(bytes, expected);
+    }
 }
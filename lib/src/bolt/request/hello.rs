@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 
 use crate::{
     bolt::{ExpectedResponse, Summary},
+    notification::{NotificationFilter, NotificationValue},
     Version,
 };
 use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize};
@@ -17,6 +18,7 @@ pub struct HelloBuilder<'a> {
     credentials: &'a str,
     user_agent: &'a str,
     routing: ServerRouting<'a>,
+    notifications: Option<&'a NotificationFilter>,
 }
 
 impl<'a> HelloBuilder<'a> {
@@ -27,6 +29,7 @@ impl<'a> HelloBuilder<'a> {
             credentials,
             user_agent: "neo4rs",
             routing: ServerRouting::No,
+            notifications: None,
         }
     }
 
@@ -43,6 +46,16 @@ impl<'a> HelloBuilder<'a> {
         HelloBuilder { routing, ..self }
     }
 
+    pub(crate) fn with_notifications(
+        self,
+        notifications: &'a NotificationFilter,
+    ) -> HelloBuilder<'a> {
+        HelloBuilder {
+            notifications: Some(notifications),
+            ..self
+        }
+    }
+
     pub fn build(self, version: Version) -> Hello<'a> {
         let Self {
             scheme,
@@ -50,6 +63,7 @@ impl<'a> HelloBuilder<'a> {
             credentials,
             user_agent,
             mut routing,
+            notifications,
         } = self;
 
         if version < Version::V4_1 {
@@ -58,10 +72,10 @@ impl<'a> HelloBuilder<'a> {
 
         let metadata = Meta {
             user_agent,
-            scheme,
-            principal,
-            credentials,
+            bolt_agent: (version >= Version::V5_3).then_some(crate::messages::BOLT_AGENT),
+            auth: (version < Version::V5_1).then_some((scheme, principal, credentials)),
             routing,
+            notifications: notifications.map_or_else(Vec::new, |n| n.entries(version)),
         };
         Hello { metadata }
     }
@@ -73,14 +87,52 @@ impl<'a> Hello<'a> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Meta<'a> {
-    scheme: &'a str,
-    principal: &'a str,
-    credentials: &'a str,
+    /// The scheme, principal and credentials, which are sent in a LOGON since Bolt 5.1.
+    auth: Option<(&'a str, &'a str, &'a str)>,
     user_agent: &'a str,
-    #[serde(skip_serializing_if = "ServerRouting::is_none")]
+    bolt_agent: Option<&'static str>,
     routing: ServerRouting<'a>,
+    notifications: Vec<(&'static str, NotificationValue)>,
+}
+
+impl Serialize for Meta<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut fields_count = 1 + self.notifications.len();
+        if self.auth.is_some() {
+            fields_count += 3;
+        }
+        if self.bolt_agent.is_some() {
+            fields_count += 1;
+        }
+        if !self.routing.is_none() {
+            fields_count += 1;
+        }
+
+        let mut map = serializer.serialize_map(Some(fields_count))?;
+        if let Some((scheme, principal, credentials)) = self.auth {
+            map.serialize_entry("scheme", scheme)?;
+            map.serialize_entry("principal", principal)?;
+            map.serialize_entry("credentials", credentials)?;
+        }
+        map.serialize_entry("user_agent", self.user_agent)?;
+        if let Some(product) = self.bolt_agent {
+            let mut bolt_agent = std::collections::HashMap::with_capacity(1);
+            bolt_agent.insert("product", product);
+            map.serialize_entry("bolt_agent", &bolt_agent)?;
+        }
+        if !self.routing.is_none() {
+            map.serialize_entry("routing", &self.routing)?;
+        }
+        for (key, value) in &self.notifications {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -147,6 +199,7 @@ mod tests {
     use crate::{
         bolt::{Message as _, MessageResponse as _},
         packstream::bolt,
+        NotificationClassification, NotificationSeverity,
     };
 
     #[test]
@@ -247,6 +300,90 @@ mod tests {
         assert_eq!(bytes, expected);
     }
 
+    #[test]
+    fn serialize_with_notification_filters() {
+        let notifications = NotificationFilter {
+            minimum_severity: Some(NotificationSeverity::Warning),
+            disabled_categories: Some(vec![NotificationClassification::Deprecation]),
+        };
+        let hello = Hello::builder("user", "pass")
+            .with_notifications(&notifications)
+            .build(Version::V5_2);
+        let bytes = hello.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x01)
+            .tiny_map(3)
+            .tiny_string("user_agent")
+            .tiny_string("neo4rs")
+            .string8("notifications_minimum_severity")
+            .tiny_string("WARNING")
+            .string8("notifications_disabled_categories")
+            .tiny_list(1)
+            .tiny_string("DEPRECATION")
+            .build();
+
+        assert_eq!(bytes, expected);
+
+        let hello = Hello::builder("user", "pass")
+            .with_notifications(&notifications)
+            .build(Version::V4_4);
+        let bytes = hello.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x01)
+            .tiny_map(4)
+            .tiny_string("scheme")
+            .tiny_string("basic")
+            .tiny_string("principal")
+            .tiny_string("user")
+            .tiny_string("credentials")
+            .tiny_string("pass")
+            .tiny_string("user_agent")
+            .tiny_string("neo4rs")
+            .build();
+
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn serialize_without_credentials_since_bolt_5_1() {
+        let hello = Hello::builder("user", "pass").build(Version::V5_1);
+        let bytes = hello.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x01)
+            .tiny_map(1)
+            .tiny_string("user_agent")
+            .tiny_string("neo4rs")
+            .build();
+
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn serialize_with_bolt_agent_since_bolt_5_3() {
+        let hello = Hello::builder("user", "pass").build(Version::V5_3);
+        let bytes = hello.to_bytes().unwrap();
+
+        let product = crate::messages::BOLT_AGENT;
+        let expected = bolt()
+            .structure(1, 0x01)
+            .tiny_map(2)
+            .tiny_string("user_agent")
+            .tiny_string("neo4rs")
+            .tiny_string("bolt_agent")
+            .tiny_map(1)
+            .tiny_string("product");
+        let expected = if product.len() < 16 {
+            expected.tiny_string(product)
+        } else {
+            expected.string8(product)
+        };
+
+        assert_eq!(bytes, expected.build());
+    }
+
     #[test]
     fn parse() {
         let data = bolt()
@@ -0,0 +1,73 @@
+use crate::bolt::{ExpectedResponse, Summary};
+use serde::{de::IgnoredAny, ser::SerializeMap, Serialize};
+
+/// Authenticates the connection since Bolt 5.1, the credentials are no longer part of the HELLO.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logon<'a> {
+    scheme: &'a str,
+    principal: &'a str,
+    credentials: &'a str,
+}
+
+impl<'a> Logon<'a> {
+    pub fn new(principal: &'a str, credentials: &'a str) -> Self {
+        Self {
+            scheme: "basic",
+            principal,
+            credentials,
+        }
+    }
+}
+
+impl ExpectedResponse for Logon<'_> {
+    type Response = Summary<IgnoredAny>;
+}
+
+impl Serialize for Logon<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_newtype_variant("Request", 0x6A, "LOGON", &Auth(self))
+    }
+}
+
+struct Auth<'a, 'b>(&'b Logon<'a>);
+
+impl Serialize for Auth<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("scheme", self.0.scheme)?;
+        map.serialize_entry("principal", self.0.principal)?;
+        map.serialize_entry("credentials", self.0.credentials)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bolt::Message as _, packstream::bolt};
+
+    #[test]
+    fn serialize() {
+        let logon = Logon::new("user", "pass");
+        let bytes = logon.to_bytes().unwrap();
+
+        let expected = bolt()
+            .structure(1, 0x6A)
+            .tiny_map(3)
+            .tiny_string("scheme")
+            .tiny_string("basic")
+            .tiny_string("principal")
+            .tiny_string("user")
+            .tiny_string("credentials")
+            .tiny_string("pass")
+            .build();
+
+        assert_eq!(bytes, expected);
+    }
+}
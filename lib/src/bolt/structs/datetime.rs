@@ -421,6 +421,7 @@ impl<'de> Deserialize<'de> for LegacyDateTimeZoneId {
 mod tests {
     use chrono::{Datelike, FixedOffset, Timelike};
 
+    use crate::bolt::Bolt;
     use crate::packstream::{bolt, from_bytes_ref, Data};
 
     use super::*;
@@ -490,6 +491,38 @@ mod tests {
         assert_eq!(tm.offset().as_hms(), (1, 0, 0));
     }
 
+    #[test]
+    fn convert_to_bolt_type_in_local_time() {
+        let data = bolt()
+            .structure(3, 0x49)
+            .int16(4500)
+            .tiny_int(42)
+            .int16(3600)
+            .build();
+        let date: DateTime = from_bytes_ref(&mut Data::new(data)).unwrap();
+        let crate::BoltType::DateTime(date) = crate::BoltType::from(Bolt::DateTime(date)) else {
+            panic!("expected a datetime");
+        };
+        assert_eq!(date.seconds.value, 8100);
+        assert_eq!(date.tz_offset_seconds.value, 3600);
+
+        let data = bolt()
+            .structure(3, 0x69)
+            .int16(4500)
+            .tiny_int(42)
+            .tiny_string("Europe/Paris")
+            .build();
+        let mut data = Data::new(data);
+        let date: DateTimeZoneIdRef = from_bytes_ref(&mut data).unwrap();
+        let crate::BoltType::DateTimeZoneId(date) =
+            crate::BoltType::from(Bolt::DateTimeZoneId(date.to_owned()))
+        else {
+            panic!("expected a datetime");
+        };
+        assert_eq!(date.seconds.value, 8100);
+        assert_eq!(date.tz_id.value, "Europe/Paris");
+    }
+
     #[test]
     fn deserialize_local_datetime() {
         let data = bolt().structure(2, 0x64).int16(4500).tiny_int(42).build();
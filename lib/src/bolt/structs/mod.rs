@@ -295,12 +295,16 @@ impl From<Bolt> for crate::BoltType {
         fn conv_unrel(rel: urel::UnboundRelationship) -> BoltUnboundedRelation {
             let id = BoltInteger::new(rel.id().try_into().unwrap());
             let typ = BoltString::from(rel.typ());
+            let element_id = rel.element_id().map(BoltString::from);
             let properties = rel.into::<Bolt>().unwrap();
             let properties = BoltType::from(properties);
             let BoltType::Map(properties) = properties else {
                 panic!("properties should be a map");
             };
-            BoltUnboundedRelation::new(id, typ, properties)
+            BoltUnboundedRelation {
+                element_id,
+                ..BoltUnboundedRelation::new(id, typ, properties)
+            }
         }
 
         match value {
@@ -326,22 +330,29 @@ impl From<Bolt> for crate::BoltType {
                     .cloned()
                     .map(BoltType::from)
                     .collect::<Vec<_>>();
+                let element_id = v.element_id().map(BoltString::from);
                 let properties = v.into::<Bolt>().unwrap();
                 let properties = BoltType::from(properties);
                 let BoltType::Map(properties) = properties else {
                     panic!("properties should be a map");
                 };
-                Self::Node(BoltNode::new(
-                    BoltInteger::new(id.try_into().unwrap()),
-                    BoltList::from(labels),
-                    properties,
-                ))
+                Self::Node(BoltNode {
+                    element_id,
+                    ..BoltNode::new(
+                        BoltInteger::new(id.try_into().unwrap()),
+                        BoltList::from(labels),
+                        properties,
+                    )
+                })
             }
             Bolt::Relationship(v) => {
                 let id = v.id();
                 let start_node_id = v.start_node_id();
                 let end_node_id = v.end_node_id();
                 let typ = BoltString::from(v.typ());
+                let element_id = v.element_id().map(BoltString::from);
+                let start_node_element_id = v.start_node_element_id().map(BoltString::from);
+                let end_node_element_id = v.end_node_element_id().map(BoltString::from);
                 let properties = v.into::<Bolt>().unwrap();
                 let properties = BoltType::from(properties);
                 let BoltType::Map(properties) = properties else {
@@ -353,6 +364,9 @@ impl From<Bolt> for crate::BoltType {
                     end_node_id: BoltInteger::new(end_node_id.try_into().unwrap()),
                     typ,
                     properties,
+                    element_id,
+                    start_node_element_id,
+                    end_node_element_id,
                 })
             }
             Bolt::Path(v) => {
@@ -394,16 +408,20 @@ impl From<Bolt> for crate::BoltType {
             Bolt::LocalTime(v) => Self::LocalTime(BoltLocalTime {
                 nanoseconds: BoltInteger::new(v.nanoseconds_since_midnight().try_into().unwrap()),
             }),
+            // the seconds of a `BoltDateTime` are in local time, since Bolt 5 they are sent in UTC
             Bolt::DateTime(v) => Self::DateTime(BoltDateTime {
-                seconds: v.seconds_since_epoch().into(),
+                seconds: v
+                    .seconds_since_epoch()
+                    .saturating_add(v.timezone_offset_seconds().into())
+                    .into(),
                 nanoseconds: BoltInteger::new(v.nanoseconds().into()),
                 tz_offset_seconds: v.timezone_offset_seconds().into(),
             }),
-            Bolt::DateTimeZoneId(v) => Self::DateTimeZoneId(BoltDateTimeZoneId {
-                seconds: v.seconds_since_epoch().into(),
-                nanoseconds: BoltInteger::new(v.nanoseconds().into()),
-                tz_id: v.timezone_identifier().into(),
-            }),
+            Bolt::DateTimeZoneId(v) => Self::DateTimeZoneId(BoltDateTimeZoneId::from_utc(
+                v.seconds_since_epoch(),
+                v.nanoseconds().into(),
+                v.timezone_identifier().into(),
+            )),
             Bolt::LocalDateTime(v) => Self::LocalDateTime(BoltLocalDateTime {
                 seconds: v.seconds_since_epoch().into(),
                 nanoseconds: BoltInteger::new(v.nanoseconds().into()),
@@ -430,11 +448,11 @@ impl From<Bolt> for crate::BoltType {
                 nanoseconds: BoltInteger::new(v.nanoseconds().into()),
                 tz_offset_seconds: v.timezone_offset_seconds().into(),
             }),
-            Bolt::LegacyDateTimeZoneId(v) => Self::DateTimeZoneId(BoltDateTimeZoneId {
-                seconds: v.seconds_since_epoch().into(),
-                nanoseconds: BoltInteger::new(v.nanoseconds().into()),
-                tz_id: v.timezone_identifier().into(),
-            }),
+            Bolt::LegacyDateTimeZoneId(v) => Self::DateTimeZoneId(BoltDateTimeZoneId::from_local(
+                v.seconds_since_epoch(),
+                v.nanoseconds().into(),
+                v.timezone_identifier().into(),
+            )),
         }
     }
 }
@@ -121,6 +121,8 @@ pub enum PointConversionError {
     WrongSystem(Crs),
     #[error("The point is not defined on the {0} ellipsoid.")]
     UndefinedPosition(Crs),
+    #[error("The coordinate system {0} is not 2-dimensional")]
+    NotTwoDimensional(Crs),
 }
 
 /// A Coordinate Reference System.
@@ -265,6 +267,29 @@ impl Point {
     }
 }
 
+#[cfg(feature = "geo")]
+impl TryFrom<Point2D> for geo_types::Point<f64> {
+    type Error = PointConversionError;
+
+    fn try_from(value: Point2D) -> Result<Self, Self::Error> {
+        value.to_point().try_into()
+    }
+}
+
+#[cfg(feature = "geo")]
+impl TryFrom<Point> for geo_types::Point<f64> {
+    type Error = PointConversionError;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        match value {
+            Point::Wgs842d(p) => Ok(geo_types::Point::new(p.longitude, p.latitude)),
+            Point::Cartesian2d(p) => Ok(geo_types::Point::new(p.x, p.y)),
+            Point::Wgs843d(_) => Err(PointConversionError::NotTwoDimensional(Crs::Wgs843D)),
+            Point::Cartesian3d(_) => Err(PointConversionError::NotTwoDimensional(Crs::Cartesian3D)),
+        }
+    }
+}
+
 impl_visitor!(Point2D(srid, x, y) == 0x58);
 impl_visitor!(Point3D(srid, x, y, z) == 0x59);
 
@@ -488,4 +513,37 @@ mod tests {
 
         assert_approx_eq!(copenhagen.distance(&malmoe).round(), 27842.0);
     }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn convert_to_geo() {
+        let data = bolt()
+            .structure(3, 0x58)
+            .int16(4326)
+            .float(12.994341)
+            .float(55.611784)
+            .build();
+        let mut data = Data::new(data);
+        let point: Point2D = from_bytes_ref(&mut data).unwrap();
+        let point: geo_types::Point<f64> = point.try_into().unwrap();
+
+        assert_approx_eq!(point.x(), 12.994341);
+        assert_approx_eq!(point.y(), 55.611784);
+
+        let data = bolt()
+            .structure(4, 0x59)
+            .int16(9157)
+            .float(1.0)
+            .float(2.0)
+            .float(3.0)
+            .build();
+        let mut data = Data::new(data);
+        let point: Point3D = from_bytes_ref(&mut data).unwrap();
+        let error = geo_types::Point::<f64>::try_from(point.to_point()).unwrap_err();
+
+        assert_eq!(
+            error,
+            PointConversionError::NotTwoDimensional(Crs::Cartesian3D)
+        );
+    }
 }
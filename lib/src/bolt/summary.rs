@@ -1,11 +1,13 @@
-use std::{fmt, marker::PhantomData};
+use std::{collections::HashMap, fmt, marker::PhantomData};
 
 use serde::{
     de::{self, VariantAccess as _, Visitor},
     Deserialize,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use crate::{errors::GqlError, BoltType};
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Summary<R> {
     Success(Success<R>),
     Ignored,
@@ -30,16 +32,55 @@ pub struct Success<R> {
     pub(crate) metadata: R,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "FailureWire")]
 pub struct Failure {
     pub(crate) code: String,
     pub(crate) message: String,
+    pub(crate) gql: Option<Box<GqlError>>,
 }
 
 impl Failure {
     pub fn into_error(self) -> crate::errors::Error {
-        let Self { code, message } = self;
-        crate::errors::Error::Neo4j(crate::errors::Neo4jError::new(code, message))
+        let Self { code, message, gql } = self;
+        let error = crate::errors::Neo4jError::new(code, message);
+        let error = match gql {
+            Some(gql) => error.with_gql(*gql),
+            None => error,
+        };
+        crate::errors::Error::Neo4j(error)
+    }
+}
+
+#[derive(Deserialize)]
+struct FailureWire {
+    // the code has been renamed to `neo4j_code` in Bolt 5.7
+    #[serde(alias = "neo4j_code")]
+    code: String,
+    message: String,
+    gql_status: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    diagnostic_record: HashMap<String, BoltType>,
+    cause: Option<Box<GqlError>>,
+}
+
+impl From<FailureWire> for Failure {
+    fn from(value: FailureWire) -> Self {
+        let gql = value.gql_status.map(|gql_status| {
+            Box::new(GqlError::new(
+                gql_status,
+                value.description.unwrap_or_default(),
+                value.message.clone(),
+                value.diagnostic_record,
+                value.cause,
+            ))
+        });
+        Failure {
+            code: value.code,
+            message: value.message,
+            gql,
+        }
     }
 }
 
@@ -134,4 +175,63 @@ mod tests {
             "The client is unauthorized due to authentication failure."
         );
     }
+
+    #[test]
+    fn parse_gql_failure() {
+        let data = bolt()
+            .structure(1, 0x7F)
+            .tiny_map(6)
+            .tiny_string("neo4j_code")
+            .string8("Neo.ClientError.Statement.SyntaxError")
+            .tiny_string("message")
+            .tiny_string("Invalid input")
+            .tiny_string("gql_status")
+            .tiny_string("42001")
+            .tiny_string("description")
+            .string8("error: syntax error or access rule violation")
+            .string8("diagnostic_record")
+            .tiny_map(1)
+            .tiny_string("OPERATION")
+            .tiny_string("")
+            .tiny_string("cause")
+            .tiny_map(3)
+            .tiny_string("gql_status")
+            .tiny_string("42I06")
+            .tiny_string("description")
+            .string8("error: syntax error or access rule violation - invalid input")
+            .tiny_string("message")
+            .tiny_string("Invalid input")
+            .build();
+
+        let failure = match Summary::<()>::parse(data).unwrap() {
+            Summary::Failure(failure) => failure,
+            _ => panic!("Expected failure"),
+        };
+
+        let error = match failure.into_error() {
+            crate::Error::Neo4j(error) => error,
+            otherwise => panic!("Expected Neo4j error, got {otherwise:?}"),
+        };
+
+        assert_eq!(error.code(), "Neo.ClientError.Statement.SyntaxError");
+        assert_eq!(error.message(), "Invalid input");
+        assert_eq!(error.gql_status(), Some("42001"));
+        assert_eq!(
+            error.description(),
+            Some("error: syntax error or access rule violation")
+        );
+        assert_eq!(
+            error.diagnostic_record().unwrap().get("OPERATION"),
+            Some(&BoltType::from(""))
+        );
+
+        let cause = error.cause().unwrap();
+        assert_eq!(cause.gql_status(), "42I06");
+        assert!(cause.diagnostic_record().is_empty());
+        assert_eq!(cause.cause(), None);
+
+        let error = crate::Error::Neo4j(error);
+        let source = std::error::Error::source(&error).unwrap();
+        assert_eq!(source.to_string(), "GQL error `42I06`: Invalid input");
+    }
 }
@@ -1,33 +1,41 @@
-use crate::auth::ConnectionTLSConfig;
+use crate::auth::{ConnectionTLSConfig, ReloadableTls};
 #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
 use crate::messages::HelloBuilder;
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 use {
     crate::bolt::{
-        ConnectionsHints, ExpectedResponse, Hello, HelloBuilder, Message, MessageResponse, Reset,
-        Summary,
+        ConnectionsHints, ExpectedResponse, Hello, HelloBuilder, Logon, Message, MessageResponse,
+        Reset, Summary,
     },
     log::debug,
 };
 
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 use crate::routing::{Route, RoutingTable};
+#[cfg(feature = "websocket")]
+use crate::websocket::WebSocketStream;
 use crate::{
+    config::Config,
     connection::stream::ConnectionStream,
     errors::{Error, Result},
     messages::{BoltRequest, BoltResponse},
+    notification::NotificationFilter,
+    proxy::Proxy,
+    recorder::{ConnectionRecorder, WireRecorder},
+    socket::{self, SocketOptions},
     version::Version,
     BoltMap, BoltString, BoltType,
 };
 use bytes::{BufMut, Bytes, BytesMut};
 use log::{info, warn};
+use percent_encoding::percent_decode_str;
 use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
 use rustls::crypto::CryptoProvider;
-use rustls::pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer, UnixTime};
+use rustls::pki_types::{CertificateDer, UnixTime};
 use rustls::{DigitallySignedStruct, SignatureScheme};
 use std::fmt::{Debug, Display, Formatter};
 use std::time::Duration;
-use std::{fs::File, io::BufReader, mem, sync::Arc};
+use std::{mem, path::Path, sync::Arc};
 use tokio::{
     io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufStream},
     net::TcpStream,
@@ -41,7 +49,10 @@ use tokio_rustls::{
 };
 use url::{Host, Url};
 
-const MAX_CHUNK_SIZE: usize = 65_535 - mem::size_of::<u16>();
+pub(crate) const MAX_CHUNK_SIZE: usize = 65_535 - mem::size_of::<u16>();
+
+/// The signature of RECORD messages, the only responses that do not complete a request.
+const RECORD_SIGNATURE: u8 = 0x71;
 
 #[derive(Debug)]
 pub struct Connection {
@@ -49,6 +60,11 @@ pub struct Connection {
     stream: BufStream<ConnectionStream>,
     /// Timeout applied to recv operations to prevent hanging on broken connections.
     recv_timeout: Duration,
+    /// The number of message bytes received so far, used to measure the size of rows.
+    bytes_received: usize,
+    /// The number of requests that have been written but whose response has not been received yet.
+    pending: usize,
+    recorder: Option<ConnectionRecorder>,
     #[allow(unused)]
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     hints: Option<ConnectionsHints>,
@@ -59,6 +75,9 @@ impl Connection {
         let mut connection = Self::prepare(&info.prepare).await?;
         let hello = info.init.to_hello(connection.version);
         connection.hello(hello).await?;
+        if connection.version >= Version::V5_1 {
+            connection.logon(info.init.to_logon()).await?;
+        }
         Ok(connection)
     }
 
@@ -67,26 +86,52 @@ impl Connection {
     }
 
     /// Configure TCP keepalive on the given `TcpStream` using `socket2`.
-    fn configure_tcp_keepalive(stream: &TcpStream, keepalive: Option<Duration>) -> Result<()> {
+    fn configure_tcp_keepalive(
+        stream: &TcpStream,
+        keepalive: Option<Duration>,
+        options: &SocketOptions,
+    ) -> Result<()> {
         if let Some(interval) = keepalive {
             let sock_ref = socket2::SockRef::from(stream);
             let keepalive = socket2::TcpKeepalive::new()
                 .with_time(interval)
-                .with_interval(Duration::from_secs(10));
+                .with_interval(options.keepalive_interval);
             #[cfg(not(windows))]
-            let keepalive = keepalive.with_retries(3);
+            let keepalive = keepalive.with_retries(options.keepalive_retries);
             sock_ref.set_tcp_keepalive(&keepalive)?;
         }
         Ok(())
     }
 
     pub(crate) async fn prepare(opts: &PrepareOpts) -> Result<Self> {
+        let mut connection = match &opts.unix_socket {
+            #[cfg(unix)]
+            Some(path) => Self::connect_unix(path, opts.connection_timeout).await?,
+            _ => Self::connect_tcp(opts).await?,
+        };
+        connection.recorder = opts
+            .wire_recorder
+            .as_ref()
+            .map(|recorder| recorder.connection(connection.version));
+        Ok(connection)
+    }
+
+    /// Connects to a Unix domain socket, which is never encrypted.
+    #[cfg(unix)]
+    async fn connect_unix(path: &std::path::Path, timeout_dur: Duration) -> Result<Self> {
+        let mut stream = tokio::time::timeout(timeout_dur, tokio::net::UnixStream::connect(path))
+            .await
+            .map_err(|_| Error::ConnectionTimedOut)??;
+        let version = Self::init(&mut stream).await?;
+        Ok(Self::create(stream, version, timeout_dur))
+    }
+
+    async fn connect_tcp(opts: &PrepareOpts) -> Result<Self> {
         let timeout_dur = opts.connection_timeout;
         let connect_future = async {
-            match &opts.host {
-                Host::Domain(domain) => TcpStream::connect((&**domain, opts.port)).await,
-                Host::Ipv4(ip) => TcpStream::connect((*ip, opts.port)).await,
-                Host::Ipv6(ip) => TcpStream::connect((*ip, opts.port)).await,
+            match &opts.proxy {
+                Some(proxy) => proxy.connect(&opts.host, opts.port, &opts.socket).await,
+                None => Ok(socket::connect(&opts.host, opts.port, &opts.socket).await?),
             }
         };
         let stream = tokio::time::timeout(timeout_dur, connect_future)
@@ -94,20 +139,28 @@ impl Connection {
             .map_err(|_| Error::ConnectionTimedOut)??;
 
         // Configure TCP keepalive on the raw socket
-        Self::configure_tcp_keepalive(&stream, opts.tcp_keepalive)?;
+        Self::configure_tcp_keepalive(&stream, opts.tcp_keepalive, &opts.socket)?;
+
+        let stream = match &opts.encryption {
+            Some((tls, domain)) => ConnectionStream::from(
+                tls.connector()
+                    .await
+                    .connect(domain.clone(), stream)
+                    .await?,
+            ),
+            None => ConnectionStream::from(stream),
+        };
+        #[cfg(feature = "websocket")]
+        let stream = if opts.websocket {
+            let host = format!("{}:{}", opts.host, opts.port);
+            ConnectionStream::from(WebSocketStream::connect(Box::new(stream), &host).await?)
+        } else {
+            stream
+        };
 
-        Ok(match &opts.encryption {
-            Some((connector, domain)) => {
-                let mut stream = connector.connect(domain.clone(), stream).await?;
-                let version = Self::init(&mut stream).await?;
-                Self::create(stream, version, timeout_dur)
-            }
-            None => {
-                let mut stream = stream;
-                let version = Self::init(&mut stream).await?;
-                Self::create(stream, version, timeout_dur)
-            }
-        })
+        let mut stream = stream;
+        let version = Self::init(&mut stream).await?;
+        Ok(Self::create(stream, version, timeout_dur))
     }
 
     async fn init<A: AsyncWrite + AsyncRead + Unpin>(stream: &mut A) -> Result<Version> {
@@ -130,6 +183,9 @@ impl Connection {
             version,
             stream: BufStream::new(stream.into()),
             recv_timeout,
+            bytes_received: 0,
+            pending: 0,
+            recorder: None,
             #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
             hints: None,
         }
@@ -153,6 +209,26 @@ impl Connection {
         }
     }
 
+    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+    async fn logon(&mut self, req: BoltRequest) -> Result<()> {
+        match self.send_recv(req).await? {
+            BoltResponse::Success(_msg) => Ok(()),
+            BoltResponse::Failure(msg) => {
+                Err(Error::AuthenticationError(msg.get("message").unwrap()))
+            }
+            msg => Err(msg.into_error("LOGON")),
+        }
+    }
+
+    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+    async fn logon(&mut self, logon: Logon<'_>) -> Result<()> {
+        match self.send_recv_as(logon).await? {
+            Summary::Success(_) => Ok(()),
+            Summary::Ignored => Err(Error::RequestIgnoredError),
+            Summary::Failure(msg) => Err(Error::AuthenticationError(msg.message)),
+        }
+    }
+
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     async fn hello(&mut self, hello: Hello<'_>) -> Result<()> {
         let hello = self.send_recv_as(hello).await?;
@@ -179,10 +255,16 @@ impl Connection {
         }
     }
 
+    /// Resets the connection to a clean state.
+    ///
+    /// The responses of all requests that have been sent before are discarded,
+    /// so that the connection can be reused after a pipeline was not read to completion.
     pub async fn reset(&mut self) -> Result<()> {
         #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
         {
-            match self.send_recv(BoltRequest::reset()).await? {
+            self.send(BoltRequest::reset()).await?;
+            self.skip_responses(1).await?;
+            match self.recv().await? {
                 BoltResponse::Success(_) => Ok(()),
                 BoltResponse::Failure(f) => Err(Error::Neo4j(f.into_error())),
                 msg => Err(msg.into_error("RESET")),
@@ -191,7 +273,12 @@ impl Connection {
 
         #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
         {
-            match self.send_recv_as(Reset).await? {
+            self.send_as(Reset).await?;
+            self.skip_responses(1).await?;
+            match self
+                .recv_as::<<Reset as ExpectedResponse>::Response>()
+                .await?
+            {
                 Summary::Success(_) => Ok(()),
                 Summary::Failure(err) => Err(Error::ConnectionClosed(err)),
                 msg => Err(Error::UnexpectedMessage(format!(
@@ -218,15 +305,47 @@ impl Connection {
     }
 
     pub async fn send(&mut self, message: BoltRequest) -> Result<()> {
-        let bytes: Bytes = message.into_bytes(self.version)?;
-        self.send_bytes(bytes).await
+        self.write(message).await?;
+        self.flush().await
     }
 
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     #[allow(unused)]
     pub(crate) async fn send_as<T: Message>(&mut self, message: T) -> Result<()> {
+        self.write_as(message).await?;
+        self.flush().await
+    }
+
+    /// Writes a request without sending it, so that multiple requests can be pipelined.
+    /// The requests are sent with the next [`Self::flush`] or [`Self::send`],
+    /// and their responses are received in the same order.
+    pub(crate) async fn write(&mut self, message: BoltRequest) -> Result<()> {
+        let bytes: Bytes = message.into_bytes(self.version)?;
+        self.write_bytes(bytes).await
+    }
+
+    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+    #[allow(unused)]
+    pub(crate) async fn write_as<T: Message>(&mut self, message: T) -> Result<()> {
         let bytes = message.to_bytes()?;
-        self.send_bytes(bytes).await
+        self.write_bytes(bytes).await
+    }
+
+    /// Sends all requests that have been written.
+    pub(crate) async fn flush(&mut self) -> Result<()> {
+        self.stream.flush().await?;
+        Ok(())
+    }
+
+    /// Receives and discards responses until only `keep` requests are waiting for a response.
+    ///
+    /// This is used to skip the responses of pipelined requests after one of them failed,
+    /// at which point the server ignores all following requests.
+    pub(crate) async fn skip_responses(&mut self, keep: usize) -> Result<()> {
+        while self.pending > keep {
+            self.recv_bytes().await?;
+        }
+        Ok(())
     }
 
     pub async fn recv(&mut self) -> Result<BoltResponse> {
@@ -243,15 +362,23 @@ impl Connection {
         Ok(T::parse(bytes)?)
     }
 
-    async fn send_bytes(&mut self, bytes: Bytes) -> Result<()> {
+    /// The number of message bytes received on this connection, wrapping on overflow.
+    pub(crate) fn bytes_received(&self) -> usize {
+        self.bytes_received
+    }
+
+    async fn write_bytes(&mut self, bytes: Bytes) -> Result<()> {
         Self::dbg("send", &bytes);
+        if let Some(recorder) = &self.recorder {
+            recorder.sent(self.version, &bytes);
+        }
         let end_marker: [u8; 2] = [0, 0];
         for c in bytes.chunks(MAX_CHUNK_SIZE) {
             self.stream.write_u16(c.len() as u16).await?;
             self.stream.write_all(c).await?;
         }
         self.stream.write_all(&end_marker).await?;
-        self.stream.flush().await?;
+        self.pending += 1;
         Ok(())
     }
 
@@ -268,7 +395,14 @@ impl Connection {
         }
 
         let bytes = bytes.freeze();
+        self.bytes_received = self.bytes_received.wrapping_add(bytes.len());
+        if bytes.get(1) != Some(&RECORD_SIGNATURE) {
+            self.pending = self.pending.saturating_sub(1);
+        }
         Self::dbg("recv", &bytes);
+        if let Some(recorder) = &self.recorder {
+            recorder.received(&bytes);
+        }
         Ok(bytes)
     }
 
@@ -340,9 +474,16 @@ impl Display for Routing {
 pub(crate) struct PrepareOpts {
     pub(crate) host: Host<Arc<str>>,
     pub(crate) port: u16,
-    pub(crate) encryption: Option<(TlsConnector, ServerName<'static>)>,
+    pub(crate) encryption: Option<(Tls, ServerName<'static>)>,
+    /// The path of a Unix domain socket that is used instead of the host and port.
+    pub(crate) unix_socket: Option<Arc<Path>>,
+    /// Whether Bolt is carried in WebSocket messages.
+    pub(crate) websocket: bool,
     pub(crate) connection_timeout: Duration,
     pub(crate) tcp_keepalive: Option<Duration>,
+    pub(crate) wire_recorder: Option<WireRecorder>,
+    pub(crate) proxy: Option<Proxy>,
+    pub(crate) socket: SocketOptions,
 }
 
 impl Debug for PrepareOpts {
@@ -351,15 +492,38 @@ impl Debug for PrepareOpts {
             .field("host", &self.host)
             .field("port", &self.port)
             .field("encryption", &self.encryption.is_some())
+            .field("unix_socket", &self.unix_socket)
+            .field("websocket", &self.websocket)
+            .field("wire_recorder", &self.wire_recorder.is_some())
+            .field("proxy", &self.proxy)
+            .field("socket", &self.socket)
             .finish()
     }
 }
 
+/// The TLS configuration of new connections.
+#[derive(Clone)]
+pub(crate) enum Tls {
+    Fixed(TlsConnector),
+    /// Checked for a new configuration whenever a connection is opened.
+    Reloadable(ReloadableTls),
+}
+
+impl Tls {
+    async fn connector(&self) -> TlsConnector {
+        match self {
+            Tls::Fixed(connector) => connector.clone(),
+            Tls::Reloadable(reloadable) => TlsConnector::from(reloadable.current().await),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct InitOpts {
     pub(crate) user: Arc<str>,
     pub(crate) password: Arc<str>,
     pub(crate) routing: Routing,
+    pub(crate) notifications: NotificationFilter,
 }
 
 impl Debug for InitOpts {
@@ -368,6 +532,7 @@ impl Debug for InitOpts {
             .field("user", &self.user)
             .field("password", &"***")
             .field("routing", &self.routing)
+            .field("notifications", &self.notifications)
             .finish()
     }
 }
@@ -377,14 +542,27 @@ impl InitOpts {
     pub(crate) fn to_hello(&self, version: Version) -> BoltRequest {
         HelloBuilder::new(&*self.user, &*self.password)
             .with_routing(self.routing.clone())
+            .with_notifications(&self.notifications)
             .build(version)
     }
 
+    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+    pub(crate) fn to_logon(&self) -> BoltRequest {
+        BoltRequest::logon((&*self.user).into(), (&*self.password).into())
+    }
+
+    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+    pub(crate) fn to_logon(&self) -> Logon<'_> {
+        Logon::new(&self.user, &self.password)
+    }
+
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     pub(crate) fn to_hello(&self, version: Version) -> Hello<'_> {
+        let hello =
+            HelloBuilder::new(&self.user, &self.password).with_notifications(&self.notifications);
         match self.routing {
-            Routing::No => HelloBuilder::new(&self.user, &self.password).build(version),
-            Routing::Yes(ref routing) => HelloBuilder::new(&self.user, &self.password)
+            Routing::No => hello.build(version),
+            Routing::Yes(ref routing) => hello
                 .with_routing(
                     routing
                         .iter()
@@ -415,14 +593,20 @@ impl Debug for ConnectionInfo {
 }
 
 impl ConnectionInfo {
-    pub(crate) fn new(
-        uri: &str,
-        user: &str,
-        password: &str,
-        tls_config: &ConnectionTLSConfig,
-        connection_timeout: Duration,
-        tcp_keepalive: Option<Duration>,
-    ) -> Result<Self> {
+    pub(crate) fn new(config: &Config) -> Result<Self> {
+        let Config {
+            uri,
+            user,
+            password,
+            tls_config,
+            connection_timeout,
+            tcp_keepalive,
+            notifications,
+            wire_recorder,
+            proxy,
+            socket,
+            ..
+        } = config;
         let mut url = NeoUrl::parse(uri)?;
 
         let (routing, encryption, validation) = match url.scheme() {
@@ -432,6 +616,12 @@ impl ConnectionInfo {
             "neo4j" => (true, false, false),
             "neo4j+s" => (true, true, true),
             "neo4j+ssc" => (true, true, false),
+            #[cfg(unix)]
+            "bolt+unix" => (false, false, false),
+            #[cfg(feature = "websocket")]
+            "bolt+ws" => (false, false, false),
+            #[cfg(feature = "websocket")]
+            "bolt+wss" => (false, true, true),
             otherwise => return Err(Error::UnsupportedScheme(otherwise.to_owned())),
         };
 
@@ -443,6 +633,10 @@ impl ConnectionInfo {
                         ConnectionTLSConfig::MutualTLS(mtls) => {
                             ConnectionTLSConfig::MutualTLS(mtls.with_no_validation())
                         }
+                        // a custom configuration decides itself how certificates are validated
+                        custom @ (ConnectionTLSConfig::Custom(_)
+                        | ConnectionTLSConfig::Verifier(_)
+                        | ConnectionTLSConfig::Reloadable(_)) => custom.clone(),
                         _ => ConnectionTLSConfig::NoSSLValidation,
                     }
                 } else {
@@ -475,14 +669,20 @@ impl ConnectionInfo {
             host,
             port: url.port(),
             encryption,
-            connection_timeout,
-            tcp_keepalive,
+            unix_socket: url.unix_socket(),
+            websocket: matches!(url.scheme(), "bolt+ws" | "bolt+wss"),
+            connection_timeout: *connection_timeout,
+            tcp_keepalive: *tcp_keepalive,
+            wire_recorder: wire_recorder.clone(),
+            proxy: proxy.clone(),
+            socket: socket.clone(),
         };
 
         let init = InitOpts {
-            user: user.into(),
-            password: password.into(),
+            user: user.as_str().into(),
+            password: password.as_str().into(),
             routing,
+            notifications: notifications.clone(),
         };
 
         Ok(Self { prepare, init })
@@ -491,7 +691,15 @@ impl ConnectionInfo {
     fn tls_connector(
         host: Host<&str>,
         tls_config: &ConnectionTLSConfig,
-    ) -> Result<(TlsConnector, ServerName<'static>)> {
+    ) -> Result<(Tls, ServerName<'static>)> {
+        let tls = match tls_config {
+            ConnectionTLSConfig::Reloadable(reloadable) => Tls::Reloadable(reloadable.clone()),
+            tls_config => Tls::Fixed(TlsConnector::from(Self::client_config(tls_config)?)),
+        };
+        Ok((tls, Self::server_name(host)?))
+    }
+
+    pub(crate) fn client_config(tls_config: &ConnectionTLSConfig) -> Result<Arc<ClientConfig>> {
         let mut root_cert_store = RootCertStore::empty();
 
         let builder = ClientConfig::builder();
@@ -510,10 +718,7 @@ impl ConnectionInfo {
                     .with_no_client_auth()
             }
             ConnectionTLSConfig::ClientCACertificate(certificate) => {
-                let cert_file = File::open(&certificate.cert_file)?;
-                let reader = BufReader::new(cert_file);
-                let certs = CertificateDer::pem_reader_iter(reader).flatten();
-                root_cert_store.add_parsable_certificates(certs);
+                root_cert_store.add_parsable_certificates(certificate.certs.load()?);
                 builder
                     .with_root_certificates(root_cert_store)
                     .with_no_client_auth()
@@ -523,24 +728,13 @@ impl ConnectionInfo {
                 .with_custom_certificate_verifier(Arc::new(NoCertificateVerification))
                 .with_no_client_auth(),
             ConnectionTLSConfig::MutualTLS(mutual) => {
-                let cert_file = File::open(&mutual.client_cert)?;
-                let cert_reader = BufReader::new(cert_file);
-                let cert_certs = CertificateDer::pem_reader_iter(cert_reader).flatten();
-
-                let cert_key = File::open(&mutual.client_key)?;
-                let key_reader = BufReader::new(cert_key);
-                let keys = PrivateKeyDer::pem_reader_iter(key_reader)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(std::io::Error::other);
-                let keys = keys?.pop().unwrap();
-                if mutual.cert_file.is_some() {
-                    let root_cert_file = File::open(mutual.cert_file.as_ref().unwrap())?;
-                    let root_reader = BufReader::new(root_cert_file);
-                    let root_certs = CertificateDer::pem_reader_iter(root_reader).flatten();
-                    root_cert_store.add_parsable_certificates(root_certs);
+                let cert_certs = mutual.client_cert.load()?;
+                let keys = mutual.client_key.load()?;
+                if let Some(cert_file) = mutual.cert_file.as_ref() {
+                    root_cert_store.add_parsable_certificates(cert_file.load()?);
                     builder
                         .with_root_certificates(root_cert_store)
-                        .with_client_auth_cert(cert_certs.collect(), keys)
+                        .with_client_auth_cert(cert_certs, keys)
                         .map_err(|_e| Error::ConnectionError)?
                 } else if mutual.validation {
                     let native_certs = rustls_native_certs::load_native_certs();
@@ -553,29 +747,35 @@ impl ConnectionInfo {
                     root_cert_store.add_parsable_certificates(native_certs.certs);
                     builder
                         .with_root_certificates(root_cert_store)
-                        .with_client_auth_cert(cert_certs.collect(), keys)
+                        .with_client_auth_cert(cert_certs, keys)
                         .map_err(|_e| Error::ConnectionError)?
                 } else {
                     builder
                         .dangerous()
                         .with_custom_certificate_verifier(Arc::new(NoCertificateVerification))
-                        .with_client_auth_cert(cert_certs.collect(), keys)
+                        .with_client_auth_cert(cert_certs, keys)
                         .map_err(|_e| Error::ConnectionError)?
                 }
             }
+            ConnectionTLSConfig::Verifier(verifier) => builder
+                .dangerous()
+                .with_custom_certificate_verifier(verifier.0.clone())
+                .with_no_client_auth(),
+            ConnectionTLSConfig::Custom(config) => return Ok(config.0.clone()),
+            ConnectionTLSConfig::Reloadable(reloadable) => return Ok(reloadable.refresh()),
         };
 
-        let config = Arc::new(config);
-        let connector = TlsConnector::from(config);
+        Ok(Arc::new(config))
+    }
 
+    fn server_name(host: Host<&str>) -> Result<ServerName<'static>> {
         let domain = match host {
             Host::Domain(domain) => ServerName::try_from(domain.to_owned())
                 .map_err(|_| Error::InvalidDnsName(domain.to_owned()))?,
             Host::Ipv4(ip) => ServerName::IpAddress(IpAddr::V4(Ipv4Addr::from(ip))),
             Host::Ipv6(ip) => ServerName::IpAddress(IpAddr::V6(Ipv6Addr::from(ip))),
         };
-
-        Ok((connector, domain))
+        Ok(domain)
     }
 }
 
@@ -585,7 +785,7 @@ pub struct NeoUrl(Url);
 impl NeoUrl {
     pub(crate) fn parse(uri: &str) -> Result<Self> {
         let url = match Url::parse(uri) {
-            Ok(url) if url.has_host() => url,
+            Ok(url) if url.has_host() || url.scheme() == "bolt+unix" => url,
             // missing scheme
             Ok(_) | Err(url::ParseError::RelativeUrlWithoutBase) => {
                 Url::parse(&format!("bolt://{uri}"))?
@@ -593,6 +793,13 @@ impl NeoUrl {
             Err(err) => return Err(Error::UrlParseError(err)),
         };
 
+        // the socket is always local, a host would be silently ignored
+        if let Some(host) = url.host_str().filter(|host| !host.is_empty()) {
+            if url.scheme() == "bolt+unix" {
+                return Err(Error::UnexpectedUnixSocketHost(host.to_owned()));
+            }
+        }
+
         Ok(Self(url))
     }
 
@@ -601,7 +808,8 @@ impl NeoUrl {
     }
 
     pub(crate) fn host(&self) -> Host<&str> {
-        self.0.host().unwrap()
+        // the URI of a Unix domain socket has no host, the server is always local
+        self.0.host().unwrap_or(Host::Domain("localhost"))
     }
 
     pub(crate) fn port(&self) -> u16 {
@@ -615,6 +823,22 @@ impl NeoUrl {
         )]
     }
 
+    /// The path of the socket of a `bolt+unix:///path/to/socket` URI.
+    pub(crate) fn unix_socket(&self) -> Option<Arc<Path>> {
+        if self.scheme() != "bolt+unix" {
+            return None;
+        }
+        let path = percent_decode_str(self.0.path()).collect::<Vec<u8>>();
+        #[cfg(unix)]
+        let path = {
+            use std::os::unix::ffi::OsStrExt;
+            Path::new(std::ffi::OsStr::from_bytes(&path)).into()
+        };
+        #[cfg(not(unix))]
+        let path = Path::new(&*String::from_utf8_lossy(&path)).into();
+        Some(path)
+    }
+
     fn warn_on_unexpected_components(&self) {
         if !self.0.username().is_empty() || self.0.password().is_some() {
             warn!(concat!(
@@ -622,7 +846,7 @@ impl NeoUrl {
                 "Credentials are passed outside of the URI"
             ));
         }
-        if !matches!(self.0.path(), "" | "/") {
+        if !matches!(self.0.path(), "" | "/") && self.unix_socket().is_none() {
             warn!("URI contained a path, which is ignored.");
         }
 
@@ -646,20 +870,39 @@ impl Display for NeoUrl {
 }
 
 mod stream {
-    use pin_project_lite::pin_project;
+    #[cfg(feature = "websocket")]
+    use crate::websocket::WebSocketStream;
+    use std::{
+        io::{self, IoSlice},
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    #[cfg(unix)]
+    use tokio::net::UnixStream;
     use tokio::{
-        io::{AsyncRead, AsyncWrite},
+        io::{AsyncRead, AsyncWrite, ReadBuf},
         net::TcpStream,
     };
     use tokio_rustls::client::TlsStream;
 
-    pin_project! {
-        #[project = ConnectionStreamProj]
-        #[derive(Debug)]
-        pub(super) enum ConnectionStream {
-            Unencrypted { #[pin] stream: TcpStream },
-            Encrypted { #[pin] stream: TlsStream<TcpStream> },
-        }
+    // All streams are `Unpin`, so they can be pinned in place without a projection.
+    #[derive(Debug)]
+    #[allow(clippy::large_enum_variant)]
+    pub(super) enum ConnectionStream {
+        Unencrypted {
+            stream: TcpStream,
+        },
+        Encrypted {
+            stream: TlsStream<TcpStream>,
+        },
+        #[cfg(unix)]
+        Unix {
+            stream: UnixStream,
+        },
+        #[cfg(feature = "websocket")]
+        WebSocket {
+            stream: WebSocketStream<Box<ConnectionStream>>,
+        },
     }
 
     impl From<TcpStream> for ConnectionStream {
@@ -674,69 +917,74 @@ mod stream {
         }
     }
 
+    #[cfg(unix)]
+    impl From<UnixStream> for ConnectionStream {
+        fn from(stream: UnixStream) -> Self {
+            ConnectionStream::Unix { stream }
+        }
+    }
+
+    #[cfg(feature = "websocket")]
+    impl From<WebSocketStream<Box<ConnectionStream>>> for ConnectionStream {
+        fn from(stream: WebSocketStream<Box<ConnectionStream>>) -> Self {
+            ConnectionStream::WebSocket { stream }
+        }
+    }
+
+    /// Calls the same method on the stream of every variant.
+    macro_rules! delegate {
+        ($self:expr, $stream:ident => $call:expr) => {
+            match $self {
+                ConnectionStream::Unencrypted { $stream } => $call,
+                ConnectionStream::Encrypted { $stream } => $call,
+                #[cfg(unix)]
+                ConnectionStream::Unix { $stream } => $call,
+                #[cfg(feature = "websocket")]
+                ConnectionStream::WebSocket { $stream } => $call,
+            }
+        };
+    }
+
     impl AsyncRead for ConnectionStream {
         fn poll_read(
-            self: std::pin::Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-            buf: &mut tokio::io::ReadBuf<'_>,
-        ) -> std::task::Poll<std::io::Result<()>> {
-            match self.project() {
-                ConnectionStreamProj::Unencrypted { stream } => stream.poll_read(cx, buf),
-                ConnectionStreamProj::Encrypted { stream } => stream.poll_read(cx, buf),
-            }
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            delegate!(self.get_mut(), stream => Pin::new(stream).poll_read(cx, buf))
         }
     }
 
     impl AsyncWrite for ConnectionStream {
         fn poll_write(
-            self: std::pin::Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
             buf: &[u8],
-        ) -> std::task::Poll<Result<usize, std::io::Error>> {
-            match self.project() {
-                ConnectionStreamProj::Unencrypted { stream } => stream.poll_write(cx, buf),
-                ConnectionStreamProj::Encrypted { stream } => stream.poll_write(cx, buf),
-            }
+        ) -> Poll<Result<usize, io::Error>> {
+            delegate!(self.get_mut(), stream => Pin::new(stream).poll_write(cx, buf))
         }
 
-        fn poll_flush(
-            self: std::pin::Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-        ) -> std::task::Poll<Result<(), std::io::Error>> {
-            match self.project() {
-                ConnectionStreamProj::Unencrypted { stream } => stream.poll_flush(cx),
-                ConnectionStreamProj::Encrypted { stream } => stream.poll_flush(cx),
-            }
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+            delegate!(self.get_mut(), stream => Pin::new(stream).poll_flush(cx))
         }
 
         fn poll_shutdown(
-            self: std::pin::Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-        ) -> std::task::Poll<Result<(), std::io::Error>> {
-            match self.project() {
-                ConnectionStreamProj::Unencrypted { stream } => stream.poll_shutdown(cx),
-                ConnectionStreamProj::Encrypted { stream } => stream.poll_shutdown(cx),
-            }
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), io::Error>> {
+            delegate!(self.get_mut(), stream => Pin::new(stream).poll_shutdown(cx))
         }
 
         fn poll_write_vectored(
-            self: std::pin::Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-            bufs: &[std::io::IoSlice<'_>],
-        ) -> std::task::Poll<Result<usize, std::io::Error>> {
-            match self.project() {
-                ConnectionStreamProj::Unencrypted { stream } => {
-                    stream.poll_write_vectored(cx, bufs)
-                }
-                ConnectionStreamProj::Encrypted { stream } => stream.poll_write_vectored(cx, bufs),
-            }
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[IoSlice<'_>],
+        ) -> Poll<Result<usize, io::Error>> {
+            delegate!(self.get_mut(), stream => Pin::new(stream).poll_write_vectored(cx, bufs))
         }
 
         fn is_write_vectored(&self) -> bool {
-            match self {
-                ConnectionStream::Unencrypted { stream } => stream.is_write_vectored(),
-                ConnectionStream::Encrypted { stream } => stream.is_write_vectored(),
-            }
+            delegate!(self, stream => stream.is_write_vectored())
         }
     }
 }
@@ -791,9 +1039,98 @@ impl ServerCertVerifier for NoCertificateVerification {
 
 #[cfg(test)]
 mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
     use url::Host;
 
-    use super::NeoUrl;
+    use super::{Connection, ConnectionInfo, NeoUrl};
+    use crate::{
+        messages::{BoltRequest, BoltResponse},
+        version::Version,
+        BoltMap,
+    };
+
+    const SUCCESS: &[u8] = &[0xB1, 0x70, 0xA0];
+    const RECORD: &[u8] = &[0xB1, 0x71, 0x91, 0x01];
+
+    /// Reads a chunked message and returns its signature.
+    async fn read_message(stream: &mut TcpStream) -> u8 {
+        let mut message = Vec::new();
+        loop {
+            let size = stream.read_u16().await.unwrap() as usize;
+            if size == 0 {
+                break message[1];
+            }
+            let mut chunk = vec![0; size];
+            stream.read_exact(&mut chunk).await.unwrap();
+            message.extend(chunk);
+        }
+    }
+
+    async fn write_message(stream: &mut TcpStream, message: &[u8]) {
+        stream.write_u16(message.len() as u16).await.unwrap();
+        stream.write_all(message).await.unwrap();
+        stream.write_u16(0).await.unwrap();
+    }
+
+    /// Starts a server that expects the given request signatures and then sends the responses.
+    async fn connect(requests: Vec<u8>, responses: Vec<&'static [u8]>) -> Connection {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            for request in requests {
+                assert_eq!(read_message(&mut stream).await, request);
+            }
+            for response in responses {
+                write_message(&mut stream, response).await;
+            }
+            stream.flush().await.unwrap();
+        });
+        let stream = TcpStream::connect(address).await.unwrap();
+        Connection::create(stream, Version::V4_1, std::time::Duration::from_secs(5))
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn should_pipeline_requests() {
+        let mut connection = connect(vec![0x10, 0x3F], vec![SUCCESS, RECORD, SUCCESS]).await;
+
+        let run = BoltRequest::run("RETURN 1", BoltMap::default(), BoltMap::default());
+        connection.write(run).await.unwrap();
+        connection.write(BoltRequest::pull(1, -1)).await.unwrap();
+        connection.flush().await.unwrap();
+        assert_eq!(connection.pending, 2);
+
+        assert!(matches!(
+            connection.recv().await,
+            Ok(BoltResponse::Success(_))
+        ));
+        assert_eq!(connection.pending, 1);
+        assert!(matches!(
+            connection.recv().await,
+            Ok(BoltResponse::Record(_))
+        ));
+        assert_eq!(connection.pending, 1);
+        assert!(matches!(
+            connection.recv().await,
+            Ok(BoltResponse::Success(_))
+        ));
+        assert_eq!(connection.pending, 0);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn should_skip_pending_responses_on_reset() {
+        let mut connection =
+            connect(vec![0x3F, 0x0F], vec![RECORD, RECORD, SUCCESS, SUCCESS]).await;
+
+        connection.send(BoltRequest::pull(2, -1)).await.unwrap();
+        connection.reset().await.unwrap();
+        assert_eq!(connection.pending, 0);
+    }
 
     #[test]
     fn should_parse_uri() {
@@ -811,6 +1148,65 @@ mod tests {
         assert_eq!(url.scheme(), "bolt");
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn should_parse_unix_socket_uri() {
+        let url = NeoUrl::parse("bolt+unix:///var/run/neo4j/bolt.sock").unwrap();
+        assert_eq!(url.scheme(), "bolt+unix");
+        assert_eq!(
+            url.unix_socket().as_deref(),
+            Some(std::path::Path::new("/var/run/neo4j/bolt.sock"))
+        );
+        assert_eq!(
+            NeoUrl::parse("bolt://localhost").unwrap().unix_socket(),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn should_decode_unix_socket_path() {
+        let url = NeoUrl::parse("bolt+unix:///tmp/my%20neo4j/bolt%25.sock").unwrap();
+        assert_eq!(
+            url.unix_socket().as_deref(),
+            Some(std::path::Path::new("/tmp/my neo4j/bolt%.sock"))
+        );
+    }
+
+    #[test]
+    fn should_reject_unix_socket_uri_with_host() {
+        assert!(matches!(
+            NeoUrl::parse("bolt+unix://localhost/var/run/neo4j/bolt.sock"),
+            Err(crate::Error::UnexpectedUnixSocketHost(host)) if host == "localhost"
+        ));
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn should_connect_to_unix_socket() {
+        let path = std::env::temp_dir().join(format!("neo4rs-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut handshake = [0; 20];
+            stream.read_exact(&mut handshake).await.unwrap();
+            stream.write_all(&[0, 0, 4, 4]).await.unwrap();
+        });
+
+        let config = crate::ConfigBuilder::default()
+            .uri(format!("bolt+unix://{}", path.display()))
+            .user("neo4j")
+            .password("neo")
+            .build()
+            .unwrap();
+        let info = ConnectionInfo::new(&config).unwrap();
+        let connection = Connection::prepare(&info.prepare).await.unwrap();
+        assert_eq!(connection.version(), Version::V4_4);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn should_parse_ip_uri_without_scheme() {
         let url = NeoUrl::parse("127.0.0.1:4242").unwrap();
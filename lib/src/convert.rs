@@ -5,6 +5,17 @@ use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::hash::Hash;
 
+#[cfg(feature = "bigdecimal")]
+pub(crate) mod bigdecimal;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "jiff")]
+pub(crate) mod jiff;
+#[cfg(feature = "rust_decimal")]
+pub(crate) mod rust_decimal;
+#[cfg(feature = "time")]
+mod time;
+
 impl<A: TryFrom<BoltType, Error = Error>> TryFrom<BoltType> for Vec<A> {
     type Error = Error;
 
@@ -303,6 +314,40 @@ impl From<(chrono::NaiveDateTime, &str)> for BoltType {
     }
 }
 
+impl From<chrono::DateTime<chrono_tz::Tz>> for BoltType {
+    fn from(value: chrono::DateTime<chrono_tz::Tz>) -> Self {
+        BoltType::DateTimeZoneId(BoltDateTimeZoneId::from_utc(
+            value.timestamp(),
+            value.timestamp_subsec_nanos().into(),
+            value.timezone().name().into(),
+        ))
+    }
+}
+
+impl From<Point2D> for BoltType {
+    fn from(value: Point2D) -> Self {
+        BoltType::Point2D(value.into_inner())
+    }
+}
+
+impl From<BoltPoint2D> for BoltType {
+    fn from(value: BoltPoint2D) -> Self {
+        BoltType::Point2D(value)
+    }
+}
+
+impl From<Point3D> for BoltType {
+    fn from(value: Point3D) -> Self {
+        BoltType::Point3D(value.into_inner())
+    }
+}
+
+impl From<BoltPoint3D> for BoltType {
+    fn from(value: BoltPoint3D) -> Self {
+        BoltType::Point3D(value)
+    }
+}
+
 impl<A: Into<BoltType> + Clone> From<Vec<A>> for BoltType {
     fn from(value: Vec<A>) -> BoltType {
         BoltType::List(BoltList {
@@ -639,6 +684,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn convert_from_to_params() {
+        #[derive(crate::ToParams)]
+        #[param(skip_none)]
+        struct Person {
+            name: String,
+            #[param(rename = "yearOfBirth")]
+            year_of_birth: i32,
+            born: chrono::NaiveDate,
+            home: Point2D,
+            nickname: Option<String>,
+            #[param(skip)]
+            #[allow(dead_code)]
+            password: String,
+        }
+
+        let person = Person {
+            name: "Alice".to_owned(),
+            year_of_birth: 1990,
+            born: chrono::NaiveDate::from_ymd_opt(1990, 4, 2).unwrap(),
+            home: Point2D::new(BoltPoint2D {
+                sr_id: 7203.into(),
+                x: BoltFloat::new(1.0),
+                y: BoltFloat::new(2.0),
+            }),
+            nickname: None,
+            password: "hunter2".to_owned(),
+        };
+
+        let value: BoltType = person.into();
+        assert_eq!(
+            value,
+            BoltType::Map(BoltMap {
+                value: HashMap::from([
+                    ("name".into(), "Alice".into()),
+                    ("yearOfBirth".into(), 1990.into()),
+                    (
+                        "born".into(),
+                        chrono::NaiveDate::from_ymd_opt(1990, 4, 2).unwrap().into()
+                    ),
+                    (
+                        "home".into(),
+                        BoltType::Point2D(BoltPoint2D {
+                            sr_id: 7203.into(),
+                            x: BoltFloat::new(1.0),
+                            y: BoltFloat::new(2.0),
+                        })
+                    ),
+                ]),
+            })
+        );
+    }
+
+    #[test]
+    fn convert_from_to_params_with_generics() {
+        #[derive(crate::ToParams)]
+        struct Wrapper<T> {
+            #[param(skip_none)]
+            value: Option<T>,
+            other: Option<T>,
+        }
+
+        let value: BoltMap = Wrapper {
+            value: Some(42),
+            other: None,
+        }
+        .into();
+        assert_eq!(
+            value,
+            BoltMap {
+                value: HashMap::from([
+                    ("value".into(), 42.into()),
+                    ("other".into(), BoltType::Null(BoltNull)),
+                ]),
+            }
+        );
+
+        let value: BoltMap = Wrapper::<i64> {
+            value: None,
+            other: None,
+        }
+        .into();
+        assert_eq!(
+            value,
+            BoltMap {
+                value: HashMap::from([("other".into(), BoltType::Null(BoltNull))]),
+            }
+        );
+    }
+
+    #[test]
+    fn convert_from_chrono_tz() {
+        use chrono::TimeZone;
+
+        let tz = chrono_tz::Europe::Paris;
+        let value = tz.with_ymd_and_hms(2024, 6, 1, 12, 30, 0).unwrap();
+        let value: BoltType = value.into();
+        assert_eq!(
+            value,
+            BoltType::from((
+                chrono::NaiveDate::from_ymd_opt(2024, 6, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 30, 0)
+                    .unwrap(),
+                "Europe/Paris"
+            ))
+        );
+    }
+
     #[test]
     fn convert_from_iterator() {
         let value: Vec<i64> = vec![42, 1337];
@@ -0,0 +1,96 @@
+//! Conversions between Neo4j values and [`bigdecimal::BigDecimal`](::bigdecimal::BigDecimal).
+//!
+//! Neo4j has no decimal type, decimals are stored as strings to keep their precision.
+//! They can be read back from strings, integers, and floats.
+//! Floats are converted from their shortest decimal representation,
+//! so `0.1` is read as `0.1` and not as its binary approximation.
+
+use crate::errors::{Error, Result};
+use crate::types::{BoltString, BoltType};
+use crate::DeError;
+use ::bigdecimal::BigDecimal;
+use serde::de::{Error as _, Unexpected};
+use std::str::FromStr;
+
+const EXPECTED: &str = "a decimal number";
+
+fn decimal_from_str(value: &str) -> Result<BigDecimal, DeError> {
+    BigDecimal::from_str(value)
+        .map_err(|_| DeError::invalid_value(Unexpected::Str(value), &EXPECTED))
+}
+
+fn decimal_string_from_f64(value: f64) -> Result<String, DeError> {
+    if value.is_finite() {
+        Ok(value.to_string())
+    } else {
+        Err(DeError::invalid_value(Unexpected::Float(value), &EXPECTED))
+    }
+}
+
+impl From<BigDecimal> for BoltType {
+    fn from(value: BigDecimal) -> Self {
+        BoltType::String(BoltString::new(&value.to_string()))
+    }
+}
+
+impl TryFrom<BoltType> for BigDecimal {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<BigDecimal> {
+        match input {
+            BoltType::String(s) => Ok(decimal_from_str(&s.value)?),
+            BoltType::Float(f) => Ok(decimal_from_str(&decimal_string_from_f64(f.value)?)?),
+            BoltType::Integer(i) => Ok(i.value.into()),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+/// Formats a float for the serde visitor of `BigDecimal`,
+/// which would otherwise use the exact binary value of the float.
+pub(crate) fn to_decimal_string(value: &BoltType) -> Option<Result<String, DeError>> {
+    match value {
+        BoltType::Float(f) => Some(decimal_string_from_f64(f.value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoltFloat;
+
+    #[test]
+    fn convert_into_string() {
+        let value: BoltType = BigDecimal::from_str("1234.5600").unwrap().into();
+        assert_eq!(value, BoltType::from("1234.5600"));
+    }
+
+    #[test]
+    fn convert_from_string() {
+        let digits = "3.14159265358979323846264338327950288419716939937510";
+        let value = BigDecimal::try_from(BoltType::from(digits)).unwrap();
+        assert_eq!(value, BigDecimal::from_str(digits).unwrap());
+    }
+
+    #[test]
+    fn convert_from_numbers() {
+        let value = BigDecimal::try_from(BoltType::Float(BoltFloat::new(0.1))).unwrap();
+        assert_eq!(value, BigDecimal::from_str("0.1").unwrap());
+
+        let value = BigDecimal::try_from(BoltType::from(42_i64)).unwrap();
+        assert_eq!(value, BigDecimal::from(42));
+    }
+
+    #[test]
+    fn convert_invalid() {
+        let value = BoltType::from("not a number");
+        assert!(matches!(
+            BigDecimal::try_from(value).unwrap_err(),
+            Error::DeserializationError(DeError::InvalidValue { .. })
+        ));
+
+        let value = BoltType::Float(BoltFloat::new(f64::INFINITY));
+        assert!(BigDecimal::try_from(value).is_err());
+    }
+}
@@ -0,0 +1,214 @@
+//! Conversions between Neo4j points and [`geo_types`].
+//!
+//! `geo_types` geometries carry no coordinate reference system.
+//! Points converted into Neo4j use WGS-84 (SRID 4326), with `x` as the longitude
+//! and `y` as the latitude, which is the convention of the `geo` algorithms.
+//! Use [`BoltPoint2D`] directly to send cartesian points.
+//!
+//! Points read from Neo4j can be converted if they are 2-dimensional
+//! and all points of a list share the same SRID.
+
+use crate::errors::{Error, Result};
+use crate::row::Point2D;
+use crate::types::{BoltFloat, BoltInteger, BoltList, BoltPoint2D, BoltType};
+
+const WGS84_2D: i64 = 4326;
+const CARTESIAN_2D: i64 = 7203;
+
+fn is_2d(sr_id: i64) -> bool {
+    matches!(sr_id, WGS84_2D | CARTESIAN_2D)
+}
+
+impl From<geo_types::Point<f64>> for BoltPoint2D {
+    fn from(value: geo_types::Point<f64>) -> Self {
+        BoltPoint2D {
+            sr_id: BoltInteger::new(WGS84_2D),
+            x: BoltFloat::new(value.x()),
+            y: BoltFloat::new(value.y()),
+        }
+    }
+}
+
+impl From<geo_types::Point<f64>> for Point2D {
+    fn from(value: geo_types::Point<f64>) -> Self {
+        Point2D::new(value.into())
+    }
+}
+
+impl From<geo_types::Point<f64>> for BoltType {
+    fn from(value: geo_types::Point<f64>) -> Self {
+        BoltType::Point2D(value.into())
+    }
+}
+
+impl From<geo_types::LineString<f64>> for BoltType {
+    fn from(value: geo_types::LineString<f64>) -> Self {
+        value.into_points().into_iter().collect()
+    }
+}
+
+impl From<geo_types::MultiPoint<f64>> for BoltType {
+    fn from(value: geo_types::MultiPoint<f64>) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+impl TryFrom<&BoltPoint2D> for geo_types::Point<f64> {
+    type Error = Error;
+
+    fn try_from(value: &BoltPoint2D) -> Result<Self> {
+        if is_2d(value.sr_id.value) {
+            Ok(geo_types::Point::new(value.x.value, value.y.value))
+        } else {
+            Err(Error::ConversionError)
+        }
+    }
+}
+
+impl TryFrom<BoltPoint2D> for geo_types::Point<f64> {
+    type Error = Error;
+
+    fn try_from(value: BoltPoint2D) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<Point2D> for geo_types::Point<f64> {
+    type Error = Error;
+
+    fn try_from(value: Point2D) -> Result<Self> {
+        value.into_inner().try_into()
+    }
+}
+
+impl TryFrom<BoltType> for geo_types::Point<f64> {
+    type Error = Error;
+
+    fn try_from(value: BoltType) -> Result<Self> {
+        match value {
+            BoltType::Point2D(p) => p.try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+/// Converts a list of points that all share the same 2-dimensional SRID.
+fn points_from_list(list: BoltList) -> Result<Vec<geo_types::Point<f64>>> {
+    let mut sr_id = None;
+    list.value
+        .into_iter()
+        .map(|value| match value {
+            BoltType::Point2D(p) => {
+                if *sr_id.get_or_insert(p.sr_id.value) != p.sr_id.value {
+                    return Err(Error::ConversionError);
+                }
+                p.try_into()
+            }
+            _ => Err(Error::ConversionError),
+        })
+        .collect()
+}
+
+impl TryFrom<BoltType> for geo_types::LineString<f64> {
+    type Error = Error;
+
+    fn try_from(value: BoltType) -> Result<Self> {
+        match value {
+            BoltType::List(l) => Ok(points_from_list(l)?.into()),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for geo_types::MultiPoint<f64> {
+    type Error = Error;
+
+    fn try_from(value: BoltType) -> Result<Self> {
+        match value {
+            BoltType::List(l) => Ok(points_from_list(l)?.into()),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoltPoint3D;
+    use geo_types::{line_string, point};
+
+    fn bolt_point(sr_id: i64, x: f64, y: f64) -> BoltType {
+        BoltType::Point2D(BoltPoint2D {
+            sr_id: BoltInteger::new(sr_id),
+            x: BoltFloat::new(x),
+            y: BoltFloat::new(y),
+        })
+    }
+
+    #[test]
+    fn convert_from_geo_point() {
+        let value: BoltType = point!(x: 12.994341, y: 55.611784).into();
+        assert_eq!(value, bolt_point(4326, 12.994341, 55.611784));
+    }
+
+    #[test]
+    fn convert_into_geo_point() {
+        let point: geo_types::Point<f64> = bolt_point(7203, 1.0, 2.0).try_into().unwrap();
+        assert_eq!(point, point!(x: 1.0, y: 2.0));
+
+        let point: geo_types::Point<f64> = Point2D::new(BoltPoint2D {
+            sr_id: BoltInteger::new(4326),
+            x: BoltFloat::new(12.5),
+            y: BoltFloat::new(55.6),
+        })
+        .try_into()
+        .unwrap();
+        assert_eq!(point, point!(x: 12.5, y: 55.6));
+    }
+
+    #[test]
+    fn convert_into_geo_point_rejects_other_srids() {
+        let value = bolt_point(4979, 1.0, 2.0);
+        assert!(geo_types::Point::<f64>::try_from(value).is_err());
+
+        let value = BoltType::Point3D(BoltPoint3D {
+            sr_id: BoltInteger::new(9157),
+            x: BoltFloat::new(1.0),
+            y: BoltFloat::new(2.0),
+            z: BoltFloat::new(3.0),
+        });
+        assert!(geo_types::Point::<f64>::try_from(value).is_err());
+    }
+
+    #[test]
+    fn convert_line_string() {
+        let line = line_string![(x: 1.0, y: 2.0), (x: 3.0, y: 4.0)];
+        let value: BoltType = line.clone().into();
+        assert_eq!(
+            value,
+            BoltType::List(BoltList {
+                value: vec![bolt_point(4326, 1.0, 2.0), bolt_point(4326, 3.0, 4.0)],
+            })
+        );
+
+        let value: geo_types::LineString<f64> = value.try_into().unwrap();
+        assert_eq!(value, line);
+    }
+
+    #[test]
+    fn convert_line_string_rejects_mixed_srids() {
+        let value = BoltType::List(BoltList {
+            value: vec![bolt_point(4326, 1.0, 2.0), bolt_point(7203, 3.0, 4.0)],
+        });
+        assert!(geo_types::LineString::<f64>::try_from(value.clone()).is_err());
+        assert!(geo_types::MultiPoint::<f64>::try_from(value).is_err());
+    }
+
+    #[test]
+    fn convert_vec_of_points() {
+        let points = vec![point!(x: 1.0, y: 2.0), point!(x: 3.0, y: 4.0)];
+        let value: BoltType = points.clone().into();
+        let value: Vec<geo_types::Point<f64>> = value.try_into().unwrap();
+        assert_eq!(value, points);
+    }
+}
@@ -0,0 +1,649 @@
+//! Conversions between Neo4j temporal values and the types of the [`jiff`](::jiff) crate.
+//!
+//! A [`Zoned`] with an IANA time zone is sent as a `DateTimeZoneId` and keeps its zone name,
+//! a `Zoned` with a fixed offset is sent as a `DateTime`.
+//! Zoned datetimes read from Neo4j are resolved with jiff's time zone database,
+//! they keep their instant even if their local time occurs twice in their zone.
+
+use crate::errors::{Error, Result};
+use crate::types::{
+    BoltDate, BoltDateTime, BoltDateTimeZoneId, BoltDuration, BoltLocalDateTime, BoltLocalTime,
+    BoltTime, BoltType,
+};
+use ::jiff::{
+    civil,
+    tz::{Offset, TimeZone},
+    SignedDuration, Span, Timestamp, Zoned,
+};
+
+const UNIX_EPOCH: civil::DateTime = civil::DateTime::constant(1970, 1, 1, 0, 0, 0, 0);
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Splits a duration into seconds and a non-negative nanosecond part, as Neo4j stores them.
+fn split(duration: SignedDuration) -> (i64, i64) {
+    let seconds = duration.as_secs();
+    let nanoseconds = i64::from(duration.subsec_nanos());
+    if nanoseconds < 0 {
+        (seconds - 1, nanoseconds + NANOS_PER_SECOND)
+    } else {
+        (seconds, nanoseconds)
+    }
+}
+
+fn duration(seconds: i64, nanoseconds: i64) -> Result<SignedDuration> {
+    if !(0..NANOS_PER_SECOND).contains(&nanoseconds) {
+        return Err(Error::ConversionError);
+    }
+    Ok(SignedDuration::new(seconds, nanoseconds as i32))
+}
+
+fn local_datetime(seconds: i64, nanoseconds: i64) -> Result<civil::DateTime> {
+    UNIX_EPOCH
+        .checked_add(duration(seconds, nanoseconds)?)
+        .map_err(|_| Error::ConversionError)
+}
+
+fn timestamp(seconds: i64, nanoseconds: i64) -> Result<Timestamp> {
+    Timestamp::from_duration(duration(seconds, nanoseconds)?).map_err(|_| Error::ConversionError)
+}
+
+fn nanos_since_midnight(time: civil::Time) -> i64 {
+    time.duration_since(civil::Time::midnight()).as_nanos() as i64
+}
+
+fn time_from_nanos(nanos: i64) -> Result<civil::Time> {
+    if !(0..24 * 3600 * NANOS_PER_SECOND).contains(&nanos) {
+        return Err(Error::ConversionError);
+    }
+    civil::Time::midnight()
+        .checked_add(SignedDuration::from_nanos(nanos))
+        .map_err(|_| Error::ConversionError)
+}
+
+fn offset(seconds: i64) -> Result<Offset> {
+    i32::try_from(seconds)
+        .ok()
+        .and_then(|seconds| Offset::from_seconds(seconds).ok())
+        .ok_or(Error::ConversionError)
+}
+
+impl From<civil::Date> for BoltDate {
+    fn from(value: civil::Date) -> Self {
+        let days = value.duration_since(UNIX_EPOCH.date()).as_secs() / (24 * 3600);
+        BoltDate { days: days.into() }
+    }
+}
+
+impl TryFrom<&BoltDate> for civil::Date {
+    type Error = Error;
+
+    fn try_from(value: &BoltDate) -> Result<Self> {
+        let seconds = value
+            .days
+            .value
+            .checked_mul(24 * 3600)
+            .ok_or(Error::ConversionError)?;
+        UNIX_EPOCH
+            .date()
+            .checked_add(SignedDuration::from_secs(seconds))
+            .map_err(|_| Error::ConversionError)
+    }
+}
+
+impl From<civil::Time> for BoltLocalTime {
+    fn from(value: civil::Time) -> Self {
+        BoltLocalTime {
+            nanoseconds: nanos_since_midnight(value).into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltLocalTime> for civil::Time {
+    type Error = Error;
+
+    fn try_from(value: &BoltLocalTime) -> Result<Self> {
+        time_from_nanos(value.nanoseconds.value)
+    }
+}
+
+impl From<(civil::Time, Offset)> for BoltTime {
+    fn from((time, offset): (civil::Time, Offset)) -> Self {
+        BoltTime {
+            nanoseconds: nanos_since_midnight(time).into(),
+            tz_offset_seconds: offset.seconds().into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltTime> for (civil::Time, Offset) {
+    type Error = Error;
+
+    fn try_from(value: &BoltTime) -> Result<Self> {
+        let time = time_from_nanos(value.nanoseconds.value)?;
+        let offset = offset(value.tz_offset_seconds.value)?;
+        Ok((time, offset))
+    }
+}
+
+impl From<civil::DateTime> for BoltLocalDateTime {
+    fn from(value: civil::DateTime) -> Self {
+        let (seconds, nanoseconds) = split(value.duration_since(UNIX_EPOCH));
+        BoltLocalDateTime {
+            seconds: seconds.into(),
+            nanoseconds: nanoseconds.into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltLocalDateTime> for civil::DateTime {
+    type Error = Error;
+
+    fn try_from(value: &BoltLocalDateTime) -> Result<Self> {
+        local_datetime(value.seconds.value, value.nanoseconds.value)
+    }
+}
+
+impl From<Timestamp> for BoltDateTime {
+    fn from(value: Timestamp) -> Self {
+        let (seconds, nanoseconds) = split(value.as_duration());
+        BoltDateTime {
+            seconds: seconds.into(),
+            nanoseconds: nanoseconds.into(),
+            tz_offset_seconds: 0.into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltDateTime> for Timestamp {
+    type Error = Error;
+
+    fn try_from(value: &BoltDateTime) -> Result<Self> {
+        Zoned::try_from(value).map(|zoned| zoned.timestamp())
+    }
+}
+
+impl TryFrom<&BoltDateTimeZoneId> for Timestamp {
+    type Error = Error;
+
+    fn try_from(value: &BoltDateTimeZoneId) -> Result<Self> {
+        Zoned::try_from(value).map(|zoned| zoned.timestamp())
+    }
+}
+
+impl From<&Zoned> for BoltDateTime {
+    fn from(value: &Zoned) -> Self {
+        let (seconds, nanoseconds) = split(value.datetime().duration_since(UNIX_EPOCH));
+        BoltDateTime {
+            seconds: seconds.into(),
+            nanoseconds: nanoseconds.into(),
+            tz_offset_seconds: value.offset().seconds().into(),
+        }
+    }
+}
+
+impl TryFrom<&Zoned> for BoltDateTimeZoneId {
+    type Error = Error;
+
+    /// Fails if the time zone of the value has no IANA name.
+    fn try_from(value: &Zoned) -> Result<Self> {
+        let tz_id = value
+            .time_zone()
+            .iana_name()
+            .ok_or(Error::ConversionError)?;
+        let (seconds, nanoseconds) = split(value.timestamp().as_duration());
+        Ok(BoltDateTimeZoneId::from_utc(
+            seconds,
+            nanoseconds,
+            tz_id.into(),
+        ))
+    }
+}
+
+impl TryFrom<&BoltDateTime> for Zoned {
+    type Error = Error;
+
+    fn try_from(value: &BoltDateTime) -> Result<Self> {
+        let offset = offset(value.tz_offset_seconds.value)?;
+        local_datetime(value.seconds.value, value.nanoseconds.value)?
+            .to_zoned(TimeZone::fixed(offset))
+            .map_err(|_| Error::ConversionError)
+    }
+}
+
+impl TryFrom<&BoltDateTimeZoneId> for Zoned {
+    type Error = Error;
+
+    fn try_from(value: &BoltDateTimeZoneId) -> Result<Self> {
+        let tz = TimeZone::get(value.tz_id()).map_err(|_| Error::ConversionError)?;
+        match value.utc_seconds {
+            Some(seconds) => Ok(timestamp(seconds, value.nanoseconds.value)?.to_zoned(tz)),
+            None => local_datetime(value.seconds.value, value.nanoseconds.value)?
+                .to_zoned(tz)
+                .map_err(|_| Error::ConversionError),
+        }
+    }
+}
+
+impl From<Span> for BoltDuration {
+    fn from(value: Span) -> Self {
+        let months = i64::from(value.get_years()) * 12 + i64::from(value.get_months());
+        let days = i64::from(value.get_weeks()) * 7 + i64::from(value.get_days());
+        let nanoseconds = i128::from(value.get_hours()) * 3600 * i128::from(NANOS_PER_SECOND)
+            + i128::from(value.get_minutes()) * 60 * i128::from(NANOS_PER_SECOND)
+            + i128::from(value.get_seconds()) * i128::from(NANOS_PER_SECOND)
+            + i128::from(value.get_milliseconds()) * 1_000_000
+            + i128::from(value.get_microseconds()) * 1_000
+            + i128::from(value.get_nanoseconds());
+        let seconds = nanoseconds.div_euclid(i128::from(NANOS_PER_SECOND)) as i64;
+        let nanoseconds = nanoseconds.rem_euclid(i128::from(NANOS_PER_SECOND)) as i64;
+        BoltDuration::new(
+            months.into(),
+            days.into(),
+            seconds.into(),
+            nanoseconds.into(),
+        )
+    }
+}
+
+impl TryFrom<&BoltDuration> for Span {
+    type Error = Error;
+
+    /// Fails if the months, days and seconds of the duration have different signs,
+    /// which a [`Span`] cannot represent.
+    fn try_from(value: &BoltDuration) -> Result<Self> {
+        let months = value.months.value;
+        let days = value.days.value;
+        let nanoseconds = i128::from(value.seconds.value) * i128::from(NANOS_PER_SECOND)
+            + i128::from(value.nanoseconds.value);
+
+        let signs = [months.signum(), days.signum(), nanoseconds.signum() as i64];
+        if signs.contains(&1) && signs.contains(&-1) {
+            return Err(Error::ConversionError);
+        }
+
+        let seconds = i64::try_from(nanoseconds / i128::from(NANOS_PER_SECOND))
+            .map_err(|_| Error::ConversionError)?;
+        let nanoseconds = (nanoseconds % i128::from(NANOS_PER_SECOND)) as i64;
+        Span::new()
+            .try_months(months)
+            .and_then(|span| span.try_days(days))
+            .and_then(|span| span.try_seconds(seconds))
+            .and_then(|span| span.try_nanoseconds(nanoseconds))
+            .map_err(|_| Error::ConversionError)
+    }
+}
+
+impl From<civil::Date> for BoltType {
+    fn from(value: civil::Date) -> Self {
+        BoltType::Date(value.into())
+    }
+}
+
+impl From<civil::Time> for BoltType {
+    fn from(value: civil::Time) -> Self {
+        BoltType::LocalTime(value.into())
+    }
+}
+
+impl From<(civil::Time, Offset)> for BoltType {
+    fn from(value: (civil::Time, Offset)) -> Self {
+        BoltType::Time(value.into())
+    }
+}
+
+impl From<civil::DateTime> for BoltType {
+    fn from(value: civil::DateTime) -> Self {
+        BoltType::LocalDateTime(value.into())
+    }
+}
+
+impl From<Timestamp> for BoltType {
+    fn from(value: Timestamp) -> Self {
+        BoltType::DateTime(value.into())
+    }
+}
+
+impl From<Zoned> for BoltType {
+    fn from(value: Zoned) -> Self {
+        match BoltDateTimeZoneId::try_from(&value) {
+            Ok(datetime) => BoltType::DateTimeZoneId(datetime),
+            Err(_) => BoltType::DateTime((&value).into()),
+        }
+    }
+}
+
+impl From<Span> for BoltType {
+    fn from(value: Span) -> Self {
+        BoltType::Duration(value.into())
+    }
+}
+
+impl TryFrom<BoltType> for civil::Date {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<civil::Date> {
+        match input {
+            BoltType::Date(d) => (&d).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for civil::Time {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<civil::Time> {
+        match input {
+            BoltType::LocalTime(t) => (&t).try_into(),
+            BoltType::Time(t) => <(civil::Time, Offset)>::try_from(&t).map(|(time, _)| time),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for (civil::Time, Offset) {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<(civil::Time, Offset)> {
+        match input {
+            BoltType::Time(t) => (&t).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for civil::DateTime {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<civil::DateTime> {
+        match input {
+            BoltType::LocalDateTime(dt) => (&dt).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for Timestamp {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Timestamp> {
+        match input {
+            BoltType::DateTime(dt) => (&dt).try_into(),
+            BoltType::DateTimeZoneId(dt) => (&dt).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for Zoned {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Zoned> {
+        match input {
+            BoltType::DateTime(dt) => (&dt).try_into(),
+            BoltType::DateTimeZoneId(dt) => (&dt).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for Span {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Span> {
+        match input {
+            BoltType::Duration(d) => (&d).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+/// Formats a temporal value for the serde visitor of a jiff type.
+///
+/// All jiff types deserialize from their string representation,
+/// which is produced here from the converted value so that no information is lost.
+pub(crate) fn to_jiff_string(value: &BoltType, visitor: &str) -> Result<String> {
+    let value = value.clone();
+    match visitor.rsplit("::").next() {
+        Some("DateVisitor") => civil::Date::try_from(value).map(|v| v.to_string()),
+        Some("TimeVisitor") => civil::Time::try_from(value).map(|v| v.to_string()),
+        Some("DateTimeVisitor") => civil::DateTime::try_from(value).map(|v| v.to_string()),
+        Some("TimestampVisitor") => Timestamp::try_from(value).map(|v| v.to_string()),
+        Some("ZonedVisitor") => Zoned::try_from(value).map(|v| v.to_string()),
+        Some("SpanVisitor") => Span::try_from(value).map(|v| v.to_string()),
+        _ => Err(Error::ConversionError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{types::BoltWireFormat, Version};
+    use ::jiff::civil::{date, time};
+
+    #[test]
+    fn convert_date() {
+        let value: BoltType = date(1999, 7, 14).into();
+        assert_eq!(
+            value,
+            chrono::NaiveDate::from_ymd_opt(1999, 7, 14).unwrap().into()
+        );
+        assert_eq!(civil::Date::try_from(value).unwrap(), date(1999, 7, 14));
+
+        let value: BoltType = date(1969, 12, 31).into();
+        assert_eq!(value, BoltType::Date(BoltDate { days: (-1).into() }));
+        assert_eq!(civil::Date::try_from(value).unwrap(), date(1969, 12, 31));
+    }
+
+    #[test]
+    fn convert_time() {
+        let value: BoltType = time(12, 34, 56, 789_000_000).into();
+        assert_eq!(
+            value,
+            chrono::NaiveTime::from_hms_milli_opt(12, 34, 56, 789)
+                .unwrap()
+                .into()
+        );
+        assert_eq!(
+            civil::Time::try_from(value).unwrap(),
+            time(12, 34, 56, 789_000_000)
+        );
+
+        let value: BoltType = (time(12, 34, 56, 0), Offset::constant(2)).into();
+        assert_eq!(
+            <(civil::Time, Offset)>::try_from(value.clone()).unwrap(),
+            (time(12, 34, 56, 0), Offset::constant(2))
+        );
+        assert_eq!(civil::Time::try_from(value).unwrap(), time(12, 34, 56, 0));
+    }
+
+    #[test]
+    fn convert_civil_datetime() {
+        let expected = date(1999, 7, 14).at(12, 34, 56, 789_000_000);
+        let value: BoltType = expected.into();
+        assert_eq!(
+            value,
+            chrono::NaiveDate::from_ymd_opt(1999, 7, 14)
+                .unwrap()
+                .and_hms_milli_opt(12, 34, 56, 789)
+                .unwrap()
+                .into()
+        );
+        assert_eq!(civil::DateTime::try_from(value).unwrap(), expected);
+
+        let expected = date(1969, 12, 31).at(23, 59, 59, 500_000_000);
+        let value: BoltType = expected.into();
+        assert_eq!(
+            value,
+            BoltType::LocalDateTime(BoltLocalDateTime {
+                seconds: (-1).into(),
+                nanoseconds: 500_000_000.into(),
+            })
+        );
+        assert_eq!(civil::DateTime::try_from(value).unwrap(), expected);
+    }
+
+    #[test]
+    fn convert_timestamp() {
+        let expected: Timestamp = "1999-07-14T10:34:56.789Z".parse().unwrap();
+        let value: BoltType = expected.into();
+        assert_eq!(
+            value,
+            chrono::DateTime::parse_from_rfc3339("1999-07-14T10:34:56.789Z")
+                .unwrap()
+                .into()
+        );
+        assert_eq!(Timestamp::try_from(value).unwrap(), expected);
+
+        let value: BoltType = chrono::DateTime::parse_from_rfc3339("1999-07-14T12:34:56.789+02:00")
+            .unwrap()
+            .into();
+        assert_eq!(Timestamp::try_from(value).unwrap(), expected);
+    }
+
+    #[test]
+    fn convert_zoned_with_zone_id() {
+        let expected: Zoned = "1999-07-14T12:34:56.789+02:00[Europe/Paris]"
+            .parse()
+            .unwrap();
+        let value: BoltType = expected.clone().into();
+
+        let local = chrono::NaiveDate::from_ymd_opt(1999, 7, 14)
+            .unwrap()
+            .and_hms_milli_opt(12, 34, 56, 789)
+            .unwrap();
+        assert_eq!(value, (local, "Europe/Paris").into());
+
+        let actual = Zoned::try_from(value).unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(actual.time_zone().iana_name(), Some("Europe/Paris"));
+    }
+
+    #[test]
+    fn convert_zoned_in_a_dst_overlap() {
+        // the clocks in Paris went back from 03:00 to 02:00 on 2024-10-27
+        for expected in [
+            "2024-10-27T02:30:00+02:00[Europe/Paris]",
+            "2024-10-27T02:30:00+01:00[Europe/Paris]",
+        ] {
+            let expected: Zoned = expected.parse().unwrap();
+            let value = BoltDateTimeZoneId::try_from(&expected).unwrap();
+
+            let bytes = value.into_bytes(Version::V5_0).unwrap();
+            let value = BoltDateTimeZoneId::parse(Version::V5_0, &mut bytes.clone()).unwrap();
+            assert_eq!(Zoned::try_from(&value).unwrap(), expected);
+
+            let value = BoltType::parse(Version::V5_0, &mut bytes.clone()).unwrap();
+            assert_eq!(Zoned::try_from(value).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn convert_zoned_in_a_dst_gap() {
+        // the clocks in Paris went from 02:00 to 03:00 on 2024-03-31
+        let local = chrono::NaiveDate::from_ymd_opt(2024, 3, 31)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let value: BoltDateTimeZoneId = (local, "Europe/Paris").into();
+        let expected: Zoned = "2024-03-31T03:30:00+02:00[Europe/Paris]".parse().unwrap();
+
+        assert_eq!(Zoned::try_from(&value).unwrap(), expected);
+        for version in [Version::V4_4, Version::V5_0] {
+            let bytes = value.clone().into_bytes(version).unwrap();
+            let parsed = BoltDateTimeZoneId::parse(version, &mut bytes.clone()).unwrap();
+            assert_eq!(Zoned::try_from(&parsed).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn convert_zoned_with_fixed_offset() {
+        let expected: Zoned = "1999-07-14T12:34:56.789+02:00[+02:00]".parse().unwrap();
+        let value: BoltType = expected.clone().into();
+        assert_eq!(
+            value,
+            chrono::DateTime::parse_from_rfc3339("1999-07-14T12:34:56.789+02:00")
+                .unwrap()
+                .into()
+        );
+        assert_eq!(Zoned::try_from(value).unwrap(), expected);
+    }
+
+    #[test]
+    fn convert_zoned_with_unknown_zone_id() {
+        let local = chrono::NaiveDate::from_ymd_opt(1999, 7, 14)
+            .unwrap()
+            .and_hms_opt(12, 34, 56)
+            .unwrap();
+        let value: BoltType = (local, "Not/AZone").into();
+        assert!(matches!(
+            Zoned::try_from(value).unwrap_err(),
+            Error::ConversionError
+        ));
+    }
+
+    #[test]
+    fn convert_span() {
+        let span = Span::new()
+            .years(1)
+            .months(2)
+            .weeks(1)
+            .days(3)
+            .hours(4)
+            .minutes(5)
+            .seconds(6)
+            .milliseconds(7);
+        let value: BoltType = span.into();
+        assert_eq!(
+            value,
+            BoltType::Duration(BoltDuration::new(
+                14.into(),
+                10.into(),
+                (4 * 3600 + 5 * 60 + 6).into(),
+                7_000_000.into()
+            ))
+        );
+
+        let actual = Span::try_from(value).unwrap();
+        assert_eq!(
+            actual.fieldwise(),
+            Span::new()
+                .months(14)
+                .days(10)
+                .seconds(4 * 3600 + 5 * 60 + 6)
+                .nanoseconds(7_000_000)
+        );
+    }
+
+    #[test]
+    fn convert_negative_span() {
+        let span = Span::new().days(-1).milliseconds(-500);
+        let value: BoltType = span.into();
+        assert_eq!(
+            value,
+            BoltType::Duration(BoltDuration::new(
+                0.into(),
+                (-1).into(),
+                (-1).into(),
+                500_000_000.into()
+            ))
+        );
+
+        let actual = Span::try_from(value).unwrap();
+        assert_eq!(
+            actual.fieldwise(),
+            Span::new().days(-1).nanoseconds(-500_000_000)
+        );
+    }
+
+    #[test]
+    fn convert_span_with_mixed_signs() {
+        let value =
+            BoltType::Duration(BoltDuration::new(1.into(), (-1).into(), 0.into(), 0.into()));
+        assert!(matches!(
+            Span::try_from(value).unwrap_err(),
+            Error::ConversionError
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+//! Conversions between Neo4j values and [`rust_decimal::Decimal`](::rust_decimal::Decimal).
+//!
+//! Neo4j has no decimal type, decimals are stored as strings to keep their precision.
+//! They can be read back from strings, integers, and floats.
+//! Values that do not fit into a `Decimal` without rounding are rejected.
+
+use crate::errors::{Error, Result};
+use crate::types::{BoltString, BoltType};
+use crate::DeError;
+use ::rust_decimal::Decimal;
+use serde::de::{Error as _, Unexpected};
+
+const EXPECTED: &str = "a decimal number that fits into a Decimal without loss of precision";
+
+fn decimal_from_str(value: &str) -> Result<Decimal, DeError> {
+    Decimal::from_str_exact(value)
+        .map_err(|_| DeError::invalid_value(Unexpected::Str(value), &EXPECTED))
+}
+
+fn decimal_from_f64(value: f64) -> Result<Decimal, DeError> {
+    // The `Display` impl of floats prints the shortest representation that
+    // round-trips, so `0.1` becomes `0.1` and not its binary approximation.
+    Some(value)
+        .filter(|value| value.is_finite())
+        .and_then(|value| Decimal::from_str_exact(&value.to_string()).ok())
+        .ok_or_else(|| DeError::invalid_value(Unexpected::Float(value), &EXPECTED))
+}
+
+impl From<Decimal> for BoltType {
+    fn from(value: Decimal) -> Self {
+        BoltType::String(BoltString::new(&value.to_string()))
+    }
+}
+
+impl TryFrom<BoltType> for Decimal {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Decimal> {
+        match input {
+            BoltType::String(s) => Ok(decimal_from_str(&s.value)?),
+            BoltType::Float(f) => Ok(decimal_from_f64(f.value)?),
+            BoltType::Integer(i) => Ok(i.value.into()),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+/// Formats a string or float for the serde visitor of `Decimal`,
+/// after checking that it converts without loss of precision.
+pub(crate) fn to_decimal_string(value: &BoltType) -> Option<Result<String, DeError>> {
+    match value {
+        BoltType::String(s) => Some(decimal_from_str(&s.value).map(|d| d.to_string())),
+        BoltType::Float(f) => Some(decimal_from_f64(f.value).map(|d| d.to_string())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoltFloat;
+    use std::str::FromStr;
+
+    #[test]
+    fn convert_into_string() {
+        let value: BoltType = Decimal::from_str("1234.5600").unwrap().into();
+        assert_eq!(value, BoltType::from("1234.5600"));
+    }
+
+    #[test]
+    fn convert_from_string() {
+        let value = Decimal::try_from(BoltType::from("-0.000000000000000000000000001")).unwrap();
+        assert_eq!(
+            value,
+            Decimal::from_str("-0.000000000000000000000000001").unwrap()
+        );
+    }
+
+    #[test]
+    fn convert_from_numbers() {
+        let value = Decimal::try_from(BoltType::Float(BoltFloat::new(0.1))).unwrap();
+        assert_eq!(value, Decimal::from_str("0.1").unwrap());
+
+        let value = Decimal::try_from(BoltType::from(42_i64)).unwrap();
+        assert_eq!(value, Decimal::from(42));
+    }
+
+    #[test]
+    fn convert_with_precision_loss() {
+        let value = BoltType::from("0.00000000000000000000000000001");
+        assert!(matches!(
+            Decimal::try_from(value).unwrap_err(),
+            Error::DeserializationError(DeError::InvalidValue { .. })
+        ));
+
+        let value = BoltType::Float(BoltFloat::new(1e30));
+        assert!(matches!(
+            Decimal::try_from(value).unwrap_err(),
+            Error::DeserializationError(DeError::InvalidValue { .. })
+        ));
+
+        let value = BoltType::Float(BoltFloat::new(f64::NAN));
+        assert!(Decimal::try_from(value).is_err());
+    }
+}
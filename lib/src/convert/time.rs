@@ -0,0 +1,404 @@
+//! Conversions between Neo4j temporal values and the types of the [`time`](::time) crate.
+
+use crate::errors::{Error, Result};
+use crate::types::{
+    BoltDate, BoltDateTime, BoltDateTimeZoneId, BoltDuration, BoltLocalDateTime, BoltLocalTime,
+    BoltTime, BoltType,
+};
+use ::time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use chrono::{Offset, Timelike};
+
+/// The julian day of 1970-01-01, Neo4j stores dates as days since that day.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+fn nanos_since_midnight(time: Time) -> i64 {
+    let (hour, minute, second, nano) = time.as_hms_nano();
+    (i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second)) * NANOS_PER_SECOND
+        + i64::from(nano)
+}
+
+fn time_from_nanos(nanos: i64) -> Result<Time> {
+    if !(0..24 * 3600 * NANOS_PER_SECOND).contains(&nanos) {
+        return Err(Error::ConversionError);
+    }
+    let seconds = nanos / NANOS_PER_SECOND;
+    Time::from_hms_nano(
+        (seconds / 3600) as u8,
+        (seconds % 3600 / 60) as u8,
+        (seconds % 60) as u8,
+        (nanos % NANOS_PER_SECOND) as u32,
+    )
+    .map_err(|_| Error::ConversionError)
+}
+
+fn utc_from_timestamp(seconds: i64, nanoseconds: i64) -> Result<OffsetDateTime> {
+    let nanoseconds = u32::try_from(nanoseconds).map_err(|_| Error::ConversionError)?;
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .and_then(|dt| dt.replace_nanosecond(nanoseconds))
+        .map_err(|_| Error::ConversionError)
+}
+
+impl From<Date> for BoltDate {
+    fn from(value: Date) -> Self {
+        let days = i64::from(value.to_julian_day()) - UNIX_EPOCH_JULIAN_DAY;
+        BoltDate { days: days.into() }
+    }
+}
+
+impl TryFrom<&BoltDate> for Date {
+    type Error = Error;
+
+    fn try_from(value: &BoltDate) -> Result<Self> {
+        value
+            .days
+            .value
+            .checked_add(UNIX_EPOCH_JULIAN_DAY)
+            .and_then(|day| i32::try_from(day).ok())
+            .and_then(|day| Date::from_julian_day(day).ok())
+            .ok_or(Error::ConversionError)
+    }
+}
+
+impl From<Time> for BoltLocalTime {
+    fn from(value: Time) -> Self {
+        BoltLocalTime {
+            nanoseconds: nanos_since_midnight(value).into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltLocalTime> for Time {
+    type Error = Error;
+
+    fn try_from(value: &BoltLocalTime) -> Result<Self> {
+        time_from_nanos(value.nanoseconds.value)
+    }
+}
+
+impl From<(Time, UtcOffset)> for BoltTime {
+    fn from((time, offset): (Time, UtcOffset)) -> Self {
+        BoltTime {
+            nanoseconds: nanos_since_midnight(time).into(),
+            tz_offset_seconds: offset.whole_seconds().into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltTime> for (Time, UtcOffset) {
+    type Error = Error;
+
+    fn try_from(value: &BoltTime) -> Result<Self> {
+        let time = time_from_nanos(value.nanoseconds.value)?;
+        let offset = i32::try_from(value.tz_offset_seconds.value)
+            .ok()
+            .and_then(|offset| UtcOffset::from_whole_seconds(offset).ok())
+            .ok_or(Error::ConversionError)?;
+        Ok((time, offset))
+    }
+}
+
+impl From<PrimitiveDateTime> for BoltLocalDateTime {
+    fn from(value: PrimitiveDateTime) -> Self {
+        let value = value.assume_utc();
+        BoltLocalDateTime {
+            seconds: value.unix_timestamp().into(),
+            nanoseconds: i64::from(value.nanosecond()).into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltLocalDateTime> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(value: &BoltLocalDateTime) -> Result<Self> {
+        let datetime = utc_from_timestamp(value.seconds.value, value.nanoseconds.value)?;
+        Ok(PrimitiveDateTime::new(datetime.date(), datetime.time()))
+    }
+}
+
+impl From<OffsetDateTime> for BoltDateTime {
+    fn from(value: OffsetDateTime) -> Self {
+        let offset = value.offset().whole_seconds();
+        BoltDateTime {
+            seconds: (value.unix_timestamp() + i64::from(offset)).into(),
+            nanoseconds: i64::from(value.nanosecond()).into(),
+            tz_offset_seconds: offset.into(),
+        }
+    }
+}
+
+impl TryFrom<&BoltDateTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(value: &BoltDateTime) -> Result<Self> {
+        let offset = i32::try_from(value.tz_offset_seconds.value)
+            .ok()
+            .and_then(|offset| UtcOffset::from_whole_seconds(offset).ok())
+            .ok_or(Error::ConversionError)?;
+        let seconds = value
+            .seconds
+            .value
+            .checked_sub(value.tz_offset_seconds.value)
+            .ok_or(Error::ConversionError)?;
+        let datetime = utc_from_timestamp(seconds, value.nanoseconds.value)?;
+        datetime
+            .checked_to_offset(offset)
+            .ok_or(Error::ConversionError)
+    }
+}
+
+impl TryFrom<&BoltDateTimeZoneId> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(value: &BoltDateTimeZoneId) -> Result<Self> {
+        let datetime = value.try_to_chrono()?;
+        let offset = UtcOffset::from_whole_seconds(datetime.offset().fix().local_minus_utc())
+            .map_err(|_| Error::ConversionError)?;
+        let nanoseconds = i64::from(datetime.nanosecond());
+        utc_from_timestamp(datetime.timestamp(), nanoseconds)?
+            .checked_to_offset(offset)
+            .ok_or(Error::ConversionError)
+    }
+}
+
+impl From<Duration> for BoltDuration {
+    fn from(value: Duration) -> Self {
+        let mut seconds = value.whole_seconds();
+        let mut nanoseconds = i64::from(value.subsec_nanoseconds());
+        if nanoseconds < 0 {
+            seconds -= 1;
+            nanoseconds += NANOS_PER_SECOND;
+        }
+        BoltDuration::new(0.into(), 0.into(), seconds.into(), nanoseconds.into())
+    }
+}
+
+impl TryFrom<&BoltDuration> for Duration {
+    type Error = Error;
+
+    fn try_from(value: &BoltDuration) -> Result<Self> {
+        Duration::seconds(value.seconds())
+            .checked_add(Duration::nanoseconds(value.nanoseconds.value))
+            .ok_or(Error::ConversionError)
+    }
+}
+
+impl From<Date> for BoltType {
+    fn from(value: Date) -> Self {
+        BoltType::Date(value.into())
+    }
+}
+
+impl From<Time> for BoltType {
+    fn from(value: Time) -> Self {
+        BoltType::LocalTime(value.into())
+    }
+}
+
+impl From<(Time, UtcOffset)> for BoltType {
+    fn from(value: (Time, UtcOffset)) -> Self {
+        BoltType::Time(value.into())
+    }
+}
+
+impl From<PrimitiveDateTime> for BoltType {
+    fn from(value: PrimitiveDateTime) -> Self {
+        BoltType::LocalDateTime(value.into())
+    }
+}
+
+impl From<OffsetDateTime> for BoltType {
+    fn from(value: OffsetDateTime) -> Self {
+        BoltType::DateTime(value.into())
+    }
+}
+
+impl From<Duration> for BoltType {
+    fn from(value: Duration) -> Self {
+        BoltType::Duration(value.into())
+    }
+}
+
+impl TryFrom<BoltType> for Date {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Date> {
+        match input {
+            BoltType::Date(d) => (&d).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for Time {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Time> {
+        match input {
+            BoltType::LocalTime(t) => (&t).try_into(),
+            BoltType::Time(t) => <(Time, UtcOffset)>::try_from(&t).map(|(time, _)| time),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for (Time, UtcOffset) {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<(Time, UtcOffset)> {
+        match input {
+            BoltType::Time(t) => (&t).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<PrimitiveDateTime> {
+        match input {
+            BoltType::LocalDateTime(dt) => (&dt).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<OffsetDateTime> {
+        match input {
+            BoltType::DateTime(dt) => (&dt).try_into(),
+            BoltType::DateTimeZoneId(dt) => (&dt).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+impl TryFrom<BoltType> for Duration {
+    type Error = Error;
+
+    fn try_from(input: BoltType) -> Result<Duration> {
+        match input {
+            BoltType::Duration(d) => (&d).try_into(),
+            _ => Err(Error::ConversionError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::time::macros::{date, datetime, offset, time};
+
+    #[test]
+    fn convert_date() {
+        let value: BoltType = date!(1999 - 07 - 14).into();
+        assert_eq!(
+            value,
+            chrono::NaiveDate::from_ymd_opt(1999, 7, 14).unwrap().into()
+        );
+        assert_eq!(Date::try_from(value).unwrap(), date!(1999 - 07 - 14));
+
+        let value: BoltType = date!(1969 - 12 - 31).into();
+        assert_eq!(value, BoltType::Date(BoltDate { days: (-1).into() }));
+        assert_eq!(Date::try_from(value).unwrap(), date!(1969 - 12 - 31));
+    }
+
+    #[test]
+    fn convert_time() {
+        let value: BoltType = time!(12:34:56.789).into();
+        assert_eq!(
+            value,
+            chrono::NaiveTime::from_hms_milli_opt(12, 34, 56, 789)
+                .unwrap()
+                .into()
+        );
+        assert_eq!(Time::try_from(value).unwrap(), time!(12:34:56.789));
+
+        let value: BoltType = (time!(12:34:56), offset!(+2)).into();
+        assert_eq!(
+            <(Time, UtcOffset)>::try_from(value.clone()).unwrap(),
+            (time!(12:34:56), offset!(+2))
+        );
+        assert_eq!(Time::try_from(value).unwrap(), time!(12:34:56));
+    }
+
+    #[test]
+    fn convert_primitive_datetime() {
+        let value: BoltType = datetime!(1999-07-14 12:34:56.789).into();
+        assert_eq!(
+            value,
+            chrono::NaiveDate::from_ymd_opt(1999, 7, 14)
+                .unwrap()
+                .and_hms_milli_opt(12, 34, 56, 789)
+                .unwrap()
+                .into()
+        );
+        assert_eq!(
+            PrimitiveDateTime::try_from(value).unwrap(),
+            datetime!(1999-07-14 12:34:56.789)
+        );
+    }
+
+    #[test]
+    fn convert_offset_datetime() {
+        let value: BoltType = datetime!(1999-07-14 12:34:56.789 +02:00).into();
+        assert_eq!(
+            value,
+            chrono::DateTime::parse_from_rfc3339("1999-07-14T12:34:56.789+02:00")
+                .unwrap()
+                .into()
+        );
+        assert_eq!(
+            OffsetDateTime::try_from(value).unwrap(),
+            datetime!(1999-07-14 12:34:56.789 +02:00)
+        );
+    }
+
+    #[test]
+    fn convert_offset_datetime_from_zone_id() {
+        let local = chrono::NaiveDate::from_ymd_opt(1999, 7, 14)
+            .unwrap()
+            .and_hms_opt(12, 34, 56)
+            .unwrap();
+        let value: BoltType = (local, "Europe/Paris").into();
+
+        let actual = OffsetDateTime::try_from(value).unwrap();
+        assert_eq!(actual, datetime!(1999-07-14 12:34:56 +02:00));
+        assert_eq!(actual.offset(), offset!(+2));
+    }
+
+    #[test]
+    fn convert_duration() {
+        let value: BoltType = Duration::new(42, 1337).into();
+        assert_eq!(value, std::time::Duration::new(42, 1337).into());
+        assert_eq!(Duration::try_from(value).unwrap(), Duration::new(42, 1337));
+
+        let value: BoltType = Duration::new(-1, -500).into();
+        assert_eq!(
+            value,
+            BoltType::Duration(BoltDuration::new(
+                0.into(),
+                0.into(),
+                (-2).into(),
+                999_999_500.into()
+            ))
+        );
+        assert_eq!(Duration::try_from(value).unwrap(), Duration::new(-1, -500));
+    }
+
+    #[test]
+    fn convert_invalid_time() {
+        let value = BoltType::LocalTime(BoltLocalTime {
+            nanoseconds: (24 * 3600 * NANOS_PER_SECOND).into(),
+        });
+        assert!(matches!(
+            Time::try_from(value).unwrap_err(),
+            Error::ConversionError
+        ));
+    }
+}
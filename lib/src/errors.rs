@@ -1,6 +1,8 @@
 #[cfg(feature = "unstable-serde-packstream-format")]
 use crate::packstream::{de, ser};
-use crate::DeError;
+use crate::{BoltType, DeError};
+use serde::Deserialize;
+use std::collections::HashMap;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -27,6 +29,9 @@ pub enum Error {
     #[error("Unsupported URI scheme: {0}")]
     UnsupportedScheme(String),
 
+    #[error("A bolt+unix URI must not have a host, but got {0}")]
+    UnexpectedUnixSocketHost(String),
+
     #[error("Invalid DNS name: {0}")]
     InvalidDnsName(String),
 
@@ -67,7 +72,7 @@ pub enum Error {
     )]
     ProtocolMismatch(u32),
 
-    #[error("Neo4j error `{}`: {}", .0.code, .0.message)]
+    #[error(transparent)]
     Neo4j(Neo4jError),
 
     #[error("{0}")]
@@ -91,6 +96,9 @@ pub enum Error {
     #[error("Result contains more than one row")]
     NotSingleResult,
 
+    #[error("The stream is consumed with the handle of a different transaction than the one that executed its query")]
+    TransactionMismatch,
+
     #[error("{0}")]
     AuthenticationError(String),
 
@@ -237,6 +245,7 @@ impl Neo4jErrorKind {
             kind: self,
             code,
             message,
+            gql: None,
         }
     }
 }
@@ -247,11 +256,18 @@ impl From<&str> for Neo4jErrorKind {
     }
 }
 
+/// An error that was reported by the server.
+///
+/// Servers with Bolt 5.7 or later also report the error in the GQL format,
+/// with a GQLSTATUS code such as `22N01` and the errors that caused it,
+/// which are returned by [`std::error::Error::source`].
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Neo4jError {
     kind: Neo4jErrorKind,
     code: String,
     message: String,
+    /// The error in the GQL format, its message is the same as the one of this error.
+    gql: Option<Box<GqlError>>,
 }
 
 impl Neo4jError {
@@ -259,6 +275,14 @@ impl Neo4jError {
         Neo4jErrorKind::new(&code).new_error(code, message)
     }
 
+    /// Adds the GQL fields of the error, if the server reported them.
+    pub(crate) fn with_gql(mut self, gql: GqlError) -> Self {
+        if !gql.gql_status.is_empty() {
+            self.gql = Some(Box::new(gql));
+        }
+        self
+    }
+
     pub fn kind(&self) -> Neo4jErrorKind {
         self.kind
     }
@@ -271,19 +295,127 @@ impl Neo4jError {
         &self.message
     }
 
+    /// The GQLSTATUS code of the error, e.g. `22N01`, if the server reported it.
+    pub fn gql_status(&self) -> Option<&str> {
+        self.gql.as_ref().map(|gql| gql.gql_status())
+    }
+
+    /// The description of the GQLSTATUS code, if the server reported it.
+    pub fn description(&self) -> Option<&str> {
+        self.gql.as_ref().map(|gql| gql.description())
+    }
+
+    /// Further information about the error, such as the operation that caused it,
+    /// if the server reported it.
+    pub fn diagnostic_record(&self) -> Option<&DiagnosticRecord> {
+        self.gql.as_ref().map(|gql| gql.diagnostic_record())
+    }
+
+    /// The error that caused this error, if the server reported it.
+    pub fn cause(&self) -> Option<&GqlError> {
+        self.gql.as_ref().and_then(|gql| gql.cause())
+    }
+
     pub(crate) fn can_retry(&self) -> bool {
         self.kind.can_retry()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl std::fmt::Display for Neo4jError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Neo4j error `{}`: {}", self.code, self.message)
+    }
+}
 
-    #[test]
-    fn should_display_connection_timed_out() {
-        let error = Error::ConnectionTimedOut;
-        assert_eq!(format!("{}", error), "connection timed out");
+impl std::error::Error for Neo4jError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause().map(|cause| cause as _)
+    }
+}
+
+/// An error in the GQL format that caused a [`Neo4jError`] or another [`GqlError`].
+///
+/// The chain of causes is returned by [`std::error::Error::source`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, thiserror::Error)]
+#[error("GQL error `{gql_status}`: {message}")]
+pub struct GqlError {
+    #[serde(default)]
+    gql_status: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    diagnostic_record: DiagnosticRecord,
+    #[source]
+    #[serde(default)]
+    cause: Option<Box<GqlError>>,
+}
+
+impl GqlError {
+    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+    pub(crate) fn new(
+        gql_status: String,
+        description: String,
+        message: String,
+        diagnostic_record: HashMap<String, BoltType>,
+        cause: Option<Box<GqlError>>,
+    ) -> Self {
+        Self {
+            gql_status,
+            description,
+            message,
+            diagnostic_record: DiagnosticRecord(diagnostic_record),
+            cause,
+        }
+    }
+
+    /// The GQLSTATUS code of the error, e.g. `22N01`.
+    pub fn gql_status(&self) -> &str {
+        &self.gql_status
+    }
+
+    /// The description of the GQLSTATUS code.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// The message of the error, which is meant to be read by humans.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Further information about the error, such as the operation that caused it.
+    pub fn diagnostic_record(&self) -> &DiagnosticRecord {
+        &self.diagnostic_record
+    }
+
+    /// The error that caused this error, if any.
+    pub fn cause(&self) -> Option<&GqlError> {
+        self.cause.as_deref()
+    }
+}
+
+/// The diagnostic record of a [`GqlError`], which can be read like a map.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct DiagnosticRecord(HashMap<String, BoltType>);
+
+// The server only reports strings, integers, lists and maps in the diagnostic record,
+// so the values are never compared as floating point numbers.
+impl Eq for DiagnosticRecord {}
+
+impl std::ops::Deref for DiagnosticRecord {
+    type Target = HashMap<String, BoltType>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DiagnosticRecord> for HashMap<String, BoltType> {
+    fn from(record: DiagnosticRecord) -> Self {
+        record.0
     }
 }
 
@@ -295,3 +427,21 @@ impl std::convert::From<deadpool::managed::PoolError<Error>> for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_display_connection_timed_out() {
+        let error = Error::ConnectionTimedOut;
+        assert_eq!(format!("{}", error), "connection timed out");
+    }
+
+    #[test]
+    fn should_compare_neo4j_errors_for_equality() {
+        fn assert_eq<T: Eq>() {}
+        assert_eq::<Neo4jError>();
+        assert_eq::<GqlError>();
+    }
+}
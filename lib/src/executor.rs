@@ -0,0 +1,223 @@
+use crate::{
+    errors::Result, row::Row, summary::ResultSummary, txn::Txn, DetachedRowStream, Graph, Query,
+    RowStream,
+};
+#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+use crate::{session::Session, Operation};
+use serde::de::DeserializeOwned;
+use std::future::Future;
+
+/// Runs queries, so that code that runs queries can be written once for a [`Graph`],
+/// a [`crate::Session`], or a [`Txn`], and can be tested with a mock,
+/// such as the `MockExecutor` of the `test-util` feature.
+///
+/// Other than [`Graph::execute`], [`QueryExecutor::execute`] collects all rows of the query
+/// together with its summary into [`Records`].
+pub trait QueryExecutor: Send {
+    /// Runs a query and discards its rows, see [`Graph::run`].
+    fn run(
+        &mut self,
+        query: impl Into<Query> + Send,
+    ) -> impl Future<Output = Result<ResultSummary>> + Send;
+
+    /// Executes a query and collects its rows, see [`Graph::execute`].
+    fn execute(
+        &mut self,
+        query: impl Into<Query> + Send,
+    ) -> impl Future<Output = Result<Records>> + Send;
+
+    /// Executes a read-only query and collects its rows, see [`Graph::execute_read`].
+    ///
+    /// A [`Txn`] executes the query in the mode that the transaction was started with.
+    fn execute_read(
+        &mut self,
+        query: impl Into<Query> + Send,
+    ) -> impl Future<Output = Result<Records>> + Send;
+}
+
+/// Starts transactions in which queries can be run, see [`Graph::start_txn`].
+pub trait TransactionExecutor: QueryExecutor {
+    /// The transaction that is started by this executor.
+    type Txn: QueryExecutor;
+
+    /// Starts a new transaction.
+    fn start_txn(&mut self) -> impl Future<Output = Result<Self::Txn>> + Send;
+
+    /// Commits a transaction that has been started by this executor.
+    fn commit(&mut self, txn: Self::Txn) -> impl Future<Output = Result<()>> + Send;
+
+    /// Rolls back a transaction that has been started by this executor.
+    fn rollback(&mut self, txn: Self::Txn) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// The rows of a query together with its summary, as returned by [`QueryExecutor::execute`].
+#[derive(Clone, Debug, Default)]
+pub struct Records {
+    rows: Vec<Row>,
+    summary: ResultSummary,
+}
+
+impl Records {
+    pub fn new(rows: Vec<Row>, summary: ResultSummary) -> Self {
+        Records { rows, summary }
+    }
+
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Converts every row into a `T` by calling [`Row::to`].
+    pub fn rows_as<T: DeserializeOwned>(&self) -> Result<Vec<T>> {
+        self.rows
+            .iter()
+            .map(|row| row.to::<T>().map_err(Into::into))
+            .collect()
+    }
+
+    pub fn summary(&self) -> &ResultSummary {
+        &self.summary
+    }
+
+    pub fn into_rows(self) -> Vec<Row> {
+        self.rows
+    }
+
+    pub fn into_parts(self) -> (Vec<Row>, ResultSummary) {
+        (self.rows, self.summary)
+    }
+}
+
+impl QueryExecutor for Graph {
+    async fn run(&mut self, query: impl Into<Query> + Send) -> Result<ResultSummary> {
+        Graph::run(self, query).await
+    }
+
+    async fn execute(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let stream = Graph::execute(self, query).await?;
+        collect_detached(stream).await
+    }
+
+    async fn execute_read(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let stream = Graph::execute_read(self, query).await?;
+        collect_detached(stream).await
+    }
+}
+
+impl TransactionExecutor for Graph {
+    type Txn = Txn;
+
+    async fn start_txn(&mut self) -> Result<Txn> {
+        Graph::start_txn(self).await
+    }
+
+    async fn commit(&mut self, txn: Txn) -> Result<()> {
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn rollback(&mut self, txn: Txn) -> Result<()> {
+        txn.rollback().await
+    }
+}
+
+#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+impl QueryExecutor for Session {
+    async fn run(&mut self, query: impl Into<Query> + Send) -> Result<ResultSummary> {
+        Session::run(self, query).await
+    }
+
+    /// Executes the query in write mode, see [`Session::execute_write`].
+    async fn execute(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let stream = Session::execute_write(self, query).await?;
+        collect_detached(stream).await
+    }
+
+    async fn execute_read(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let stream = Session::execute_read(self, query).await?;
+        collect_detached(stream).await
+    }
+}
+
+#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+impl TransactionExecutor for Session {
+    type Txn = Txn;
+
+    /// Starts a write transaction with the bookmarks of the session.
+    async fn start_txn(&mut self) -> Result<Txn> {
+        self.begin_txn(Operation::Write).await
+    }
+
+    /// Commits the transaction and keeps its bookmark for the next transactions of the session.
+    async fn commit(&mut self, txn: Txn) -> Result<()> {
+        if let Some(bookmark) = txn.commit().await? {
+            self.set_bookmark(bookmark);
+        }
+        Ok(())
+    }
+
+    async fn rollback(&mut self, txn: Txn) -> Result<()> {
+        txn.rollback().await
+    }
+}
+
+impl QueryExecutor for Txn {
+    async fn run(&mut self, query: impl Into<Query> + Send) -> Result<ResultSummary> {
+        Txn::run(self, query).await
+    }
+
+    async fn execute(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let stream = Txn::execute(self, query).await?;
+        collect(stream, self).await
+    }
+
+    async fn execute_read(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let stream = Txn::execute(self, query).await?;
+        collect(stream, self).await
+    }
+}
+
+async fn collect_detached(mut stream: DetachedRowStream) -> Result<Records> {
+    let mut rows = Vec::new();
+    while let Some(row) = stream.next().await? {
+        rows.push(row);
+    }
+    Ok(Records::new(rows, stream.finish().await?))
+}
+
+async fn collect(mut stream: RowStream, txn: &mut Txn) -> Result<Records> {
+    let mut rows = Vec::new();
+    while let Some(row) = stream.next(&mut *txn).await? {
+        rows.push(row);
+    }
+    Ok(Records::new(rows, stream.finish(txn).await?))
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{hello, Request, Response, StubServer},
+        BoltType, Graph,
+    };
+
+    #[tokio::test]
+    async fn should_collect_records_of_graph() {
+        let script = hello()
+            .expect(Request::run("UNWIND [1, 2] AS x RETURN x"))
+            .reply(Response::success([("fields", BoltType::from(vec!["x"]))]))
+            .expect(Request::pull())
+            .reply(Response::record([1]))
+            .reply(Response::record([2]))
+            .reply(Response::success([("type", "r")]));
+        let server = StubServer::start(script).await.unwrap();
+        let mut graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let records = QueryExecutor::execute(&mut graph, "UNWIND [1, 2] AS x RETURN x")
+            .await
+            .unwrap();
+        assert_eq!(records.rows_as::<i64>().unwrap(), [1, 2]);
+        assert_eq!(records.summary().query_type(), crate::summary::Type::Read);
+
+        server.finish().await.unwrap();
+    }
+}
@@ -0,0 +1,194 @@
+use std::time::{Duration, Instant};
+
+const DEFAULT_MIN_FETCH_SIZE: usize = 1;
+const DEFAULT_MAX_FETCH_SIZE: usize = 10_000;
+const DEFAULT_BYTE_BUDGET: usize = 1024 * 1024;
+
+/// Settings for adapting the number of rows that are requested per PULL
+/// to the size of the rows and the speed at which they are consumed.
+///
+/// The first batch is requested with the configured fetch size.
+/// After every batch, the size of the next batch is adjusted:
+///
+/// * it shrinks so that a batch is expected to stay within the byte budget,
+///   based on the average size of the rows received so far,
+/// * it doubles, up to the byte budget, when the previous batch was consumed
+///   faster than it took to receive it.
+///
+/// The size of a batch always stays between [`Self::with_min`] and [`Self::with_max`] rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveFetchSize {
+    min: usize,
+    max: usize,
+    byte_budget: usize,
+}
+
+impl AdaptiveFetchSize {
+    /// Adapts the fetch size so that a batch is expected to stay within `byte_budget` bytes.
+    pub fn new(byte_budget: usize) -> Self {
+        Self {
+            byte_budget: byte_budget.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// The minimum number of rows to request per PULL, defaults to 1.
+    pub fn with_min(mut self, min: usize) -> Self {
+        self.min = min.max(1);
+        self.max = self.max.max(self.min);
+        self
+    }
+
+    /// The maximum number of rows to request per PULL, defaults to 10000.
+    pub fn with_max(mut self, max: usize) -> Self {
+        self.max = max.max(1);
+        self.min = self.min.min(self.max);
+        self
+    }
+}
+
+impl Default for AdaptiveFetchSize {
+    /// Adapts the fetch size between 1 and 10000 rows with a byte budget of 1 MiB.
+    fn default() -> Self {
+        Self {
+            min: DEFAULT_MIN_FETCH_SIZE,
+            max: DEFAULT_MAX_FETCH_SIZE,
+            byte_budget: DEFAULT_BYTE_BUDGET,
+        }
+    }
+}
+
+/// Tracks the batches of a stream and decides on the size of the next PULL.
+#[derive(Debug, Clone)]
+pub(crate) struct AdaptiveState {
+    config: AdaptiveFetchSize,
+    size: usize,
+    /// The number of rows that fit into the byte budget, once rows have been received.
+    limit: usize,
+    pull_sent: Option<Instant>,
+    last_batch: Option<Batch>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Batch {
+    received: Instant,
+    latency: Duration,
+}
+
+impl AdaptiveState {
+    pub(crate) fn new(config: AdaptiveFetchSize, initial: usize) -> Self {
+        Self {
+            config,
+            size: initial.clamp(config.min, config.max),
+            limit: config.max,
+            pull_sent: None,
+            last_batch: None,
+        }
+    }
+
+    /// Returns the number of rows to request in a PULL that is sent at `now`.
+    pub(crate) fn on_pull(&mut self, now: Instant) -> usize {
+        if let Some(batch) = self.last_batch.take() {
+            let consumed = now.saturating_duration_since(batch.received);
+            if consumed <= batch.latency {
+                self.size = self.size.saturating_mul(2).min(self.limit);
+            }
+        }
+        self.pull_sent = Some(now);
+        self.size
+    }
+
+    /// Records a batch of `rows` rows with a total size of `bytes` that was received at `now`.
+    pub(crate) fn on_batch(&mut self, now: Instant, rows: usize, bytes: usize) {
+        if let Some(row_size) = bytes.checked_div(rows) {
+            let row_size = row_size.max(1);
+            self.limit =
+                (self.config.byte_budget / row_size).clamp(self.config.min, self.config.max);
+            self.size = self.size.min(self.limit);
+        }
+        let sent = self.pull_sent.take().unwrap_or(now);
+        self.last_batch = Some(Batch {
+            received: now,
+            latency: now.saturating_duration_since(sent),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn starts_with_the_initial_size() {
+        let mut state = AdaptiveState::new(AdaptiveFetchSize::default(), 200);
+        assert_eq!(state.on_pull(Instant::now()), 200);
+
+        let config = AdaptiveFetchSize::default().with_min(10).with_max(100);
+        let mut state = AdaptiveState::new(config, 200);
+        assert_eq!(state.on_pull(Instant::now()), 100);
+    }
+
+    #[test]
+    fn grows_when_small_rows_are_consumed_quickly() {
+        let start = Instant::now();
+        let mut state = AdaptiveState::new(AdaptiveFetchSize::new(1000 * 100), 100);
+
+        assert_eq!(state.on_pull(start), 100);
+        state.on_batch(start + 10 * MS, 100, 100 * 10);
+        assert_eq!(state.on_pull(start + 15 * MS), 200);
+        state.on_batch(start + 25 * MS, 200, 200 * 10);
+        assert_eq!(state.on_pull(start + 30 * MS), 400);
+    }
+
+    #[test]
+    fn keeps_the_size_when_rows_are_consumed_slowly() {
+        let start = Instant::now();
+        let mut state = AdaptiveState::new(AdaptiveFetchSize::default(), 100);
+
+        assert_eq!(state.on_pull(start), 100);
+        state.on_batch(start + 10 * MS, 100, 100 * 10);
+        assert_eq!(state.on_pull(start + 100 * MS), 100);
+    }
+
+    #[test]
+    fn growth_is_limited_by_the_byte_budget_and_max() {
+        let start = Instant::now();
+        let mut state = AdaptiveState::new(AdaptiveFetchSize::new(1500), 100);
+
+        assert_eq!(state.on_pull(start), 100);
+        state.on_batch(start + 10 * MS, 100, 100 * 10);
+        assert_eq!(state.on_pull(start + 10 * MS), 150);
+
+        let config = AdaptiveFetchSize::default().with_max(120);
+        let mut state = AdaptiveState::new(config, 100);
+        assert_eq!(state.on_pull(start), 100);
+        state.on_batch(start + 10 * MS, 100, 100 * 10);
+        assert_eq!(state.on_pull(start + 10 * MS), 120);
+    }
+
+    #[test]
+    fn shrinks_for_large_rows() {
+        let start = Instant::now();
+        let mut state = AdaptiveState::new(AdaptiveFetchSize::new(1000 * 1000), 100);
+
+        assert_eq!(state.on_pull(start), 100);
+        state.on_batch(start + 10 * MS, 100, 100 * 100_000);
+        assert_eq!(state.on_pull(start + 100 * MS), 10);
+
+        state.on_batch(start + 110 * MS, 10, 10 * 10_000_000);
+        assert_eq!(state.on_pull(start + 200 * MS), 1);
+    }
+
+    #[test]
+    fn min_and_max_stay_ordered() {
+        let config = AdaptiveFetchSize::default().with_min(500).with_max(100);
+        let mut state = AdaptiveState::new(config, 1);
+        assert_eq!(state.on_pull(Instant::now()), 100);
+
+        let config = AdaptiveFetchSize::default().with_max(0).with_min(0);
+        let mut state = AdaptiveState::new(config, 0);
+        assert_eq!(state.on_pull(Instant::now()), 1);
+    }
+}
@@ -2,7 +2,6 @@
 use {
     crate::connection::{ConnectionInfo, Routing},
     crate::routing::{ClusterRoutingTableProvider, RoutedConnectionManager},
-    crate::summary::ResultSummary,
     log::debug,
     std::sync::Arc,
 };
@@ -15,6 +14,7 @@ use crate::RunResult;
 use crate::{
     config::{Config, ConfigBuilder, Database, LiveConfig},
     errors::Result,
+    notification::NotificationFilter,
     pool::{create_pool, ConnectionPool},
     query::Query,
     stream::DetachedRowStream,
@@ -84,14 +84,7 @@ impl Graph {
     pub fn connect(config: Config) -> Result<Self> {
         #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
         {
-            let info = ConnectionInfo::new(
-                &config.uri,
-                &config.user,
-                &config.password,
-                &config.tls_config,
-                config.connection_timeout,
-                config.tcp_keepalive,
-            )?;
+            let info = ConnectionInfo::new(&config)?;
             if matches!(info.init.routing, Routing::Yes(_)) {
                 debug!("Routing enabled, creating a routed connection manager");
                 let pool = ConnectionPoolManager::Routed(RoutedConnectionManager::new(
@@ -139,6 +132,8 @@ impl Graph {
     /// should be executed using either [`Txn::run`] or [`Txn::execute`]
     ///
     /// Transactions will not be automatically retried on any failure.
+    /// The BEGIN is sent together with the first query, so an error when starting the transaction
+    /// is returned by the first query, unless [`ConfigBuilder::pipeline_begin`] is disabled.
     pub async fn start_txn(&self) -> Result<Txn> {
         self.impl_start_txn_on(
             self.config.db.clone(),
@@ -146,6 +141,7 @@ impl Graph {
             self.config.imp_user.clone(),
             &[],
             Some(self.config.fetch_size),
+            &self.config.notifications,
         )
         .await
     }
@@ -167,6 +163,7 @@ impl Graph {
             self.config.imp_user.clone(),
             bookmarks.as_deref().unwrap_or_default(),
             Some(self.config.fetch_size),
+            &self.config.notifications,
         )
         .await
     }
@@ -176,6 +173,8 @@ impl Graph {
     /// should be executed using either [`Txn::run`] or [`Txn::execute`]
     ///
     /// Transactions will not be automatically retried on any failure.
+    /// The BEGIN is sent together with the first query, so an error when starting the transaction
+    /// is returned by the first query, unless [`ConfigBuilder::pipeline_begin`] is disabled.
     pub async fn start_txn_on(&self, db: impl Into<Database>) -> Result<Txn> {
         self.impl_start_txn_on(
             Some(db.into()),
@@ -183,11 +182,11 @@ impl Graph {
             self.config.imp_user.clone(),
             &[],
             Some(self.config.fetch_size),
+            &self.config.notifications,
         )
         .await
     }
 
-    #[allow(unused_variables)]
     pub(crate) async fn impl_start_txn_on(
         &self,
         db: Option<Database>,
@@ -195,27 +194,37 @@ impl Graph {
         imp_user: Option<ImpersonateUser>,
         bookmarks: &[String],
         fetch_size: Option<usize>,
+        notifications: &NotificationFilter,
     ) -> Result<Txn> {
         let connection = self
             .pool
             .get(Some(operation), db.clone(), imp_user.clone(), bookmarks)
             .await?;
         #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-        {
-            Txn::new(
-                db,
-                fetch_size.unwrap_or(self.config.fetch_size),
-                connection,
-                operation,
-                imp_user,
-                bookmarks,
-            )
-            .await
-        }
+        let mut txn = Txn::new(
+            db,
+            fetch_size.unwrap_or(self.config.fetch_size),
+            connection,
+            operation,
+            imp_user,
+            bookmarks,
+            notifications,
+        )
+        .await?;
         #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-        {
-            Txn::new(db, self.config.fetch_size, connection, operation, imp_user).await
+        let mut txn = Txn::new(
+            db,
+            fetch_size.unwrap_or(self.config.fetch_size),
+            connection,
+            operation,
+            imp_user,
+            notifications,
+        )
+        .await?;
+        if !self.config.pipeline_begin {
+            txn.flush_begin().await?;
         }
+        Ok(txn)
     }
 
     /// Runs a query on the configured database using a connection from the connection pool,
@@ -251,24 +260,7 @@ impl Graph {
     /// Use [`Graph::run`] for cases where you just want a write operation
     ///
     /// use [`Graph::execute`] when you are interested in the result stream
-    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-    pub async fn run_on(
-        &self,
-        db: impl Into<Database>,
-        q: impl Into<Query>,
-    ) -> Result<ResultSummary> {
-        self.impl_run_on(
-            Some(db.into()),
-            self.config.imp_user.clone(),
-            &[],
-            Some(self.config.fetch_size),
-            q.into(),
-        )
-        .await
-    }
-
-    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-    pub async fn run_on(&self, db: impl Into<Database>, q: impl Into<Query>) -> Result<()> {
+    pub async fn run_on(&self, db: impl Into<Database>, q: impl Into<Query>) -> Result<RunResult> {
         self.impl_run_on(
             Some(db.into()),
             self.config.imp_user.clone(),
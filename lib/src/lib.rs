@@ -36,6 +36,7 @@
 //! * `db` - the database to connect to (default is `neo4j`)
 //! * `connection_timeout` - timeout for connection and recv operations (default is 30s)
 //! * `tcp_keepalive` - OS-level TCP keepalive for dead connection detection (default is Some(60s))
+//! * `tcp_nodelay` - disables Nagle's algorithm on the socket (default is true)
 //! * `idle_timeout` - duration after which idle connections are discarded from the pool (default is None)
 //! * `max_lifetime` - maximum lifetime of a connection before it is discarded from the pool (default is None)
 //!
@@ -186,36 +187,26 @@ async fn main() {
 
 "
 )]
-#![cfg_attr(
-    feature = "unstable-result-summary",
-    doc = r##"### Streaming summary
-
-To get access to the result summary after streaming a [`RowStream`], use the [`RowStream::finish`] method.
-
-```no_run
-use neo4rs::*;
-
-#[tokio::main]
-async fn main() {
-    let uri = "127.0.0.1:7687";
-    let user = "neo4j";
-    let pass = "neo";
-    let graph = Graph::new(uri, user, pass).unwrap();
-
-"##
-)]
-#![cfg_attr(
-    feature = "unstable-result-summary",
-    doc = include_snippet!("integrationtests/result_summary.rs")
-)]
-#![cfg_attr(
-    feature = "unstable-result-summary",
-    doc = r"
-}
-```
-
-"
-)]
+//! ### Streaming summary
+//!
+//! To get access to the result summary after streaming a [`RowStream`], use the [`RowStream::finish`] method.
+//! [`Graph::run`] and [`Txn::run`] return the summary of the query as well.
+//!
+//! ```no_run
+//! use neo4rs::*;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!    let uri = "127.0.0.1:7687";
+//!    let user = "neo4j";
+//!    let pass = "neo";
+//!    let graph = Graph::new(uri, user, pass).unwrap();
+//!
+#![doc = include_snippet!("integrationtests/result_summary.rs")]
+//! }
+//!
+//! ```
+//!
 //! ### Rollback a transaction
 //! ```no_run
 //! use neo4rs::*;
@@ -489,6 +480,9 @@ async fn main() {
 //! ```
 //!
 //!
+// allows the derive macros to refer to `::neo4rs` from within this crate
+extern crate self as neo4rs;
+
 mod auth;
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 pub mod bolt;
@@ -497,26 +491,35 @@ mod config;
 mod connection;
 mod convert;
 mod errors;
+mod executor;
+mod fetch_size;
 mod graph;
 mod messages;
+mod notification;
 #[cfg(feature = "unstable-serde-packstream-format")]
 mod packstream;
 mod pool;
+mod proxy;
 mod query;
+mod recorder;
 mod retry;
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 mod routing;
 mod row;
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 mod session;
+mod socket;
 mod stream;
-#[cfg(feature = "unstable-result-summary")]
 pub mod summary;
+#[cfg(feature = "test-util")]
+pub mod testing;
 mod txn;
 mod types;
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 mod utils;
 mod version;
+#[cfg(feature = "websocket")]
+mod websocket;
 
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 pub use {
@@ -524,15 +527,20 @@ pub use {
     utils::ConcurrentHashMap,
 };
 
-pub use crate::auth::ClientCertificate;
+pub use crate::auth::{ClientCertificate, MutualTLS, PinnedCertificateVerifier, ReloadableTls};
 pub use crate::config::{Config, ConfigBuilder, Database};
 pub use crate::errors::{
-    Error, Neo4jClientErrorKind, Neo4jError, Neo4jErrorKind, Neo4jSecurityErrorKind, Result,
+    DiagnosticRecord, Error, GqlError, Neo4jClientErrorKind, Neo4jError, Neo4jErrorKind,
+    Neo4jSecurityErrorKind, Result,
 };
+pub use crate::executor::{QueryExecutor, Records, TransactionExecutor};
+pub use crate::fetch_size::AdaptiveFetchSize;
 pub use crate::graph::{query, Graph};
+pub use crate::notification::{NotificationClassification, NotificationSeverity};
 pub use crate::query::{Query, QueryParameter, RunResult};
+pub use crate::recorder::WireRecorder;
 pub use crate::row::{Node, Path, Point2D, Point3D, Relation, Row, UnboundedRelation};
-pub use crate::stream::{DetachedRowStream, RowStream};
+pub use crate::stream::{DetachedRowStream, RowStream, TypedDetachedRowStream};
 pub use crate::txn::Txn;
 pub use crate::types::serde::{
     DeError, EndNodeId, Id, Indices, Keys, Labels, Nodes, Offset, Relationships, StartNodeId,
@@ -546,6 +554,33 @@ pub use crate::types::{
 pub use crate::version::Version;
 pub(crate) use messages::Success;
 use neo4rs_include_snippet::include_snippet;
+/// Derive macro to convert a struct into a map parameter.
+///
+/// ```
+/// use neo4rs::{query, BoltMap, ToParams};
+///
+/// #[derive(ToParams)]
+/// struct Person {
+///     name: String,
+///     #[param(rename = "yearOfBirth")]
+///     year_of_birth: i64,
+///     #[param(skip_none)]
+///     nickname: Option<String>,
+/// }
+///
+/// let person = Person {
+///     name: "Alice".to_owned(),
+///     year_of_birth: 1990,
+///     nickname: None,
+/// };
+///
+/// let q = query("CREATE (n:Person $props)").param("props", person);
+/// let props = q.get_params().get::<BoltMap>("props").unwrap();
+/// assert_eq!(props.get::<String>("name").unwrap(), "Alice");
+/// assert_eq!(props.get::<i64>("yearOfBirth").unwrap(), 1990);
+/// assert!(props.get::<String>("nickname").is_err());
+/// ```
+pub use neo4rs_macros::ToParams;
 use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -5,6 +5,7 @@ mod discard;
 mod failure;
 mod hello;
 mod ignore;
+mod logon;
 mod pull;
 mod record;
 mod reset;
@@ -14,6 +15,7 @@ mod success;
 
 use crate::{
     errors::{Error, Result},
+    notification::NotificationFilter,
     types::{BoltMap, BoltWireFormat},
     version::Version,
     BoltString, BoltType,
@@ -26,6 +28,9 @@ use record::Record;
 use run::Run;
 pub(crate) use success::Success;
 
+/// The `bolt_agent` that identifies the driver in the HELLO since Bolt 5.3.
+pub(crate) const BOLT_AGENT: &str = concat!("neo4rs/", env!("CARGO_PKG_VERSION"));
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum BoltResponse {
     Success(Success),
@@ -42,6 +47,11 @@ pub enum BoltRequest {
         deprecated(since = "0.9.0", note = "Use `crate::bolt::Hello` instead.")
     )]
     Hello(hello::Hello),
+    #[cfg_attr(
+        feature = "unstable-bolt-protocol-impl-v2",
+        deprecated(since = "0.9.0", note = "Use `crate::bolt::Logon` instead.")
+    )]
+    Logon(logon::Logon),
     Run(Run),
     #[cfg_attr(
         feature = "unstable-bolt-protocol-impl-v2",
@@ -77,6 +87,7 @@ pub struct HelloBuilder {
     principal: BoltString,
     credentials: BoltString,
     routing: Option<BoltMap>,
+    notifications: NotificationFilter,
 }
 
 #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
@@ -87,6 +98,7 @@ impl HelloBuilder {
             principal: principal.into(),
             credentials: credentials.into(),
             routing: None,
+            notifications: NotificationFilter::default(),
         }
     }
 
@@ -97,6 +109,13 @@ impl HelloBuilder {
         }
     }
 
+    pub fn with_notifications(self, notifications: &NotificationFilter) -> Self {
+        Self {
+            notifications: notifications.clone(),
+            ..self
+        }
+    }
+
     #[cfg_attr(feature = "unstable-bolt-protocol-impl-v2", allow(deprecated))]
     pub fn build(self, version: Version) -> BoltRequest {
         let HelloBuilder {
@@ -104,8 +123,16 @@ impl HelloBuilder {
             principal,
             credentials,
             routing,
+            notifications,
         } = self;
-        BoltRequest::hello(agent, principal, credentials, routing, version)
+        BoltRequest::hello(
+            agent,
+            principal,
+            credentials,
+            routing,
+            &notifications,
+            version,
+        )
     }
 }
 
@@ -120,21 +147,46 @@ impl BoltRequest {
         principal: BoltString,
         credentials: BoltString,
         routing: Option<BoltMap>,
+        notifications: &NotificationFilter,
         version: Version,
     ) -> BoltRequest {
         let mut data = BoltMap::default();
         data.put("user_agent".into(), BoltType::String(agent));
-        data.put("scheme".into(), "basic".into());
-        data.put("principal".into(), BoltType::String(principal));
-        data.put("credentials".into(), BoltType::String(credentials));
+        if version >= Version::V5_3 {
+            let bolt_agent = [("product".into(), BOLT_AGENT.into())]
+                .into_iter()
+                .collect::<BoltMap>();
+            data.put("bolt_agent".into(), BoltType::Map(bolt_agent));
+        }
+        if version < Version::V5_1 {
+            data.put("scheme".into(), "basic".into());
+            data.put("principal".into(), BoltType::String(principal));
+            data.put("credentials".into(), BoltType::String(credentials));
+        }
         if version >= Version::V4_1 {
             if let Some(context) = routing {
                 data.put("routing".into(), BoltType::Map(context));
             }
         }
+        for (key, value) in notifications.entries(version) {
+            data.put(key.into(), value.into());
+        }
         BoltRequest::Hello(hello::Hello::new(data))
     }
 
+    /// The credentials, which are sent in a LOGON after the HELLO since Bolt 5.1.
+    #[cfg_attr(
+        feature = "unstable-bolt-protocol-impl-v2",
+        deprecated(since = "0.9.0", note = "Use `crate::bolt::Logon` instead.")
+    )]
+    pub fn logon(principal: BoltString, credentials: BoltString) -> BoltRequest {
+        let mut auth = BoltMap::default();
+        auth.put("scheme".into(), "basic".into());
+        auth.put("principal".into(), BoltType::String(principal));
+        auth.put("credentials".into(), BoltType::String(credentials));
+        BoltRequest::Logon(logon::Logon::new(auth))
+    }
+
     pub fn run(query: &str, params: BoltMap, extra: BoltMap) -> BoltRequest {
         BoltRequest::Run(Run::new(query.into(), params, extra))
     }
@@ -167,8 +219,18 @@ impl BoltRequest {
         feature = "unstable-bolt-protocol-impl-v2",
         deprecated(since = "0.9.0", note = "Use `crate::bolt::Begin` instead.")
     )]
-    pub fn begin(db: Option<&str>) -> BoltRequest {
-        let extra = db.into_iter().map(|db| ("db".into(), db.into())).collect();
+    pub fn begin(
+        db: Option<&str>,
+        notifications: &NotificationFilter,
+        version: Version,
+    ) -> BoltRequest {
+        let mut extra = db
+            .into_iter()
+            .map(|db| ("db".into(), db.into()))
+            .collect::<BoltMap>();
+        for (key, value) in notifications.entries(version) {
+            extra.put(key.into(), value.into());
+        }
         let begin = Begin::new(extra);
         BoltRequest::Begin(begin)
     }
@@ -203,6 +265,7 @@ impl BoltRequest {
     pub fn into_bytes(self, version: Version) -> Result<Bytes> {
         let bytes: Bytes = match self {
             BoltRequest::Hello(hello) => hello.into_bytes(version)?,
+            BoltRequest::Logon(logon) => logon.into_bytes(version)?,
             BoltRequest::Run(run) => run.into_bytes(version)?,
             BoltRequest::Pull(pull) => pull.into_bytes(version)?,
             BoltRequest::Discard(discard) => discard.into_bytes(version)?,
@@ -16,7 +16,10 @@ impl Begin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::version::Version;
+    use crate::{
+        messages::BoltRequest, notification::NotificationFilter, version::Version,
+        NotificationClassification, NotificationSeverity,
+    };
     use bytes::*;
 
     #[test]
@@ -52,4 +55,53 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_send_notification_filters_since_bolt_5_2() {
+        let notifications = NotificationFilter {
+            minimum_severity: Some(NotificationSeverity::Warning),
+            disabled_categories: Some(vec![NotificationClassification::Hint]),
+        };
+        let begin = |version| {
+            let BoltRequest::Begin(begin) =
+                BoltRequest::begin(Some("neo4j"), &notifications, version)
+            else {
+                panic!("expected a BEGIN");
+            };
+            begin.extra
+        };
+
+        let extra = begin(Version::V5_1);
+        assert_eq!(extra.get::<String>("db").unwrap(), "neo4j");
+        assert_eq!(extra.value.len(), 1);
+
+        let extra = begin(Version::V5_2);
+        assert_eq!(
+            extra
+                .get::<String>("notifications_minimum_severity")
+                .unwrap(),
+            "WARNING"
+        );
+        assert_eq!(
+            extra
+                .get::<Vec<String>>("notifications_disabled_categories")
+                .unwrap(),
+            ["HINT"]
+        );
+        assert!(!extra
+            .value
+            .contains_key("notifications_disabled_classifications"));
+
+        let extra = begin(Version::V5_5);
+        assert_eq!(
+            extra
+                .get::<Vec<String>>("notifications_disabled_classifications")
+                .unwrap(),
+            ["HINT"]
+        );
+        assert!(!extra
+            .value
+            .contains_key("notifications_disabled_categories"));
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    errors::Neo4jError,
+    errors::{GqlError, Neo4jError},
     types::{serde::DeError, BoltMap},
     BoltType,
 };
@@ -21,13 +21,20 @@ impl Failure {
     }
 
     pub(crate) fn into_error(self) -> Neo4jError {
+        let gql = self.metadata.to::<GqlError>().ok();
         let mut meta = self.metadata.value;
-        let (code, message) = (meta.remove("code"), meta.remove("message"));
+        // the code has been renamed to `neo4j_code` in Bolt 5.7
+        let code = meta.remove("neo4j_code").or_else(|| meta.remove("code"));
+        let (code, message) = (code, meta.remove("message"));
         let (code, message) = match (code, message) {
             (Some(BoltType::String(s)), Some(BoltType::String(m))) => (s.value, m.value),
             _ => (String::new(), String::new()),
         };
-        Neo4jError::new(code, message)
+        let error = Neo4jError::new(code, message);
+        match gql {
+            Some(gql) => error.with_gql(gql),
+            None => error,
+        }
     }
 }
 
@@ -61,4 +68,86 @@ mod tests {
             "The client is unauthorized due to authentication failure."
         );
     }
+
+    #[test]
+    fn should_convert_gql_failure() {
+        fn map(entries: Vec<(&str, BoltType)>) -> BoltMap {
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect()
+        }
+
+        let cause = map(vec![
+            ("gql_status", "22N27".into()),
+            (
+                "description",
+                "error: data exception - invalid entity type".into(),
+            ),
+            ("message", "Invalid input type".into()),
+            (
+                "diagnostic_record",
+                BoltType::Map(map(vec![("_classification", "CLIENT_ERROR".into())])),
+            ),
+        ]);
+        let metadata = map(vec![
+            ("neo4j_code", "Neo.ClientError.Statement.SyntaxError".into()),
+            ("message", "Invalid input".into()),
+            ("gql_status", "42001".into()),
+            (
+                "description",
+                "error: syntax error or access rule violation".into(),
+            ),
+            (
+                "diagnostic_record",
+                BoltType::Map(map(vec![("OPERATION", "".into())])),
+            ),
+            ("cause", BoltType::Map(cause)),
+        ]);
+
+        let error = Failure { metadata }.into_error();
+
+        assert_eq!(error.code(), "Neo.ClientError.Statement.SyntaxError");
+        assert_eq!(error.message(), "Invalid input");
+        assert_eq!(error.gql_status(), Some("42001"));
+        assert_eq!(
+            error.description(),
+            Some("error: syntax error or access rule violation")
+        );
+        assert_eq!(
+            error.diagnostic_record().unwrap().get("OPERATION"),
+            Some(&BoltType::from(""))
+        );
+
+        let cause = error.cause().unwrap();
+        assert_eq!(cause.gql_status(), "22N27");
+        assert_eq!(cause.message(), "Invalid input type");
+        assert_eq!(cause.cause(), None);
+
+        let source = std::error::Error::source(&error).unwrap();
+        assert_eq!(source.to_string(), "GQL error `22N27`: Invalid input type");
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    fn should_convert_failure_without_gql_fields() {
+        let metadata = vec![
+            (
+                "code".into(),
+                "Neo.TransientError.General.OutOfMemoryError".into(),
+            ),
+            ("message".into(), "Out of memory".into()),
+        ]
+        .into_iter()
+        .collect();
+
+        let error = Failure { metadata }.into_error();
+
+        assert_eq!(error.code(), "Neo.TransientError.General.OutOfMemoryError");
+        assert_eq!(error.gql_status(), None);
+        assert_eq!(error.description(), None);
+        assert_eq!(error.diagnostic_record(), None);
+        assert_eq!(error.cause(), None);
+        assert!(std::error::Error::source(&error).is_none());
+    }
 }
@@ -23,7 +23,7 @@ impl Hello {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::version::Version;
+    use crate::{messages::BoltRequest, notification::NotificationFilter, version::Version};
     use bytes::*;
 
     #[test]
@@ -58,4 +58,86 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_move_credentials_to_logon_since_bolt_5_1() {
+        let hello = |version| {
+            let BoltRequest::Hello(hello) = BoltRequest::hello(
+                "neo4rs".into(),
+                "neo4j".into(),
+                "secret".into(),
+                None,
+                &NotificationFilter::default(),
+                version,
+            ) else {
+                panic!("expected a HELLO");
+            };
+            hello.extra
+        };
+
+        let extra = hello(Version::V5_0);
+        assert_eq!(extra.get::<String>("credentials").unwrap(), "secret");
+        assert!(extra.get::<BoltMap>("bolt_agent").is_err());
+
+        let extra = hello(Version::V5_1);
+        assert!(!extra.value.contains_key("credentials"));
+        assert!(!extra.value.contains_key("principal"));
+        assert!(extra.get::<BoltMap>("bolt_agent").is_err());
+
+        let extra = hello(Version::V5_3);
+        assert!(!extra.value.contains_key("credentials"));
+        let agent = extra.get::<BoltMap>("bolt_agent").unwrap();
+        assert!(agent
+            .get::<String>("product")
+            .unwrap()
+            .starts_with("neo4rs/"));
+    }
+
+    #[cfg(feature = "test-util")]
+    #[tokio::test]
+    async fn should_send_notification_filters_since_bolt_5_2() {
+        use crate::{
+            testing::{Request, Response, Script, StubServer},
+            ConfigBuilder, Graph, NotificationClassification, NotificationSeverity,
+        };
+
+        let filters = || {
+            [
+                ("notifications_minimum_severity", BoltType::from("WARNING")),
+                (
+                    "notifications_disabled_categories",
+                    BoltType::from(vec!["HINT"]),
+                ),
+            ]
+        };
+        let script = Script::new()
+            .with_version(Version::V5_2)
+            .expect(Request::hello().with_extra(filters()))
+            .reply(Response::success([
+                ("server", "Neo4j/5.7.0"),
+                ("connection_id", "bolt-1"),
+            ]))
+            .expect(Request::logon())
+            .reply(Response::success(Vec::<(&str, BoltType)>::new()))
+            .expect(Request::begin().with_extra(filters()))
+            .reply(Response::success(Vec::<(&str, BoltType)>::new()))
+            .expect(Request::commit())
+            .reply(Response::success([("bookmark", "bookmark-1")]));
+        let server = StubServer::start(script).await.unwrap();
+        let config = ConfigBuilder::default()
+            .uri(server.uri())
+            .user("neo4j")
+            .password("neo")
+            .notifications_minimum_severity(NotificationSeverity::Warning)
+            .notifications_disabled_categories([NotificationClassification::Hint])
+            .build()
+            .unwrap();
+        let graph = Graph::connect(config).unwrap();
+
+        let txn = graph.start_txn().await.unwrap();
+        txn.commit().await.unwrap();
+
+        server.finish().await.unwrap();
+    }
 }
@@ -0,0 +1,70 @@
+#![cfg_attr(feature = "unstable-bolt-protocol-impl-v2", allow(deprecated))]
+
+use crate::types::*;
+use neo4rs_macros::BoltStruct;
+
+#[derive(Debug, PartialEq, Clone, BoltStruct)]
+#[signature(0xB1, 0x6A)]
+/// Authenticates the connection since Bolt 5.1, the credentials are no longer part of the HELLO.
+#[cfg_attr(
+    feature = "unstable-bolt-protocol-impl-v2",
+    deprecated(since = "0.9.0", note = "Use `crate::bolt::Logon` instead.")
+)]
+pub struct Logon {
+    auth: BoltMap,
+}
+
+impl Logon {
+    #[cfg_attr(feature = "unstable-bolt-protocol-impl-v2", allow(dead_code))]
+    pub fn new(auth: BoltMap) -> Logon {
+        Logon { auth }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{messages::BoltRequest, version::Version};
+    use bytes::*;
+
+    #[test]
+    fn should_serialize_logon() {
+        let logon = Logon::new(vec![("scheme".into(), "none".into())].into_iter().collect());
+
+        let bytes: Bytes = logon.into_bytes(Version::V5_1).unwrap();
+
+        assert_eq!(
+            bytes,
+            Bytes::from_static(&[
+                0xB1,
+                0x6A,
+                map::TINY | 1,
+                string::TINY | 6,
+                b's',
+                b'c',
+                b'h',
+                b'e',
+                b'm',
+                b'e',
+                string::TINY | 4,
+                b'n',
+                b'o',
+                b'n',
+                b'e',
+            ])
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_send_basic_credentials() {
+        let BoltRequest::Logon(logon) = BoltRequest::logon("neo4j".into(), "secret".into()) else {
+            panic!("expected a LOGON");
+        };
+
+        assert_eq!(logon.auth.get::<String>("scheme").unwrap(), "basic");
+        assert_eq!(logon.auth.get::<String>("principal").unwrap(), "neo4j");
+        assert_eq!(logon.auth.get::<String>("credentials").unwrap(), "secret");
+        assert_eq!(logon.auth.value.len(), 3);
+    }
+}
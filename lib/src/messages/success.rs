@@ -15,6 +15,14 @@ impl Success {
     {
         self.metadata.get::<T>(key)
     }
+
+    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+    pub(crate) fn to<'this, T>(&'this self) -> Result<T, DeError>
+    where
+        T: Deserialize<'this>,
+    {
+        self.metadata.to::<T>()
+    }
 }
 
 #[cfg(test)]
@@ -37,4 +45,70 @@ mod tests {
         assert_eq!(success.get::<String>("server").unwrap(), "Neo4j/4.1.4");
         assert_eq!(success.get::<String>("connection_id").unwrap(), "bolt-31");
     }
+
+    #[test]
+    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+    fn should_convert_to_result_summary() {
+        use crate::{
+            summary::{Streaming, Type},
+            BoltType,
+        };
+        use std::{collections::HashMap, time::Duration};
+
+        let stats = HashMap::from([("nodes-created", 2), ("properties-set", 4)]);
+        let metadata = [
+            ("bookmark", BoltType::from("FB:kcwQ")),
+            ("t_last", BoltType::from(42)),
+            ("type", BoltType::from("w")),
+            ("db", BoltType::from("neo4j")),
+            ("stats", BoltType::from(stats)),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.into(), value))
+        .collect();
+        let success = Success { metadata };
+
+        let Streaming::Done(summary) = success.to::<Streaming>().unwrap() else {
+            panic!("expected a summary");
+        };
+
+        assert_eq!(summary.bookmark.as_deref(), Some("FB:kcwQ"));
+        assert_eq!(summary.consumed_after(), Some(Duration::from_millis(42)));
+        assert_eq!(summary.query_type(), Type::Write);
+        assert_eq!(summary.db(), Some("neo4j"));
+        assert_eq!(summary.nodes_created(), 2);
+        assert_eq!(summary.properties_set(), 4);
+
+        let metadata = [("has_more", BoltType::from(true))]
+            .into_iter()
+            .map(|(key, value)| (key.into(), value))
+            .collect();
+        let success = Success { metadata };
+
+        assert!(matches!(success.to::<Streaming>(), Ok(Streaming::HasMore)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+    fn should_keep_readable_entries_of_a_malformed_summary() {
+        use crate::{summary::Streaming, BoltType};
+
+        let metadata = [
+            ("bookmark", BoltType::from("FB:kcwQ")),
+            ("type", BoltType::from("unknown")),
+            ("stats", BoltType::from("none")),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.into(), value))
+        .collect();
+        let success = Success { metadata };
+        assert!(success.to::<Streaming>().is_err());
+
+        let Streaming::Done(summary) = Streaming::from_success(&success) else {
+            panic!("expected a summary");
+        };
+        assert_eq!(summary.bookmark.as_deref(), Some("FB:kcwQ"));
+        assert_eq!(summary.r#type, None);
+        assert_eq!(summary.nodes_created(), 0);
+    }
 }
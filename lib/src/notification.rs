@@ -0,0 +1,306 @@
+use crate::{BoltList, BoltType, Version};
+use serde::{
+    de::{self, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Serialize,
+};
+
+/// The severity of a notification, also used as the minimum severity
+/// of the notifications that the server should send.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationSeverity {
+    Information,
+    Warning,
+    Off,
+}
+
+/// The category of a notification, also used to disable notifications
+/// of a category on the server.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationClassification {
+    Hint,
+    Unrecognized,
+    Unsupported,
+    Performance,
+    Deprecation,
+    Security,
+    Topology,
+    Generic,
+    Schema,
+}
+
+impl NotificationSeverity {
+    /// The name of the severity as used by the server, e.g. `WARNING`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NotificationSeverity::Information => "INFORMATION",
+            NotificationSeverity::Warning => "WARNING",
+            NotificationSeverity::Off => "OFF",
+        }
+    }
+}
+
+impl NotificationClassification {
+    /// The name of the category as used by the server, e.g. `DEPRECATION`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NotificationClassification::Hint => "HINT",
+            NotificationClassification::Unrecognized => "UNRECOGNIZED",
+            NotificationClassification::Unsupported => "UNSUPPORTED",
+            NotificationClassification::Performance => "PERFORMANCE",
+            NotificationClassification::Deprecation => "DEPRECATION",
+            NotificationClassification::Security => "SECURITY",
+            NotificationClassification::Topology => "TOPOLOGY",
+            NotificationClassification::Generic => "GENERIC",
+            NotificationClassification::Schema => "SCHEMA",
+        }
+    }
+}
+
+/// The notifications that the server should send, as configured on the
+/// [`crate::ConfigBuilder`] or the session.
+///
+/// Filters are part of Bolt 5.2, they are not sent on older protocol versions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotificationFilter {
+    pub(crate) minimum_severity: Option<NotificationSeverity>,
+    pub(crate) disabled_categories: Option<Vec<NotificationClassification>>,
+}
+
+impl NotificationFilter {
+    /// The entries to add to a HELLO or BEGIN message for the given protocol version.
+    pub(crate) fn entries(&self, version: Version) -> Vec<(&'static str, NotificationValue)> {
+        let mut entries = Vec::new();
+        if version < Version::V5_2 {
+            return entries;
+        }
+
+        if let Some(severity) = self.minimum_severity {
+            entries.push((
+                "notifications_minimum_severity",
+                NotificationValue::Severity(severity),
+            ));
+        }
+        if let Some(categories) = self.disabled_categories.as_ref() {
+            // the categories have been renamed to classifications in Bolt 5.5
+            let key = if version < Version::V5_5 {
+                "notifications_disabled_categories"
+            } else {
+                "notifications_disabled_classifications"
+            };
+            entries.push((key, NotificationValue::Categories(categories.clone())));
+        }
+        entries
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NotificationValue {
+    Severity(NotificationSeverity),
+    Categories(Vec<NotificationClassification>),
+}
+
+impl From<NotificationValue> for BoltType {
+    fn from(value: NotificationValue) -> Self {
+        match value {
+            NotificationValue::Severity(severity) => severity.as_str().into(),
+            NotificationValue::Categories(categories) => BoltType::List(
+                categories
+                    .into_iter()
+                    .map(NotificationClassification::as_str)
+                    .collect::<BoltList>(),
+            ),
+        }
+    }
+}
+
+impl Serialize for NotificationValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            NotificationValue::Severity(severity) => serializer.serialize_str(severity.as_str()),
+            NotificationValue::Categories(categories) => {
+                let mut seq = serializer.serialize_seq(Some(categories.len()))?;
+                for category in categories {
+                    seq.serialize_element(category.as_str())?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NotificationSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct TheVisitor;
+
+        impl<'de> Visitor<'de> for TheVisitor {
+            type Value = NotificationSeverity;
+            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                formatter.write_str("a valid NotificationSeverity")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: ::serde::de::Error,
+            {
+                if v.eq_ignore_ascii_case("information") {
+                    Ok(NotificationSeverity::Information)
+                } else if v.eq_ignore_ascii_case("warning") {
+                    Ok(NotificationSeverity::Warning)
+                } else if v.eq_ignore_ascii_case("off") {
+                    Ok(NotificationSeverity::Off)
+                } else {
+                    Err(de::Error::unknown_variant(
+                        v,
+                        &["INFORMATION", "WARNING", "OFF"],
+                    ))
+                }
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&v)
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(v)
+            }
+        }
+
+        deserializer.deserialize_any(TheVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for NotificationClassification {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct TheVisitor;
+
+        impl<'de> Visitor<'de> for TheVisitor {
+            type Value = NotificationClassification;
+            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                formatter.write_str("a valid NotificationClassification")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: ::serde::de::Error,
+            {
+                if v.eq_ignore_ascii_case("hint") {
+                    Ok(NotificationClassification::Hint)
+                } else if v.eq_ignore_ascii_case("unrecognized") {
+                    Ok(NotificationClassification::Unrecognized)
+                } else if v.eq_ignore_ascii_case("unsupported") {
+                    Ok(NotificationClassification::Unsupported)
+                } else if v.eq_ignore_ascii_case("performance") {
+                    Ok(NotificationClassification::Performance)
+                } else if v.eq_ignore_ascii_case("deprecation") {
+                    Ok(NotificationClassification::Deprecation)
+                } else if v.eq_ignore_ascii_case("security") {
+                    Ok(NotificationClassification::Security)
+                } else if v.eq_ignore_ascii_case("topology") {
+                    Ok(NotificationClassification::Topology)
+                } else if v.eq_ignore_ascii_case("generic") {
+                    Ok(NotificationClassification::Generic)
+                } else if v.eq_ignore_ascii_case("schema") {
+                    Ok(NotificationClassification::Schema)
+                } else {
+                    Err(de::Error::unknown_variant(
+                        v,
+                        &[
+                            "HINT",
+                            "UNRECOGNIZED",
+                            "UNSUPPORTED",
+                            "PERFORMANCE",
+                            "DEPRECATION",
+                            "SECURITY",
+                            "TOPOLOGY",
+                            "GENERIC",
+                            "SCHEMA",
+                        ],
+                    ))
+                }
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&v)
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(v)
+            }
+        }
+
+        deserializer.deserialize_any(TheVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_are_only_sent_on_bolt_5_2() {
+        let filter = NotificationFilter {
+            minimum_severity: Some(NotificationSeverity::Warning),
+            disabled_categories: Some(vec![NotificationClassification::Deprecation]),
+        };
+
+        assert_eq!(filter.entries(Version::V4_4), vec![]);
+        assert_eq!(
+            filter.entries(Version::V5_2),
+            vec![
+                (
+                    "notifications_minimum_severity",
+                    NotificationValue::Severity(NotificationSeverity::Warning)
+                ),
+                (
+                    "notifications_disabled_categories",
+                    NotificationValue::Categories(vec![NotificationClassification::Deprecation])
+                ),
+            ]
+        );
+        assert_eq!(
+            filter.entries(Version::V5_5)[1].0,
+            "notifications_disabled_classifications"
+        );
+        assert_eq!(NotificationFilter::default().entries(Version::V5_5), vec![]);
+    }
+
+    #[test]
+    fn convert_filters_to_bolt() {
+        let severity = BoltType::from(NotificationValue::Severity(NotificationSeverity::Off));
+        assert_eq!(severity, BoltType::from("OFF"));
+
+        let categories = BoltType::from(NotificationValue::Categories(vec![
+            NotificationClassification::Hint,
+            NotificationClassification::Generic,
+        ]));
+        assert_eq!(
+            categories,
+            BoltType::List(BoltList::from(vec![
+                BoltType::from("HINT"),
+                BoltType::from("GENERIC"),
+            ]))
+        );
+    }
+}
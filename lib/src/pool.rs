@@ -1,6 +1,5 @@
 use std::time::Duration;
 
-use crate::auth::ConnectionTLSConfig;
 use crate::{
     config::Config,
     connection::{Connection, ConnectionInfo},
@@ -19,22 +18,8 @@ pub struct ConnectionManager {
 }
 
 impl ConnectionManager {
-    pub fn new(
-        uri: &str,
-        user: &str,
-        password: &str,
-        tls_config: &ConnectionTLSConfig,
-        connection_timeout: Duration,
-        tcp_keepalive: Option<Duration>,
-    ) -> Result<Self> {
-        let info = ConnectionInfo::new(
-            uri,
-            user,
-            password,
-            tls_config,
-            connection_timeout,
-            tcp_keepalive,
-        )?;
+    pub fn new(config: &Config) -> Result<Self> {
+        let info = ConnectionInfo::new(config)?;
         let backoff = backoff();
         Ok(ConnectionManager { info, backoff })
     }
@@ -76,14 +61,7 @@ impl Manager for ConnectionManager {
 }
 
 pub fn create_pool(config: &Config) -> Result<ConnectionPool> {
-    let mgr = ConnectionManager::new(
-        &config.uri,
-        &config.user,
-        &config.password,
-        &config.tls_config,
-        config.connection_timeout,
-        config.tcp_keepalive,
-    )?;
+    let mgr = ConnectionManager::new(config)?;
     info!(
         "creating connection pool for node {} with max size {}",
         config.uri, config.max_connections
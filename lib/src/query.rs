@@ -1,8 +1,9 @@
 use std::cell::{Cell, RefCell};
 
-use crate::config::ImpersonateUser;
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-use crate::{bolt::Summary, summary::ResultSummary};
+use crate::bolt::Summary;
+use crate::config::ImpersonateUser;
+use crate::fetch_size::AdaptiveFetchSize;
 use crate::{
     errors::Result,
     graph::ConnectionPoolManager,
@@ -10,14 +11,13 @@ use crate::{
     pool::ManagedConnection,
     retry::Retry,
     stream::{DetachedRowStream, RowStream},
-    types::{BoltList, BoltMap, BoltString, BoltType},
-    Database, Error, Operation, Success,
+    summary::ResultSummary,
+    types::{BoltMap, BoltString, BoltType},
+    Database, Error, Operation,
 };
 
-#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+/// The result of running a query without consuming its rows.
 pub type RunResult = ResultSummary;
-#[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-pub type RunResult = ();
 
 /// Abstracts a cypher query that is sent to neo4j server.
 #[derive(Clone)]
@@ -25,6 +25,8 @@ pub struct Query {
     query: String,
     params: BoltMap,
     extra: BoltMap,
+    fetch_size: Option<usize>,
+    adaptive_fetch_size: Option<AdaptiveFetchSize>,
 }
 
 impl Query {
@@ -33,9 +35,28 @@ impl Query {
             query,
             params: BoltMap::default(),
             extra: BoltMap::default(),
+            fetch_size: None,
+            adaptive_fetch_size: None,
         }
     }
 
+    /// Overrides the number of rows to fetch in one batch for this query.
+    ///
+    /// Takes precedence over the fetch size of the [`crate::Config`], [`crate::SessionConfig`], or [`crate::Txn`].
+    /// Bolt does not allow to fetch zero rows, so a fetch size of `0` fetches one row per batch.
+    pub fn fetch_size(mut self, fetch_size: usize) -> Self {
+        self.fetch_size = Some(fetch_size.max(1));
+        self
+    }
+
+    /// Adapts the number of rows to fetch in one batch for this query
+    /// to the size of the rows and the speed at which they are consumed,
+    /// see [`RowStream::with_adaptive_fetch_size`].
+    pub fn adaptive_fetch_size(mut self, adaptive: AdaptiveFetchSize) -> Self {
+        self.adaptive_fetch_size = Some(adaptive);
+        self
+    }
+
     pub fn with_params(mut self, params: BoltMap) -> Self {
         self.params = params;
         self
@@ -99,13 +120,6 @@ impl Query {
         &self.params
     }
 
-    pub(crate) async fn run(self, connection: &mut ManagedConnection) -> Result<RunResult> {
-        let request = BoltRequest::run(&self.query, self.params, self.extra);
-        Self::try_run(request, connection)
-            .await
-            .map_err(Retry::into_inner)
-    }
-
     pub(crate) fn into_retryable<'a>(
         self,
         db: Option<Database>,
@@ -144,8 +158,14 @@ impl Query {
         &self,
         connection: &mut ManagedConnection,
     ) -> QueryResult<RunResult> {
-        let request = BoltRequest::run(&self.query, self.params.clone(), self.extra.clone());
-        Self::try_run(request, connection).await
+        let stream = self
+            .clone()
+            .write_run(connection)
+            .await
+            .map_err(Retry::no)?;
+        connection.flush().await.map_err(Retry::no)?;
+        let stream = Self::recv_run(stream, connection).await?;
+        stream.finish(connection).await.map_err(into_retry)
     }
 
     pub(crate) async fn execute_retryable(
@@ -153,61 +173,77 @@ impl Query {
         fetch_size: usize,
         mut connection: ManagedConnection,
     ) -> QueryResult<DetachedRowStream> {
-        let request = BoltRequest::run(&self.query, self.params.clone(), self.extra.clone());
-        Self::try_execute(request, fetch_size, &mut connection)
+        let stream = self
+            .clone()
+            .write_execute(fetch_size, &mut connection)
+            .await
+            .map_err(Retry::no)?;
+        connection.flush().await.map_err(Retry::no)?;
+        Self::recv_execute(stream, &mut connection)
             .await
             .map(|stream| DetachedRowStream::new(stream, connection))
     }
 
-    pub(crate) async fn execute_mut(
+    /// Writes the RUN of this query and a DISCARD of all of its rows, without sending them.
+    ///
+    /// Once the requests are sent, the responses are received with [`Self::recv_run`]
+    /// followed by [`RowStream::finish`].
+    pub(crate) async fn write_run(self, connection: &mut ManagedConnection) -> Result<RowStream> {
+        let mut stream = RowStream::new(0);
+        connection.write(self.into_request()).await?;
+        stream.write_discard(connection).await?;
+        Ok(stream)
+    }
+
+    /// Writes the RUN of this query and the PULL of the first batch of rows, without sending them.
+    ///
+    /// Once the requests are sent, the responses are received with [`Self::recv_execute`].
+    pub(crate) async fn write_execute(
         self,
         fetch_size: usize,
         connection: &mut ManagedConnection,
     ) -> Result<RowStream> {
-        let run = BoltRequest::run(&self.query, self.params, self.extra);
-        Self::try_execute(run, fetch_size, connection)
-            .await
-            .map_err(Retry::into_inner)
+        let mut stream = RowStream::new(self.fetch_size.unwrap_or(fetch_size))
+            .with_adaptive(self.adaptive_fetch_size);
+        connection.write(self.into_request()).await?;
+        stream.write_pull(connection).await?;
+        Ok(stream)
     }
 
-    async fn try_run(
-        request: BoltRequest,
+    /// Receives the responses to the requests written by [`Self::write_execute`],
+    /// the first batch of rows is buffered in the returned stream.
+    pub(crate) async fn recv_execute(
+        stream: RowStream,
         connection: &mut ManagedConnection,
-    ) -> QueryResult<RunResult> {
-        let result = Self::try_execute(request, 4096, connection).await?;
-        Ok(result.finish(connection).await?)
+    ) -> QueryResult<RowStream> {
+        let mut stream = Self::recv_run(stream, connection).await?;
+        stream.fetch(connection).await.map_err(into_retry)?;
+        Ok(stream)
     }
 
-    async fn try_execute(
-        request: BoltRequest,
-        fetch_size: usize,
+    /// Receives the response to the RUN of a query.
+    ///
+    /// If the RUN failed, the server ignores the requests that were pipelined after it,
+    /// and their responses are skipped.
+    pub(crate) async fn recv_run(
+        mut stream: RowStream,
         connection: &mut ManagedConnection,
     ) -> QueryResult<RowStream> {
-        Self::try_request(request, connection).await.map(|success| {
-            let fields: BoltList = success.get("fields").unwrap_or_default();
-            let qid: i64 = success.get("qid").unwrap_or(-1);
-
-            #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-            {
-                let available: i64 = success.get("t_first").unwrap_or(-1);
-                RowStream::new(qid, available, fields, fetch_size)
+        match connection.recv().await {
+            Ok(BoltResponse::Success(success)) => {
+                stream.started(&success);
+                Ok(stream)
             }
-
-            #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-            {
-                RowStream::new(qid, fields, fetch_size)
+            Ok(response) => {
+                connection.skip_responses(0).await.map_err(Retry::no)?;
+                wrap_error(Ok(response), "RUN")
             }
-        })
+            Err(e) => Err(Retry::no(e)),
+        }
     }
 
-    async fn try_request(
-        request: BoltRequest,
-        connection: &mut ManagedConnection,
-    ) -> QueryResult<Success> {
-        match connection.send_recv(request).await {
-            Ok(BoltResponse::Success(success)) => Ok(success),
-            otherwise => wrap_error(otherwise, "RUN"),
-        }
+    fn into_request(self) -> BoltRequest {
+        BoltRequest::run(&self.query, self.params, self.extra)
     }
 }
 
@@ -235,16 +271,19 @@ impl std::fmt::Debug for Query {
 pub(crate) type QueryResult<T> = Result<T, Retry<Error>>;
 
 fn wrap_error<T>(resp: impl IntoError, req: &'static str) -> QueryResult<T> {
-    let error = resp.into_error(req);
+    Err(into_retry(resp.into_error(req)))
+}
+
+fn into_retry(error: Error) -> Retry<Error> {
     let can_retry = match &error {
         Error::Neo4j(e) => e.can_retry(),
         _ => false,
     };
 
     if can_retry {
-        Err(Retry::yes(error))
+        Retry::yes(error)
     } else {
-        Err(Retry::no(error))
+        Retry::no(error)
     }
 }
 
@@ -504,4 +543,13 @@ mod tests {
         assert!(q.has_param_key("name"));
         assert!(!q.has_param_key("country"));
     }
+
+    #[test]
+    fn fetch_at_least_one_row() {
+        let q = Query::new("RETURN 1".to_owned()).fetch_size(0);
+        assert_eq!(q.fetch_size, Some(1));
+
+        let q = Query::new("RETURN 1".to_owned()).fetch_size(42);
+        assert_eq!(q.fetch_size, Some(42));
+    }
 }
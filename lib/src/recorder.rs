@@ -0,0 +1,247 @@
+use crate::{
+    errors::Result,
+    types::{BoltMap, BoltType},
+    version::Version,
+};
+use bytes::{Bytes, BytesMut};
+use log::warn;
+use std::{
+    fmt::{self, Write as _},
+    fs::File,
+    io::{BufWriter, Write as _},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+};
+use tokio::sync::oneshot;
+
+/// The signature of the HELLO message, whose credentials are not recorded.
+const HELLO_SIGNATURE: u8 = 0x01;
+/// The signature of the LOGON message, which carries the credentials since Bolt 5.1.
+const LOGON_SIGNATURE: u8 = 0x6A;
+
+/// Records the Bolt messages of every connection to a file, see [`crate::ConfigBuilder::record_wire`].
+///
+/// Every line of the recording belongs to one connection, which is identified by a number.
+/// The first line of a connection contains the negotiated version,
+/// followed by the messages that the driver sent (`C:`) and received (`S:`) as hex encoded packstream.
+/// The credentials of the `HELLO` and `LOGON` messages are replaced with `***`.
+///
+/// ```text
+/// 1 V: 4.4
+/// 1 C: B101A4...
+/// 1 S: B170A2...
+/// ```
+///
+/// With the `test-util` feature, a recording can be replayed with `testing::StubServer::replay`.
+///
+/// The file is written by a background thread, so that recording does not block the connections.
+/// Use [`WireRecorder::flush`] to wait until the messages recorded so far have been written,
+/// for example before the recording is read or the program exits.
+#[derive(Clone)]
+pub struct WireRecorder {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    commands: Sender<Command>,
+    connections: AtomicUsize,
+}
+
+enum Command {
+    Write(String),
+    Flush(oneshot::Sender<()>),
+}
+
+impl WireRecorder {
+    /// Creates a recorder that writes to the given file, replacing its contents.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::create(path)?;
+        let (commands, received) = mpsc::channel();
+        // the thread is not joined, it stops once the recorder and all of its connections are dropped
+        std::thread::Builder::new()
+            .name("neo4rs-wire-recorder".into())
+            .spawn(move || write_lines(BufWriter::new(file), received))?;
+        Ok(WireRecorder {
+            inner: Arc::new(Inner {
+                commands,
+                connections: AtomicUsize::new(0),
+            }),
+        })
+    }
+
+    /// Waits until all messages that have been recorded so far are written to the file.
+    pub async fn flush(&self) -> Result<()> {
+        let (done, flushed) = oneshot::channel();
+        let stopped = || std::io::Error::other("the recording has stopped");
+        self.inner
+            .commands
+            .send(Command::Flush(done))
+            .map_err(|_| stopped())?;
+        flushed.await.map_err(|_| stopped())?;
+        Ok(())
+    }
+
+    /// Starts the recording of a new connection.
+    pub(crate) fn connection(&self, version: Version) -> ConnectionRecorder {
+        let id = self.inner.connections.fetch_add(1, Ordering::Relaxed) + 1;
+        let recorder = ConnectionRecorder {
+            id,
+            recorder: self.clone(),
+        };
+        recorder.write("V", &version.to_string());
+        recorder
+    }
+}
+
+impl fmt::Debug for WireRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WireRecorder")
+            .field("connections", &self.inner.connections)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Writes the received lines to the file, which is flushed whenever no more lines are waiting.
+/// Flush requests are answered once the lines that were received before them have been flushed.
+fn write_lines(mut file: BufWriter<File>, commands: Receiver<Command>) {
+    while let Ok(command) = commands.recv() {
+        let mut result = Ok(());
+        let mut flushed = Vec::new();
+        for command in std::iter::once(command).chain(commands.try_iter()) {
+            match command {
+                Command::Write(line) => {
+                    result = result.and_then(|()| file.write_all(line.as_bytes()));
+                }
+                Command::Flush(done) => flushed.push(done),
+            }
+        }
+        if let Err(e) = result.and_then(|()| file.flush()) {
+            warn!("Could not record Bolt message: {e}");
+        }
+        for done in flushed {
+            let _ = done.send(());
+        }
+    }
+}
+
+/// Records the messages of a single connection.
+#[derive(Debug)]
+pub(crate) struct ConnectionRecorder {
+    id: usize,
+    recorder: WireRecorder,
+}
+
+impl ConnectionRecorder {
+    pub(crate) fn sent(&self, version: Version, message: &Bytes) {
+        if matches!(message.get(1), Some(&HELLO_SIGNATURE | &LOGON_SIGNATURE)) {
+            match redact_credentials(version, message.clone()) {
+                Ok(message) => self.write("C", &hex(&message)),
+                Err(e) => warn!("Could not record HELLO or LOGON message: {e}"),
+            }
+        } else {
+            self.write("C", &hex(message));
+        }
+    }
+
+    pub(crate) fn received(&self, message: &Bytes) {
+        self.write("S", &hex(message));
+    }
+
+    fn write(&self, tag: &str, content: &str) {
+        let line = format!("{} {tag}: {content}\n", self.id);
+        let sent = self.recorder.inner.commands.send(Command::Write(line));
+        if sent.is_err() {
+            warn!("Could not record Bolt message, the recording has stopped");
+        }
+    }
+}
+
+/// Replaces the credentials of a HELLO or LOGON message.
+fn redact_credentials(version: Version, mut message: Bytes) -> Result<Bytes> {
+    let header = message.split_to(2);
+    let mut extra = match BoltType::parse(version, &mut message)? {
+        BoltType::Map(extra) => extra,
+        _ => BoltMap::default(),
+    };
+    if extra.value.contains_key("credentials") {
+        extra.put("credentials".into(), "***".into());
+    }
+
+    let mut redacted = BytesMut::from(&header[..]);
+    BoltType::Map(extra).write_into(version, &mut redacted)?;
+    Ok(redacted.freeze())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(
+        String::with_capacity(bytes.len() * 2),
+        |mut output, byte| {
+            let _ = write!(output, "{byte:02X}");
+            output
+        },
+    )
+}
+
+/// Decodes a message that has been encoded with [`hex`].
+#[cfg(feature = "test-util")]
+pub(crate) fn unhex(hex: &str) -> Option<Bytes> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()
+        .map(Bytes::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_redact_credentials() {
+        let extra = [
+            ("scheme", "basic"),
+            ("principal", "neo4j"),
+            ("credentials", "secret"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect::<BoltMap>();
+        let mut message = BytesMut::from(&[0xB1, HELLO_SIGNATURE][..]);
+        BoltType::Map(extra)
+            .write_into(Version::V4_4, &mut message)
+            .unwrap();
+
+        let mut redacted = redact_credentials(Version::V4_4, message.freeze()).unwrap();
+        assert_eq!(&redacted[..2], &[0xB1, HELLO_SIGNATURE]);
+
+        let _ = redacted.split_to(2);
+        let BoltType::Map(extra) = BoltType::parse(Version::V4_4, &mut redacted).unwrap() else {
+            panic!("expected a map");
+        };
+        assert_eq!(extra.get::<String>("credentials").unwrap(), "***");
+        assert_eq!(extra.get::<String>("principal").unwrap(), "neo4j");
+    }
+
+    #[tokio::test]
+    async fn should_record_connections() {
+        let path = std::env::temp_dir().join(format!("neo4rs-{}.bolt", std::process::id()));
+        let recorder = WireRecorder::create(&path).unwrap();
+
+        let first = recorder.connection(Version::V4_4);
+        let second = recorder.connection(Version::V4_3);
+        first.received(&Bytes::from_static(&[0xB1, 0x70, 0xA0]));
+        second.sent(Version::V4_3, &Bytes::from_static(&[0xB0, 0x0F]));
+        recorder.flush().await.unwrap();
+
+        let recording = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(recording, "1 V: 4.4\n2 V: 4.3\n1 S: B170A0\n2 C: B00F\n");
+    }
+}
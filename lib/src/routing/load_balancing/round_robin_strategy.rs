@@ -208,12 +208,17 @@ mod tests {
             max_connections: 10,
             db: None,
             fetch_size: 200,
+            pipeline_begin: false,
             tls_config: ConnectionTLSConfig::None,
             imp_user: None,
             connection_timeout: std::time::Duration::from_secs(30),
             tcp_keepalive: Some(std::time::Duration::from_secs(60)),
             idle_timeout: None,
             max_lifetime: None,
+            notifications: Default::default(),
+            wire_recorder: None,
+            proxy: None,
+            socket: Default::default(),
         };
         let registry = Arc::new(ConnectionRegistry::new(
             &config,
@@ -40,14 +40,7 @@ impl RoutingTableProvider for ClusterRoutingTableProvider {
         let pool = router.unwrap_or(self.pool.clone());
         let bookmarks = bookmarks.to_vec();
         Box::pin(async move {
-            let info = ConnectionInfo::new(
-                &config.uri,
-                &config.user,
-                &config.password,
-                &config.tls_config,
-                config.connection_timeout,
-                config.tcp_keepalive,
-            )?;
+            let info = ConnectionInfo::new(&config)?;
             let mut connection = pool.get().await?;
             let version = connection.version();
             let mut builder = RouteBuilder::new(info.init.routing, bookmarks);
@@ -12,7 +12,7 @@ use std::convert::TryInto;
 /// Represents a row returned as a result of executing a query.
 ///
 /// A row is very similar to a `HashMap`, you can get the attributes using [`Row::get`] method.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Row {
     attributes: BoltMap,
 }
@@ -111,6 +111,10 @@ impl Point2D {
         Point2D { inner }
     }
 
+    pub(crate) fn into_inner(self) -> BoltPoint2D {
+        self.inner
+    }
+
     /// Spatial refrerence system identifier, see <https://en.wikipedia.org/wiki/Spatial_reference_system#Identifier>
     pub fn sr_id(&self) -> i64 {
         self.inner.sr_id.value
@@ -130,6 +134,10 @@ impl Point3D {
         Point3D { inner }
     }
 
+    pub(crate) fn into_inner(self) -> BoltPoint3D {
+        self.inner
+    }
+
     /// Spatial refrerence system identifier, see <https://en.wikipedia.org/wiki/Spatial_reference_system#Identifier>
     pub fn sr_id(&self) -> i64 {
         self.inner.sr_id.value
@@ -151,7 +159,7 @@ impl Point3D {
 impl Row {
     pub fn new(fields: BoltList, data: BoltList) -> Self {
         let mut attributes = BoltMap::with_capacity(fields.len());
-        for (field, value) in fields.into_iter().zip(data.into_iter()) {
+        for (field, value) in fields.into_iter().zip(data) {
             if let Ok(key) = field.try_into() {
                 attributes.put(key, value);
             }
@@ -208,6 +216,11 @@ impl Node {
         self.inner.id.value
     }
 
+    /// Element id of the node, only sent by the server since Bolt 5
+    pub fn element_id(&self) -> Option<&str> {
+        self.inner.element_id()
+    }
+
     /// various labels attached to this node
     pub fn labels(&self) -> Vec<&str> {
         self.to::<crate::Labels<_>>().unwrap().0
@@ -252,6 +265,21 @@ impl Relation {
         self.inner.end_node_id.value
     }
 
+    /// Element id of the relationship, only sent by the server since Bolt 5
+    pub fn element_id(&self) -> Option<&str> {
+        self.inner.element_id()
+    }
+
+    /// Element id of the start node, only sent by the server since Bolt 5
+    pub fn start_node_element_id(&self) -> Option<&str> {
+        self.inner.start_node_element_id()
+    }
+
+    /// Element id of the end node, only sent by the server since Bolt 5
+    pub fn end_node_element_id(&self) -> Option<&str> {
+        self.inner.end_node_element_id()
+    }
+
     pub fn typ(&self) -> &str {
         self.to::<crate::Type<_>>().unwrap().0
     }
@@ -287,6 +315,11 @@ impl UnboundedRelation {
         self.inner.id.value
     }
 
+    /// Element id of the relationship, only sent by the server since Bolt 5
+    pub fn element_id(&self) -> Option<&str> {
+        self.inner.element_id()
+    }
+
     pub fn typ(&self) -> &str {
         self.to::<crate::Type<_>>().unwrap().0
     }
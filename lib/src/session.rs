@@ -1,6 +1,9 @@
 use crate::config::ImpersonateUser;
+use crate::notification::{NotificationClassification, NotificationFilter, NotificationSeverity};
 use crate::summary::Counters;
-use crate::{Database, DetachedRowStream, Error, Graph, Operation, Query, RowStream, RunResult};
+use crate::{
+    Database, DetachedRowStream, Error, Graph, Operation, Query, RowStream, RunResult, Txn,
+};
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::Arc;
@@ -11,6 +14,7 @@ pub struct SessionConfig {
     imp_user: Option<ImpersonateUser>,
     fetch_size: Option<usize>,
     bookmarks: Vec<String>,
+    notifications: NotificationFilter,
 }
 
 impl SessionConfig {
@@ -25,6 +29,7 @@ pub struct SessionConfigBuilder {
     imp_user: Option<ImpersonateUser>,
     fetch_size: Option<usize>,
     bookmarks: Vec<String>,
+    notifications: NotificationFilter,
 }
 
 impl SessionConfigBuilder {
@@ -48,12 +53,38 @@ impl SessionConfigBuilder {
         self
     }
 
+    /// The minimum severity of the notifications that the server should send
+    /// in the transactions of this session, see [`Session::write_transaction`],
+    /// overriding [`crate::ConfigBuilder::notifications_minimum_severity`].
+    ///
+    /// Notification filters are part of Bolt 5.2. On older protocol versions,
+    /// the server sends all notifications.
+    pub fn with_notifications_minimum_severity(mut self, severity: NotificationSeverity) -> Self {
+        self.notifications.minimum_severity = Some(severity);
+        self
+    }
+
+    /// The categories of notifications that the server should not send
+    /// in the transactions of this session, see [`Session::write_transaction`],
+    /// overriding [`crate::ConfigBuilder::notifications_disabled_categories`].
+    ///
+    /// Notification filters are part of Bolt 5.2. On older protocol versions,
+    /// the server sends all notifications.
+    pub fn with_notifications_disabled_categories(
+        mut self,
+        categories: impl IntoIterator<Item = NotificationClassification>,
+    ) -> Self {
+        self.notifications.disabled_categories = Some(categories.into_iter().collect());
+        self
+    }
+
     pub fn build(self) -> SessionConfig {
         SessionConfig {
             db: self.db,
             imp_user: self.imp_user,
             fetch_size: self.fetch_size,
             bookmarks: self.bookmarks,
+            notifications: self.notifications,
         }
     }
 }
@@ -65,6 +96,7 @@ pub struct Session {
     imp_user: Option<ImpersonateUser>,
     fetch_size: Option<usize>,
     bookmarks: Vec<String>,
+    notifications: NotificationFilter,
     should_fetch_default_db: AtomicBool,
     driver: Arc<Graph>,
 }
@@ -76,6 +108,7 @@ impl Session {
             imp_user: config.imp_user,
             fetch_size: config.fetch_size,
             bookmarks: config.bookmarks,
+            notifications: config.notifications,
             should_fetch_default_db: AtomicBool::new(true),
             driver: graph,
         }
@@ -142,21 +175,11 @@ impl Session {
         &mut self,
         queries: Vec<impl Into<Query>>,
     ) -> crate::Result<Counters> {
-        self.update_db_name().await?;
-        let mut txn = self
-            .driver
-            .impl_start_txn_on(
-                self.db.clone(),
-                Operation::Write,
-                self.imp_user.clone(),
-                &self.bookmarks,
-                self.fetch_size,
-            )
-            .await?;
+        let mut txn = self.begin_txn(Operation::Write).await?;
         match txn.run_queries(queries).await {
             Ok(counters) => match txn.commit().await {
                 Ok(Some(bookmark)) => {
-                    self.bookmarks = vec![bookmark.clone()];
+                    self.set_bookmark(bookmark);
                     Ok(counters)
                 }
                 Ok(None) => Ok(counters),
@@ -170,18 +193,28 @@ impl Session {
     }
 
     pub async fn read_transaction(&mut self, query: impl Into<Query>) -> crate::Result<RowStream> {
+        let mut txn = self.begin_txn(Operation::Read).await?;
+        txn.execute(query).await
+    }
+
+    /// Starts a transaction with the configuration and the bookmarks of this session.
+    pub(crate) async fn begin_txn(&mut self, operation: Operation) -> crate::Result<Txn> {
         self.update_db_name().await?;
-        let mut txn = self
-            .driver
+        self.driver
             .impl_start_txn_on(
                 self.db.clone(),
-                Operation::Read,
+                operation,
                 self.imp_user.clone(),
                 &self.bookmarks,
                 self.fetch_size,
+                &self.notifications,
             )
-            .await?;
-        txn.execute(query).await
+            .await
+    }
+
+    /// Replaces the bookmarks of this session with the bookmark of a committed transaction.
+    pub(crate) fn set_bookmark(&mut self, bookmark: String) {
+        self.bookmarks = vec![bookmark];
     }
 
     pub fn last_bookmark(&self) -> Option<String> {
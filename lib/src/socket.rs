@@ -0,0 +1,210 @@
+//! Opens TCP connections with the configured socket options.
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::{
+    future::Future,
+    io,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+use tokio::net::{TcpSocket, TcpStream};
+use url::Host;
+
+/// The delay after which the next address is tried while earlier attempts are still pending,
+/// as recommended by RFC 8305 (Happy Eyeballs).
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// The options of the TCP sockets, see the socket options of [`crate::ConfigBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SocketOptions {
+    pub(crate) nodelay: bool,
+    /// The time between keepalive probes.
+    pub(crate) keepalive_interval: Duration,
+    /// The number of unanswered keepalive probes before the connection is closed.
+    pub(crate) keepalive_retries: u32,
+    pub(crate) send_buffer_size: Option<u32>,
+    pub(crate) recv_buffer_size: Option<u32>,
+    pub(crate) bind_address: Option<IpAddr>,
+}
+
+impl Default for SocketOptions {
+    fn default() -> Self {
+        SocketOptions {
+            nodelay: true,
+            keepalive_interval: Duration::from_secs(10),
+            keepalive_retries: 3,
+            send_buffer_size: None,
+            recv_buffer_size: None,
+            bind_address: None,
+        }
+    }
+}
+
+/// Connects to the first address of the host that answers.
+///
+/// The addresses of both IP families are tried alternately, and a new attempt is started
+/// whenever an attempt fails or has not succeeded after a short delay,
+/// so that a broken IPv6 or IPv4 path does not delay the connection.
+pub(crate) async fn connect(
+    host: &Host<Arc<str>>,
+    port: u16,
+    options: &SocketOptions,
+) -> io::Result<TcpStream> {
+    let addresses = match host {
+        Host::Domain(domain) => tokio::net::lookup_host((&**domain, port)).await?.collect(),
+        Host::Ipv4(ip) => vec![SocketAddr::new((*ip).into(), port)],
+        Host::Ipv6(ip) => vec![SocketAddr::new((*ip).into(), port)],
+    };
+    let addresses = interleave(addresses, options.bind_address);
+    let stream = race(addresses, |address| attempt(address, options)).await?;
+    stream.set_nodelay(options.nodelay)?;
+    Ok(stream)
+}
+
+/// Orders the addresses so that the IP families alternate, starting with the family
+/// of the first address. Only addresses of the family of the bind address are kept.
+fn interleave(addresses: Vec<SocketAddr>, bind_address: Option<IpAddr>) -> Vec<SocketAddr> {
+    let addresses = addresses
+        .into_iter()
+        .filter(|address| !bind_address.is_some_and(|ip| ip.is_ipv4() != address.is_ipv4()));
+    let prefer_ipv6 = addresses.clone().next().is_some_and(|a| a.is_ipv6());
+    let (preferred, other): (Vec<_>, Vec<_>) = addresses.partition(|a| a.is_ipv6() == prefer_ipv6);
+
+    let mut interleaved = Vec::with_capacity(preferred.len() + other.len());
+    let (mut preferred, mut other) = (preferred.into_iter(), other.into_iter());
+    loop {
+        match (preferred.next(), other.next()) {
+            (None, None) => return interleaved,
+            (first, second) => interleaved.extend(first.into_iter().chain(second)),
+        }
+    }
+}
+
+/// Starts the `attempt` for each address in turn until one succeeds.
+async fn race<A: Future<Output = io::Result<TcpStream>>>(
+    addresses: Vec<SocketAddr>,
+    attempt: impl Fn(SocketAddr) -> A,
+) -> io::Result<TcpStream> {
+    let mut addresses = addresses.into_iter();
+    let mut attempts = FuturesUnordered::new();
+    let mut last_error = None;
+    loop {
+        if attempts.is_empty() {
+            match addresses.next() {
+                Some(address) => attempts.push(attempt(address)),
+                None => {
+                    return Err(last_error.unwrap_or_else(|| {
+                        io::Error::new(io::ErrorKind::NotFound, "no address to connect to")
+                    }))
+                }
+            }
+        }
+        tokio::select! {
+            Some(result) = attempts.next() => match result {
+                Ok(stream) => return Ok(stream),
+                Err(e) => {
+                    last_error = Some(e);
+                    if let Some(address) = addresses.next() {
+                        attempts.push(attempt(address));
+                    }
+                }
+            },
+            _ = tokio::time::sleep(CONNECTION_ATTEMPT_DELAY), if !addresses.as_slice().is_empty() => {
+                if let Some(address) = addresses.next() {
+                    attempts.push(attempt(address));
+                }
+            }
+        }
+    }
+}
+
+async fn attempt(address: SocketAddr, options: &SocketOptions) -> io::Result<TcpStream> {
+    let socket = match address {
+        SocketAddr::V4(_) => TcpSocket::new_v4()?,
+        SocketAddr::V6(_) => TcpSocket::new_v6()?,
+    };
+    if let Some(size) = options.send_buffer_size {
+        socket.set_send_buffer_size(size)?;
+    }
+    if let Some(size) = options.recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(ip) = options.bind_address {
+        socket.bind(SocketAddr::new(ip, 0))?;
+    }
+    socket.connect(address).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn should_interleave_address_families() {
+        let v4 = |last| SocketAddr::new(Ipv4Addr::new(10, 0, 0, last).into(), 7687);
+        let v6 = |last| SocketAddr::new(Ipv6Addr::new(0xfd, 0, 0, 0, 0, 0, 0, last).into(), 7687);
+
+        let addresses = vec![v6(1), v6(2), v6(3), v4(1), v4(2)];
+        assert_eq!(
+            interleave(addresses.clone(), None),
+            vec![v6(1), v4(1), v6(2), v4(2), v6(3)]
+        );
+        assert_eq!(
+            interleave(vec![v4(1), v6(1), v4(2)], None),
+            vec![v4(1), v6(1), v4(2)]
+        );
+        assert_eq!(
+            interleave(addresses, Some(Ipv4Addr::LOCALHOST.into())),
+            vec![v4(1), v4(2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_connect_with_options() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let options = SocketOptions {
+            recv_buffer_size: Some(64 * 1024),
+            bind_address: Some(Ipv4Addr::LOCALHOST.into()),
+            ..SocketOptions::default()
+        };
+
+        let stream = connect(&Host::Domain("localhost".into()), port, &options)
+            .await
+            .unwrap();
+        assert!(stream.nodelay().unwrap());
+        assert_eq!(stream.local_addr().unwrap().ip(), Ipv4Addr::LOCALHOST);
+    }
+
+    #[tokio::test]
+    async fn should_try_next_address_if_an_attempt_hangs_or_fails() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let closed = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap()
+        };
+        // an address whose connection attempt never finishes
+        let unreachable = SocketAddr::new(Ipv4Addr::new(10, 255, 255, 1).into(), 7687);
+        let options = &SocketOptions::default();
+        let connect = |address| async move {
+            if address == unreachable {
+                std::future::pending::<()>().await;
+            }
+            attempt(address, options).await
+        };
+
+        let stream = tokio::time::timeout(
+            Duration::from_secs(5),
+            race(vec![unreachable, closed, address], connect),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), address);
+
+        assert!(race(vec![closed], connect).await.is_err());
+    }
+}
@@ -1,30 +1,36 @@
 #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
 use crate::messages::{BoltRequest, BoltResponse};
-#[cfg(feature = "unstable-result-summary")]
-use crate::summary::{ResultSummary, Streaming};
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 use crate::{
-    bolt::{Bolt, Discard, Pull, Response, Summary, WrapExtra as _},
+    bolt::{Bolt, Discard, ExpectedResponse, Pull, Response, Summary, WrapExtra as _},
     BoltType,
 };
 use crate::{
     errors::{Error, Result},
+    fetch_size::{AdaptiveFetchSize, AdaptiveState},
     pool::ManagedConnection,
     row::Row,
+    summary::{ResultSummary, Streaming},
     txn::TransactionHandle,
     types::BoltList,
-    DeError, RunResult,
+    DeError, RunResult, Success,
 };
 
-use futures::{stream::try_unfold, TryStream};
+use futures::{
+    future::BoxFuture,
+    stream::{try_unfold, FusedStream},
+    FutureExt as _, Stream, StreamExt as _, TryStream,
+};
 use serde::de::DeserializeOwned;
 
-use std::collections::VecDeque;
-
-#[cfg(feature = "unstable-result-summary")]
-type BoxedSummary = Box<ResultSummary>;
-#[cfg(not(feature = "unstable-result-summary"))]
-type BoxedSummary = ();
+use std::{
+    collections::{HashMap, VecDeque},
+    marker::PhantomData,
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+    task::{ready, Context, Poll},
+    time::Instant,
+};
 
 /// An abstraction over a stream of rows, this is returned as a result of [`crate::Txn::execute`].
 ///
@@ -33,45 +39,116 @@ type BoxedSummary = ();
 pub struct RowStream {
     qid: i64,
     fields: BoltList,
-    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     available_after: i64,
     state: State,
     fetch_size: usize,
+    adaptive: Option<AdaptiveState>,
+    prefetch: Option<usize>,
+    /// The transaction that the stream belongs to, see [`OpenStreams`].
+    txn: Option<u64>,
     buffer: VecDeque<Row>,
 }
 
 impl RowStream {
-    pub(crate) fn new(
-        qid: i64,
-        #[cfg(feature = "unstable-bolt-protocol-impl-v2")] available_after: i64,
-        fields: BoltList,
-        fetch_size: usize,
-    ) -> Self {
+    /// Creates a stream for a query whose RUN has not been answered yet,
+    /// see [`Self::started`].
+    pub(crate) fn new(fetch_size: usize) -> Self {
         RowStream {
-            qid,
-            #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-            available_after,
-            fields,
+            qid: -1,
+            available_after: -1,
+            fields: BoltList::default(),
             fetch_size,
+            adaptive: None,
+            prefetch: None,
+            txn: None,
             state: State::Ready,
             buffer: VecDeque::with_capacity(fetch_size),
         }
     }
+
+    /// Takes the fields and the query id from the response to the RUN of the query.
+    ///
+    /// Requests for the stream that were pipelined before the RUN was answered
+    /// use the query id `-1`, which refers to the last query.
+    pub(crate) fn started(&mut self, success: &Success) {
+        self.fields = success.get("fields").unwrap_or_default();
+        self.qid = success.get("qid").unwrap_or(-1);
+        self.available_after = success.get("t_first").unwrap_or(-1);
+    }
+
+    /// Enables prefetching of the next batch of rows.
+    ///
+    /// Once fewer than `low_water_mark` rows are buffered, the PULL for the next batch
+    /// is sent right away, instead of after the buffer is empty.
+    /// The server can then produce and send the next batch while the buffered rows are processed,
+    /// which avoids waiting a full round trip for every batch.
+    /// Only the PULL is sent early, the batch is not read in the background,
+    /// but when the buffered rows have been consumed.
+    ///
+    /// If the transaction is used for something else while the prefetched batch is transferred,
+    /// the batch is received first and kept in the [`crate::Txn`] until this stream is consumed again.
+    pub fn with_prefetch(mut self, low_water_mark: usize) -> Self {
+        self.prefetch = Some(low_water_mark);
+        self
+    }
+
+    /// Adapts the number of rows requested per PULL to the size of the rows
+    /// and the speed at which they are consumed, see [`AdaptiveFetchSize`].
+    ///
+    /// The configured fetch size is used for the first batch.
+    pub fn with_adaptive_fetch_size(self, adaptive: AdaptiveFetchSize) -> Self {
+        self.with_adaptive(Some(adaptive))
+    }
+
+    pub(crate) fn in_transaction(mut self, streams: &OpenStreams) -> Self {
+        self.txn = Some(streams.id);
+        self
+    }
+
+    pub(crate) fn with_adaptive(mut self, adaptive: Option<AdaptiveFetchSize>) -> Self {
+        if let Some(adaptive) = adaptive {
+            self.adaptive = Some(AdaptiveState::new(adaptive, self.fetch_size));
+        }
+        self
+    }
 }
 
 /// An abstraction over a stream of rows, this is returned as a result of [`crate::Graph::execute`].
 ///
 /// A stream will contain a connection from the connection pool which will be released to the pool
 /// when the stream is dropped.
+///
+/// The stream implements [`futures::Stream`] and owns its connection,
+/// so it can be returned from functions or used as the body of a streaming response.
+/// After the stream returned an error, it ends and is [`FusedStream::is_terminated`].
+/// If the stream is dropped while a batch of rows is still being received,
+/// or after the next batch has been requested by prefetching,
+/// that batch is read to completion on the current tokio runtime
+/// before the connection is released, so that the connection can be reused.
 #[must_use = "Results must be streamed through with `next` in order to execute the query"]
 pub struct DetachedRowStream {
+    detached: Option<Detached>,
+    fetch: Option<Fetch>,
+    /// Whether polling the stream returned an error, after which it does not return any more rows.
+    failed: bool,
+}
+
+struct Detached {
     stream: RowStream,
     connection: ManagedConnection,
 }
 
+type Fetch = BoxFuture<'static, (Detached, Result<()>)>;
+
+const AVAILABLE: &str = "the connection is available when no fetch is in progress";
+
 impl DetachedRowStream {
     pub(crate) fn new(stream: RowStream, connection: ManagedConnection) -> Self {
-        DetachedRowStream { stream, connection }
+        DetachedRowStream {
+            detached: Some(Detached { stream, connection }),
+            fetch: None,
+            failed: false,
+        }
     }
 }
 
@@ -81,73 +158,146 @@ impl RowStream {
     /// are fetched from the server (using the fetch_size value configured see [`crate::ConfigBuilder::fetch_size`])
     pub async fn next(&mut self, mut handle: impl TransactionHandle) -> Result<Option<Row>> {
         loop {
-            if let Some(row) = self.buffer.pop_front() {
-                return Ok(Some(row));
+            if !self.buffer.is_empty() {
+                // the next batch is requested before the row is taken,
+                // so that the row stays buffered if the request fails
+                self.prefetch(&mut handle, 1).await?;
+                return Ok(self.buffer.pop_front());
             }
-
-            #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-            {
-                if self.state == State::Ready {
-                    let pull = Pull::some(self.fetch_size as i64).for_query(self.qid);
-                    let connection = handle.connection();
-                    connection.send_as(pull).await?;
-                    self.state = loop {
-                        let response = connection
-                            .recv_as::<Response<Vec<Bolt>, Streaming>>()
-                            .await?;
-                        match response {
-                            Response::Detail(record) => {
-                                let record = BoltList::from(
-                                    record
-                                        .into_iter()
-                                        .map(BoltType::from)
-                                        .collect::<Vec<BoltType>>(),
-                                );
-                                let row = Row::new(self.fields.clone(), record);
-                                self.buffer.push_back(row);
-                            }
-                            Response::Success(Streaming::HasMore) => break State::Ready,
-                            Response::Success(Streaming::Done(mut s)) => {
-                                s.set_t_first(self.available_after);
-                                break State::Complete(s);
-                            }
-                            otherwise => return Err(otherwise.into_error("PULL")),
-                        }
-                    };
-                } else if let State::Complete(_) = self.state {
-                    break Ok(None);
-                }
+            if self.is_complete() {
+                return Ok(None);
             }
+            self.fetch(&mut handle).await?;
+        }
+    }
 
-            #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-            {
-                if self.state == State::Ready {
-                    let pull = BoltRequest::pull(self.fetch_size, self.qid);
-                    let connection = handle.connection();
-                    connection.send(pull).await?;
-
-                    self.state = loop {
-                        match connection.recv().await {
-                            Ok(BoltResponse::Success(s)) => {
-                                break if s.get("has_more").unwrap_or(false) {
-                                    State::Ready
-                                } else {
-                                    State::Complete(())
-                                };
-                            }
-                            Ok(BoltResponse::Record(record)) => {
-                                let row = Row::new(self.fields.clone(), record.data);
-                                self.buffer.push_back(row);
-                            }
-                            Ok(msg) => return Err(msg.into_error("PULL")),
-                            Err(e) => return Err(e),
-                        }
-                    };
-                } else if let State::Complete(_) = self.state {
-                    break Ok(None);
-                };
-            }
+    /// Sends a PULL for the next batch of rows, unless it was already sent by prefetching,
+    /// and reads the batch into the buffer.
+    /// Does nothing if the server has no more rows.
+    pub(crate) async fn fetch(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        self.sync(&mut handle).await?;
+        if self.state == State::Ready && self.buffer.is_empty() {
+            self.send_pull(&mut handle).await?;
+        }
+        if self.state == State::Pulling {
+            self.recv_pull(&mut handle).await?;
         }
+        Ok(())
+    }
+
+    async fn send_pull(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        self.write_pull(&mut handle).await?;
+        handle.connection().flush().await
+    }
+
+    /// Writes a PULL for the next batch of rows without sending it,
+    /// so that it can be pipelined with other requests.
+    pub(crate) async fn write_pull(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        let (connection, streams) = handle.parts();
+        let fetch_size = match &mut self.adaptive {
+            Some(adaptive) => adaptive.on_pull(Instant::now()),
+            None => self.fetch_size,
+        };
+
+        #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+        connection
+            .write_as(Pull::some(fetch_size as i64).for_query(self.qid))
+            .await?;
+
+        #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+        connection
+            .write(BoltRequest::pull(fetch_size, self.qid))
+            .await?;
+
+        if let Some(streams) = streams {
+            streams.in_flight = Some(InFlight {
+                qid: self.qid,
+                fields: self.fields.clone(),
+                available_after: self.available_after,
+            });
+        }
+        self.state = State::Pulling;
+        Ok(())
+    }
+
+    /// Reads the response of a PULL that has been sent into the buffer.
+    async fn recv_pull(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        let (connection, streams) = handle.parts();
+        if let Some(streams) = streams {
+            streams.in_flight = None;
+        }
+        let rows_before = self.buffer.len();
+        let bytes_before = connection.bytes_received();
+
+        self.state = recv_batch(
+            connection,
+            &self.fields,
+            self.available_after,
+            &mut self.buffer,
+        )
+        .await?;
+
+        if let Some(adaptive) = &mut self.adaptive {
+            let rows = self.buffer.len() - rows_before;
+            let bytes = connection.bytes_received().wrapping_sub(bytes_before);
+            adaptive.on_batch(Instant::now(), rows, bytes);
+        }
+
+        Ok(())
+    }
+
+    /// Prepares the stream for using the connection of a transaction that is shared with other streams.
+    ///
+    /// A batch of this stream that was received while another stream was used is moved into the buffer,
+    /// and a batch of another stream that is still being transferred is received for that stream.
+    async fn sync(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        let (connection, streams) = handle.parts();
+        let Some(streams) = streams else {
+            return Ok(());
+        };
+        if self.txn != Some(streams.id) {
+            return Err(Error::TransactionMismatch);
+        }
+
+        if streams
+            .in_flight
+            .as_ref()
+            .is_some_and(|in_flight| in_flight.qid != self.qid)
+        {
+            streams.drain(connection).await?;
+        }
+
+        if let Some(drained) = streams.drained.remove(&self.qid) {
+            self.buffer.extend(drained.rows);
+            self.state = drained.state?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the PULL for the next batch early if prefetching is enabled
+    /// and the buffer is below the low-water mark once `taken` rows have been taken from it.
+    async fn prefetch(&mut self, mut handle: impl TransactionHandle, taken: usize) -> Result<()> {
+        if self.should_prefetch(taken) {
+            self.sync(&mut handle).await?;
+            self.send_pull(handle).await?;
+        }
+        Ok(())
+    }
+
+    fn should_prefetch(&self, taken: usize) -> bool {
+        self.state == State::Ready
+            && self.prefetch.is_some_and(|low_water_mark| {
+                self.buffer.len().saturating_sub(taken) < low_water_mark
+            })
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(self.state, State::Complete(_))
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.is_complete() && self.buffer.is_empty()
     }
 
     /// Return the [`RowStream::next`] item,
@@ -246,55 +396,88 @@ impl RowStream {
     /// Stop consuming the stream and return a summary, if available.
     /// Stopping the stream will also discard any messages on the server side.
     pub async fn finish(mut self, mut handle: impl TransactionHandle) -> Result<RunResult> {
+        self.sync(&mut handle).await?;
+        if self.state == State::Pulling {
+            self.recv_pull(&mut handle).await?;
+        }
         self.buffer.clear();
 
-        #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+        if self.state == State::Ready {
+            self.write_discard(&mut handle).await?;
+            handle.connection().flush().await?;
+        }
+        if self.state == State::Discarding {
+            self.recv_discard(&mut handle).await?;
+        }
+
         match self.state {
-            State::Ready => {
-                let summary = {
-                    let connected = handle.connection();
-                    connected
-                        .send_recv_as(Discard::all().for_query(self.qid))
-                        .await
-                }?;
-                let summary = match summary {
-                    Summary::Success(s) => match s.metadata {
-                        Streaming::Done(summary) => *summary,
-                        Streaming::HasMore => {
-                            unreachable!("Query returned has_more after a discard_all");
-                        }
-                    },
-                    Summary::Ignored => {
-                        return Err(Error::RequestIgnoredError);
-                    }
-                    Summary::Failure(f) => {
-                        return Err(f.into_error());
-                    }
-                };
-                Ok(summary)
-            }
             State::Complete(summary) => Ok(*summary),
+            _ => unreachable!("the stream is complete after a DISCARD"),
+        }
+    }
+
+    /// Writes a DISCARD for the remaining rows without sending it,
+    /// so that it can be pipelined with other requests.
+    pub(crate) async fn write_discard(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        let connection = handle.connection();
+
+        #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+        connection
+            .write_as(Discard::all().for_query(self.qid))
+            .await?;
+
+        #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+        connection
+            .write(BoltRequest::discard_all_for(self.qid))
+            .await?;
+
+        self.state = State::Discarding;
+        Ok(())
+    }
+
+    async fn recv_discard(&mut self, mut handle: impl TransactionHandle) -> Result<()> {
+        let connection = handle.connection();
+
+        #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+        {
+            let summary = connection
+                .recv_as::<<Discard as ExpectedResponse>::Response>()
+                .await?;
+            self.state = match summary {
+                Summary::Success(s) => match s.metadata {
+                    Streaming::Done(summary) => State::Complete(summary),
+                    Streaming::HasMore => {
+                        return Err(Error::UnexpectedMessage(
+                            "unexpected response for DISCARD: has_more after a discard_all".into(),
+                        ));
+                    }
+                },
+                Summary::Ignored => {
+                    return Err(Error::RequestIgnoredError);
+                }
+                Summary::Failure(f) => {
+                    return Err(f.into_error());
+                }
+            };
         }
 
         #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-        match self.state {
-            State::Ready => {
-                let summary = {
-                    let connected = handle.connection();
-                    connected
-                        .send_recv(BoltRequest::discard_all_for(self.qid))
-                        .await
-                }?;
-                let summary = match summary {
-                    crate::messages::BoltResponse::Success(_) => Ok(()),
-                    crate::messages::BoltResponse::Failure(f) => Err(Error::Neo4j(f.into_error())),
-                    msg => Err(msg.into_error("DISCARD")),
-                };
-                self.state = State::Complete(());
-                summary
-            }
-            State::Complete(_) => Ok(()),
+        {
+            self.state = match connection.recv().await? {
+                BoltResponse::Success(s) => match Streaming::from_success(&s) {
+                    Streaming::Done(summary) => State::Complete(summary),
+                    Streaming::HasMore => {
+                        return Err(Error::UnexpectedMessage(format!(
+                            "unexpected response for DISCARD: has_more after a discard_all: {s:?}"
+                        )));
+                    }
+                },
+                BoltResponse::Failure(f) => return Err(Error::Neo4j(f.into_error())),
+                msg => return Err(msg.into_error("DISCARD")),
+            };
         }
+
+        Ok(())
     }
 
     /// Turns this RowStream into a [`futures::stream::TryStream`] where
@@ -359,7 +542,8 @@ impl DetachedRowStream {
     /// if the buffer is empty and the server has more rows left to consume, then a new batch of rows
     /// are fetched from the server (using the fetch_size value configured see [`crate::ConfigBuilder::fetch_size`])
     pub async fn next(&mut self) -> Result<Option<Row>> {
-        self.stream.next(&mut self.connection).await
+        let detached = self.detached().await?;
+        detached.stream.next(&mut detached.connection).await
     }
 
     /// Return the [`RowStream::next`] item,
@@ -367,7 +551,8 @@ impl DetachedRowStream {
     ///
     /// Unlike `next`, this method returns a missing items as an error ([`Error::NoMoreRows`]).
     pub async fn next_as<'this, T: DeserializeOwned + 'this>(&'this mut self) -> Result<T> {
-        self.stream.next_as(&mut self.connection).await
+        let detached = self.detached().await?;
+        detached.stream.next_as(&mut detached.connection).await
     }
 
     /// Return the first [`crate::Row`] in the result.
@@ -375,7 +560,8 @@ impl DetachedRowStream {
     /// If there are 0 results, [`Error::NoMoreRows`] is returned.
     /// If there are 2 or more results, [`Error::NotSingleResult`] is returned.
     pub async fn single(&mut self) -> Result<Row> {
-        self.stream.single(&mut self.connection).await
+        let detached = self.detached().await?;
+        detached.stream.single(&mut detached.connection).await
     }
 
     /// Return the first [`crate::Row`] in the result.
@@ -384,7 +570,8 @@ impl DetachedRowStream {
     /// If there are 0 results, [`Error::NoMoreRows`] is returned.
     /// If there are 2 or more results, [`Error::NotSingleResult`] is returned.
     pub async fn single_as<'this, T: DeserializeOwned + 'this>(&'this mut self) -> Result<T> {
-        self.stream.single_as(&mut self.connection).await
+        let detached = self.detached().await?;
+        detached.stream.single_as(&mut detached.connection).await
     }
 
     /// Return the first [`crate::Row`] buffered result without consuming it.
@@ -395,7 +582,7 @@ impl DetachedRowStream {
     /// As such, returning `None` does not mean that there are no more results,
     /// it just means that the buffer is empty.
     pub fn peek(&self) -> Option<&Row> {
-        self.stream.peek()
+        self.detached.as_ref()?.stream.peek()
     }
 
     /// Return the first [`crate::Row`] buffered result without consuming it,
@@ -407,7 +594,10 @@ impl DetachedRowStream {
     /// As such, returning [`Error::NoMoreRows`] does not mean that there are no more results,
     /// it just means that the buffer is empty.
     pub fn peek_as<'this, T: DeserializeOwned + 'this>(&'this self) -> Result<T> {
-        self.stream.peek_as()
+        self.detached
+            .as_ref()
+            .ok_or(Error::NoMoreRows)
+            .and_then(|detached| detached.stream.peek_as())
     }
 
     /// Return the first [`crate::Row`] buffered result and consume it.
@@ -418,7 +608,7 @@ impl DetachedRowStream {
     /// As such, returning `None` does not mean that there are no more results,
     /// it just means that the buffer is empty.
     pub fn pop(&mut self) -> Option<Row> {
-        self.stream.pop()
+        self.detached.as_mut()?.stream.pop()
     }
 
     /// Return the first [`crate::Row`] buffered result and consume it,
@@ -430,13 +620,21 @@ impl DetachedRowStream {
     /// As such, returning [`Error::NoMoreRows`] does not mean that there are no more results,
     /// it just means that the buffer is empty.
     pub fn pop_as<'this, T: DeserializeOwned + 'this>(&'this mut self) -> Result<T> {
-        self.stream.pop_as()
+        self.detached
+            .as_mut()
+            .ok_or(Error::NoMoreRows)
+            .and_then(|detached| detached.stream.pop_as())
     }
 
     /// Stop consuming the stream and return a summary, if available.
     /// Stopping the stream will also discard any messages on the server side.
     pub async fn finish(mut self) -> Result<RunResult> {
-        self.stream.finish(&mut self.connection).await
+        self.detached().await?;
+        let Detached {
+            stream,
+            mut connection,
+        } = self.detached.take().expect(AVAILABLE);
+        stream.finish(&mut connection).await
     }
 
     /// Turns this RowStream into a [`futures::stream::TryStream`] where
@@ -444,9 +642,12 @@ impl DetachedRowStream {
     ///
     /// The stream can only be converted once.
     /// After the returned stream is consumed, this stream can be [`Self::finish`]ed to get the summary.
+    ///
+    /// Since `DetachedRowStream` is itself a [`futures::Stream`],
+    /// the stream can also be consumed directly, without borrowing it.
     #[allow(clippy::wrong_self_convention)]
     pub fn into_stream(&mut self) -> impl TryStream<Ok = Row, Error = Error> + '_ {
-        self.stream.into_stream(&mut self.connection)
+        self
     }
 
     /// Turns this RowStream into a [`futures::stream::TryStream`] where
@@ -458,7 +659,7 @@ impl DetachedRowStream {
     pub fn into_stream_as<'this, T: DeserializeOwned + 'this>(
         &'this mut self,
     ) -> impl TryStream<Ok = T, Error = Error> + 'this {
-        self.stream.into_stream_as(&mut self.connection)
+        self.map(|row| row.and_then(|row| row.to::<T>().map_err(Error::DeserializationError)))
     }
 
     /// Turns this RowStream into a [`futures::stream::TryStream`] where
@@ -471,12 +672,593 @@ impl DetachedRowStream {
         &'this mut self,
         column: &'db str,
     ) -> impl TryStream<Ok = T, Error = Error> + 'this {
-        self.stream.column_into_stream(&mut self.connection, column)
+        self.map(move |row| {
+            row.and_then(|row| row.get::<T>(column).map_err(Error::DeserializationError))
+        })
+    }
+
+    /// Enables prefetching of the next batch of rows, see [`RowStream::with_prefetch`].
+    ///
+    /// Since the stream owns its connection, prefetching is always safe.
+    pub fn with_prefetch(mut self, low_water_mark: usize) -> Self {
+        if let Some(detached) = self.detached.as_mut() {
+            detached.stream.prefetch = Some(low_water_mark);
+        }
+        self
+    }
+
+    /// Adapts the number of rows requested per PULL, see [`RowStream::with_adaptive_fetch_size`].
+    pub fn with_adaptive_fetch_size(mut self, adaptive: AdaptiveFetchSize) -> Self {
+        if let Some(detached) = self.detached.as_mut() {
+            let stream = &mut detached.stream;
+            stream.adaptive = Some(AdaptiveState::new(adaptive, stream.fetch_size));
+        }
+        self
+    }
+
+    /// Turns this stream into a [`TypedDetachedRowStream`], a [`futures::Stream`] where
+    /// every row is converted into a `T` by calling [`crate::row::Row::to`].
+    ///
+    /// Unlike [`Self::into_stream_as`], the returned stream owns the connection
+    /// and can be returned from a function.
+    pub fn into_typed<T: DeserializeOwned>(self) -> TypedDetachedRowStream<T> {
+        TypedDetachedRowStream {
+            stream: self,
+            _row: PhantomData,
+        }
+    }
+
+    /// Waits for a batch of rows that was requested while polling this as a [`futures::Stream`].
+    async fn detached(&mut self) -> Result<&mut Detached> {
+        futures::future::poll_fn(|cx| self.poll_fetch(cx)).await?;
+        Ok(self.detached.as_mut().expect(AVAILABLE))
+    }
+
+    fn poll_fetch(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let Some(fetch) = self.fetch.as_mut() else {
+            return Poll::Ready(Ok(()));
+        };
+        let (detached, result) = ready!(fetch.poll_unpin(cx));
+        self.fetch = None;
+        self.detached = Some(detached);
+        Poll::Ready(result)
+    }
+}
+
+impl Stream for DetachedRowStream {
+    type Item = Result<Row>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.failed {
+            return Poll::Ready(None);
+        }
+        loop {
+            if let Err(e) = ready!(this.poll_fetch(cx)) {
+                this.failed = true;
+                return Poll::Ready(Some(Err(e)));
+            }
+
+            let mut detached = this.detached.take().expect(AVAILABLE);
+            if let Some(row) = detached.stream.pop() {
+                if detached.stream.should_prefetch(0) {
+                    this.fetch = Some(
+                        async move {
+                            let result =
+                                detached.stream.prefetch(&mut detached.connection, 0).await;
+                            (detached, result)
+                        }
+                        .boxed(),
+                    );
+                } else {
+                    this.detached = Some(detached);
+                }
+                return Poll::Ready(Some(Ok(row)));
+            }
+            if detached.stream.is_complete() {
+                this.detached = Some(detached);
+                return Poll::Ready(None);
+            }
+
+            this.fetch = Some(
+                async move {
+                    let result = detached.stream.fetch(&mut detached.connection).await;
+                    (detached, result)
+                }
+                .boxed(),
+            );
+        }
+    }
+}
+
+impl FusedStream for DetachedRowStream {
+    fn is_terminated(&self) -> bool {
+        self.failed
+            || self
+                .detached
+                .as_ref()
+                .is_some_and(|detached| detached.stream.is_exhausted())
+    }
+}
+
+impl Drop for DetachedRowStream {
+    fn drop(&mut self) {
+        // Dropping the connection in the middle of a PULL, or after a prefetched PULL was sent,
+        // would release it to the pool with unread messages, which fails the health check
+        // and discards the connection.
+        // Instead, the batch is read to completion and the connection is released afterwards.
+        // After a failed PULL, nothing is left to read.
+        let failed = self.failed;
+        let fetch = match (self.fetch.take(), self.detached.take()) {
+            (Some(fetch), _) => fetch,
+            (None, Some(mut detached)) if !failed && detached.stream.state == State::Pulling => {
+                async move {
+                    let result = detached.stream.recv_pull(&mut detached.connection).await;
+                    (detached, result)
+                }
+                .boxed()
+            }
+            _ => return,
+        };
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            drop(runtime.spawn(fetch));
+        }
+    }
+}
+
+const _: () = {
+    const fn assert_send_static<T: Send + 'static>() {}
+    assert_send_static::<DetachedRowStream>();
+    assert_send_static::<TypedDetachedRowStream<Row>>();
+};
+
+/// A [`DetachedRowStream`] where every row is converted into a `T`
+/// by calling [`crate::row::Row::to`], this is returned by [`DetachedRowStream::into_typed`].
+///
+/// The stream owns its connection and implements [`futures::Stream`].
+#[must_use = "Results must be streamed through in order to execute the query"]
+pub struct TypedDetachedRowStream<T> {
+    stream: DetachedRowStream,
+    _row: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedDetachedRowStream<T> {
+    /// Stop consuming the stream and return a summary, if available.
+    /// Stopping the stream will also discard any messages on the server side.
+    pub async fn finish(self) -> Result<RunResult> {
+        self.stream.finish().await
+    }
+
+    /// Return the underlying [`DetachedRowStream`].
+    pub fn into_inner(self) -> DetachedRowStream {
+        self.stream
+    }
+}
+
+impl<T: DeserializeOwned> Stream for TypedDetachedRowStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let row = ready!(self.get_mut().stream.poll_next_unpin(cx));
+        Poll::Ready(
+            row.map(|row| row.and_then(|row| row.to::<T>().map_err(Error::DeserializationError))),
+        )
+    }
+}
+
+impl<T: DeserializeOwned> FusedStream for TypedDetachedRowStream<T> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+/// Reads the response of a PULL into `buffer` and returns the state of the stream afterwards.
+async fn recv_batch(
+    connection: &mut ManagedConnection,
+    fields: &BoltList,
+    available_after: i64,
+    buffer: &mut VecDeque<Row>,
+) -> Result<State> {
+    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+    loop {
+        let response = connection
+            .recv_as::<Response<Vec<Bolt>, Streaming>>()
+            .await?;
+        match response {
+            Response::Detail(record) => {
+                let record = BoltList::from(
+                    record
+                        .into_iter()
+                        .map(BoltType::from)
+                        .collect::<Vec<BoltType>>(),
+                );
+                let row = Row::new(fields.clone(), record);
+                buffer.push_back(row);
+            }
+            Response::Success(Streaming::HasMore) => return Ok(State::Ready),
+            Response::Success(Streaming::Done(mut s)) => {
+                s.set_t_first(available_after);
+                return Ok(State::Complete(s));
+            }
+            otherwise => return Err(otherwise.into_error("PULL")),
+        }
+    }
+
+    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+    loop {
+        match connection.recv().await {
+            Ok(BoltResponse::Success(s)) => {
+                return Ok(match Streaming::from_success(&s) {
+                    Streaming::HasMore => State::Ready,
+                    Streaming::Done(mut s) => {
+                        s.set_t_first(available_after);
+                        State::Complete(s)
+                    }
+                });
+            }
+            Ok(BoltResponse::Record(record)) => {
+                let row = Row::new(fields.clone(), record.data);
+                buffer.push_back(row);
+            }
+            Ok(msg) => return Err(msg.into_error("PULL")),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// The bookkeeping of the streams of a transaction, which share the connection of the transaction.
+///
+/// At most one stream can wait for the response of a PULL at any time.
+/// Before another request is sent on the connection, that response is received and kept here,
+/// until the stream it belongs to is consumed again.
+#[derive(Debug)]
+pub struct OpenStreams {
+    id: u64,
+    in_flight: Option<InFlight>,
+    drained: HashMap<i64, Drained>,
+}
+
+#[derive(Debug)]
+struct InFlight {
+    qid: i64,
+    fields: BoltList,
+    available_after: i64,
+}
+
+#[derive(Debug)]
+struct Drained {
+    rows: VecDeque<Row>,
+    state: Result<State>,
+}
+
+impl OpenStreams {
+    pub(crate) fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        OpenStreams {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            in_flight: None,
+            drained: HashMap::new(),
+        }
+    }
+
+    /// Receives the response of a PULL that has been sent by a stream but not been received yet,
+    /// so that the connection can be used for other requests.
+    pub(crate) async fn drain(&mut self, connection: &mut ManagedConnection) -> Result<()> {
+        let Some(in_flight) = self.in_flight.take() else {
+            return Ok(());
+        };
+
+        let mut rows = VecDeque::new();
+        let state = recv_batch(
+            connection,
+            &in_flight.fields,
+            in_flight.available_after,
+            &mut rows,
+        )
+        .await;
+        // IO errors leave the connection unusable and are returned right away,
+        // errors from the server are returned by the stream that the PULL belongs to.
+        let state = match state {
+            Err(e @ (Error::IOError { .. } | Error::ConnectionTimedOut)) => return Err(e),
+            state => state,
+        };
+        self.drained.insert(in_flight.qid, Drained { rows, state });
+        Ok(())
     }
 }
 
 #[derive(Clone, PartialEq, Debug)]
 enum State {
     Ready,
-    Complete(BoxedSummary),
+    /// A PULL has been sent and its response has not been read yet.
+    Pulling,
+    /// A DISCARD has been sent and its response has not been read yet.
+    Discarding,
+    Complete(Box<ResultSummary>),
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{hello, Request, Response, Script, StubServer},
+        BoltType, ConfigBuilder, Graph,
+    };
+    use std::time::Duration;
+
+    fn graph(server: &StubServer, fetch_size: usize) -> Graph {
+        let config = ConfigBuilder::default()
+            .uri(server.uri())
+            .user("neo4j")
+            .password("neo")
+            .fetch_size(fetch_size)
+            .max_connections(1)
+            .build()
+            .unwrap();
+        Graph::connect(config).unwrap()
+    }
+
+    /// Replies to a PULL with the given rows of the column `x`.
+    fn batch(script: Script, rows: impl IntoIterator<Item = i64>, has_more: bool) -> Script {
+        let script = rows
+            .into_iter()
+            .fold(script, |script, x| script.reply(Response::record([x])));
+        if has_more {
+            script.reply(Response::success([("has_more", true)]))
+        } else {
+            script.reply(Response::success([("type", "r")]))
+        }
+    }
+
+    fn fields() -> Response {
+        Response::success([("fields", BoltType::from(vec!["x"]))])
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_has_more_after_a_discard() {
+        let script = hello()
+            .expect(Request::run("RETURN 1"))
+            .reply(Response::success([("fields", BoltType::from(vec!["1"]))]))
+            .expect(Request::discard())
+            .reply(Response::success([("has_more", true)]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let error = graph.run("RETURN 1").await.unwrap_err();
+        assert!(matches!(error, Error::UnexpectedMessage(_)), "{error}");
+
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_end_detached_stream_after_an_error() {
+        use futures::{stream::FusedStream, StreamExt};
+
+        let script = hello()
+            .expect(Request::run("UNWIND range(1, 10) AS x RETURN 1 / (5 - x)"))
+            .expect(Request::pull())
+            .reply(Response::success([(
+                "fields",
+                BoltType::from(vec!["x".to_owned()]),
+            )]))
+            .reply(Response::record([1]))
+            .reply(Response::success([("has_more", true)]))
+            .expect(Request::pull())
+            .reply(Response::failure(
+                "Neo.ClientError.Statement.ArithmeticError",
+                "/ by zero",
+            ));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let mut stream = graph
+            .execute("UNWIND range(1, 10) AS x RETURN 1 / (5 - x)")
+            .await
+            .unwrap();
+        assert!(matches!(StreamExt::next(&mut stream).await, Some(Ok(_))));
+        assert!(matches!(
+            StreamExt::next(&mut stream).await,
+            Some(Err(Error::Neo4j(_)))
+        ));
+        assert!(FusedStream::is_terminated(&stream));
+        assert!(StreamExt::next(&mut stream).await.is_none());
+
+        drop(stream);
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_send_prefetched_pull_before_the_buffer_is_empty() {
+        let script = hello()
+            .expect(Request::run("UNWIND range(1, 5) AS x RETURN x"))
+            .expect(Request::pull())
+            .reply(fields());
+        let script = batch(script, 1..=3, true).expect(Request::pull());
+        let script = batch(script, 4..=5, false);
+        let server = StubServer::start(script).await.unwrap();
+        let graph = graph(&server, 3);
+
+        let mut stream = graph
+            .execute("UNWIND range(1, 5) AS x RETURN x")
+            .await
+            .unwrap()
+            .with_prefetch(3);
+        assert_eq!(stream.next_as::<i64>().await.unwrap(), 1);
+
+        // the server has received the PULL for the next batch while two rows are still buffered
+        tokio::time::timeout(Duration::from_secs(5), server.finish())
+            .await
+            .expect("the next batch has not been requested")
+            .unwrap();
+
+        let mut rows = Vec::new();
+        while let Some(row) = stream.next().await.unwrap() {
+            rows.push(row.get::<i64>("x").unwrap());
+        }
+        assert_eq!(rows, [2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn should_only_pull_when_the_buffer_is_empty_without_prefetch() {
+        let script = hello()
+            .expect(Request::run("UNWIND range(1, 5) AS x RETURN x"))
+            .expect(Request::pull())
+            .reply(fields());
+        let script = batch(script, 1..=3, true).expect(Request::pull());
+        let script = batch(script, 4..=5, false);
+        let server = StubServer::start(script).await.unwrap();
+        let graph = graph(&server, 3);
+
+        let mut stream = graph
+            .execute("UNWIND range(1, 5) AS x RETURN x")
+            .await
+            .unwrap();
+        assert_eq!(stream.next_as::<i64>().await.unwrap(), 1);
+        let server = tokio::time::timeout(Duration::from_millis(100), server.finish()).await;
+        assert!(server.is_err(), "the next batch was requested too early");
+    }
+
+    #[tokio::test]
+    async fn should_read_prefetched_batch_before_finishing() {
+        let script = hello()
+            .expect(Request::begin().with_extra([("db", "neo4j")]))
+            .expect(Request::run("UNWIND range(1, 10) AS x RETURN x"))
+            .expect(Request::pull())
+            .reply(Response::success(Vec::<(&str, BoltType)>::new()))
+            .reply(fields());
+        let script = batch(script, 1..=3, true).expect(Request::pull());
+        let script = batch(script, 4..=6, true)
+            .expect(Request::discard())
+            .reply(Response::success([("type", "r")]))
+            .expect(Request::commit())
+            .reply(Response::success([("bookmark", "bookmark-1")]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = graph(&server, 3);
+
+        let mut txn = graph.start_txn_on("neo4j").await.unwrap();
+        let mut stream = txn
+            .execute("UNWIND range(1, 10) AS x RETURN x")
+            .await
+            .unwrap()
+            .with_prefetch(3);
+        assert_eq!(stream.next_as::<i64>(txn.handle()).await.unwrap(), 1);
+        stream.finish(txn.handle()).await.unwrap();
+        txn.commit().await.unwrap();
+
+        server.finish().await.unwrap();
+    }
+
+    /// Begins a transaction on the database `neo4j`, with the RUN and PULL of its first query.
+    fn begin(query: &str) -> Script {
+        hello()
+            .expect(Request::begin().with_extra([("db", "neo4j")]))
+            .expect(Request::run(query))
+            .expect(Request::pull())
+            .reply(Response::success(Vec::<(&str, BoltType)>::new()))
+    }
+
+    fn started(qid: i64) -> Response {
+        Response::success([
+            ("fields", BoltType::from(vec!["x"])),
+            ("qid", BoltType::from(qid)),
+        ])
+    }
+
+    #[tokio::test]
+    async fn should_interleave_batches_of_streams() {
+        const ONE: &str = "UNWIND range(1, 5) AS x RETURN x";
+        const TWO: &str = "UNWIND range(11, 15) AS x RETURN x";
+        let script = begin(ONE).reply(started(0));
+        let script = batch(script, 1..=2, true).expect(Request::pull().with_extra([("qid", 0)]));
+        let script = batch(script, 3..=4, true)
+            .expect(Request::run(TWO))
+            .expect(Request::pull())
+            .reply(started(1));
+        let script = batch(script, 11..=12, true).expect(Request::pull().with_extra([("qid", 1)]));
+        let script = batch(script, 13..=14, true).expect(Request::pull().with_extra([("qid", 1)]));
+        let script = batch(script, 15..=15, false).expect(Request::pull().with_extra([("qid", 0)]));
+        let script = batch(script, 5..=5, false)
+            .expect(Request::commit())
+            .reply(Response::success([("bookmark", "bookmark-1")]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = graph(&server, 2);
+
+        let mut txn = graph.start_txn_on("neo4j").await.unwrap();
+        let mut one = txn.execute(ONE).await.unwrap().with_prefetch(2);
+        assert_eq!(one.next_as::<i64>(txn.handle()).await.unwrap(), 1);
+
+        // the prefetched batch of the first stream is received before the second query is sent
+        let mut two = txn.execute(TWO).await.unwrap().with_prefetch(2);
+        let mut rows = Vec::new();
+        while let Some(row) = two.next(txn.handle()).await.unwrap() {
+            rows.push(row.get::<i64>("x").unwrap());
+        }
+        assert_eq!(rows, [11, 12, 13, 14, 15]);
+
+        let mut rows = Vec::new();
+        while let Some(row) = one.next(txn.handle()).await.unwrap() {
+            rows.push(row.get::<i64>("x").unwrap());
+        }
+        assert_eq!(rows, [2, 3, 4, 5]);
+        txn.commit().await.unwrap();
+
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_drain_dropped_stream_before_commit() {
+        const QUERY: &str = "UNWIND range(1, 5) AS x RETURN x";
+        let script = begin(QUERY).reply(started(0));
+        let script = batch(script, 1..=2, true).expect(Request::pull().with_extra([("qid", 0)]));
+        let script = batch(script, 3..=4, true)
+            .expect(Request::commit())
+            .reply(Response::success([("bookmark", "bookmark-1")]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = graph(&server, 2);
+
+        let mut txn = graph.start_txn_on("neo4j").await.unwrap();
+        let mut stream = txn.execute(QUERY).await.unwrap().with_prefetch(2);
+        assert_eq!(stream.next_as::<i64>(txn.handle()).await.unwrap(), 1);
+        drop(stream);
+        txn.commit().await.unwrap();
+
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_return_error_of_interleaved_stream_to_its_stream() {
+        const ONE: &str = "UNWIND range(1, 5) AS x RETURN 1 / (3 - x)";
+        const TWO: &str = "RETURN 2";
+        let script = begin(ONE).reply(started(0));
+        let script = batch(script, 1..=2, true)
+            .expect(Request::pull().with_extra([("qid", 0)]))
+            .reply(Response::failure(
+                "Neo.ClientError.Statement.ArithmeticError",
+                "/ by zero",
+            ))
+            .expect(Request::run(TWO))
+            .expect(Request::pull())
+            .reply(Response::ignored())
+            .reply(Response::ignored());
+        let server = StubServer::start(script).await.unwrap();
+        let graph = graph(&server, 2);
+
+        let mut txn = graph.start_txn_on("neo4j").await.unwrap();
+        let mut one = txn.execute(ONE).await.unwrap().with_prefetch(2);
+        assert_eq!(one.next_as::<i64>(txn.handle()).await.unwrap(), 1);
+
+        // the failed PULL of the first stream is received before the second query is sent,
+        // which the server ignores because the transaction has failed
+        assert!(txn.execute(TWO).await.is_err());
+
+        // the buffered row is still returned, followed by the error of the PULL
+        assert_eq!(one.next_as::<i64>(txn.handle()).await.unwrap(), 2);
+        let Err(Error::Neo4j(error)) = one.next(txn.handle()).await else {
+            panic!("expected the error of the PULL");
+        };
+        assert_eq!(error.code(), "Neo.ClientError.Statement.ArithmeticError");
+        drop(txn);
+
+        server.finish().await.unwrap();
+    }
 }
@@ -1,22 +1,18 @@
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 use crate::bookmarks::Bookmark;
+use crate::BoltType;
 use serde::{
-    de::{self, Visitor},
+    de::{self, DeserializeOwned, Visitor},
     Deserialize,
 };
-use std::{fmt, time::Duration};
+use std::{collections::HashMap, fmt, time::Duration};
 
-#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-type MapKey = String;
-#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-type MapValue = crate::bolt::Bolt;
-#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
-type Map = std::collections::HashMap<MapKey, MapValue>;
+pub use crate::notification::{NotificationClassification, NotificationSeverity};
 
-#[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-type MapKey = crate::BoltString;
-#[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-type MapValue = crate::BoltType;
+mod plan;
+
+#[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+type Map = HashMap<String, crate::bolt::Bolt>;
 #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
 type Map = crate::BoltMap;
 
@@ -29,26 +25,6 @@ pub enum Type {
     Unknown,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub enum NotificationSeverity {
-    Information,
-    Warning,
-    Off,
-}
-
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub enum NotificationClassification {
-    Hint,
-    Unrecognized,
-    Unsupported,
-    Performance,
-    Deprecation,
-    Security,
-    Topology,
-    Generic,
-    Schema,
-}
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
 pub struct InputPosition {
     pub offset: i64,
@@ -67,6 +43,30 @@ pub struct Notification {
     pub position: Option<InputPosition>,
 }
 
+/// A status of the query in the GQL format, as returned by servers with Bolt 5.5 or later.
+///
+/// Besides the outcome of the query, the statuses also include all notifications.
+#[derive(Clone, Debug, PartialEq, Default, Deserialize)]
+#[serde(from = "GqlStatusObjectWire")]
+pub struct GqlStatusObject {
+    /// The GQLSTATUS code, e.g. `01N01` for a deprecation warning.
+    pub gql_status: String,
+    /// The description of the status.
+    pub status_description: String,
+    /// The Neo4j status code, if the status is also a notification.
+    pub neo4j_code: Option<String>,
+    /// The title of the notification, if the status is also a notification.
+    pub title: Option<String>,
+    /// Further information about the status, such as the operation that caused it.
+    pub diagnostic_record: HashMap<String, BoltType>,
+    /// The severity from the diagnostic record.
+    pub severity: Option<NotificationSeverity>,
+    /// The classification from the diagnostic record.
+    pub classification: Option<NotificationClassification>,
+    /// The position in the query from the diagnostic record.
+    pub position: Option<InputPosition>,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize)]
 #[serde(rename_all = "kebab-case", default)]
 pub struct Counters {
@@ -107,6 +107,59 @@ impl std::ops::AddAssign<&Counters> for Counters {
     }
 }
 
+/// The execution plan of a query, as returned for queries prefixed with `EXPLAIN`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plan {
+    /// The name of the operator, e.g. `NodeIndexSeek`.
+    pub operator_type: String,
+    /// The arguments of the operator, such as the estimated rows or the planner that was used.
+    #[serde(rename = "args", default)]
+    pub arguments: HashMap<String, BoltType>,
+    /// The variables that are introduced by this operator.
+    #[serde(default)]
+    pub identifiers: Vec<String>,
+    /// The operators whose output is the input of this operator.
+    #[serde(default)]
+    pub children: Vec<Plan>,
+}
+
+/// The execution plan of a query together with the statistics of its execution,
+/// as returned for queries prefixed with `PROFILE`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfiledPlan {
+    /// The name of the operator, e.g. `NodeIndexSeek`.
+    pub operator_type: String,
+    /// The arguments of the operator, such as the estimated rows or the planner that was used.
+    #[serde(rename = "args", default)]
+    pub arguments: HashMap<String, BoltType>,
+    /// The variables that are introduced by this operator.
+    #[serde(default)]
+    pub identifiers: Vec<String>,
+    /// The number of times the operator accessed the storage.
+    #[serde(default)]
+    pub db_hits: i64,
+    /// The number of rows produced by the operator.
+    #[serde(default)]
+    pub rows: i64,
+    /// The number of page cache hits of the operator, if the server reports them.
+    #[serde(default)]
+    pub page_cache_hits: i64,
+    /// The number of page cache misses of the operator, if the server reports them.
+    #[serde(default)]
+    pub page_cache_misses: i64,
+    /// The ratio of page cache hits to all page cache accesses, if the server reports it.
+    #[serde(default)]
+    pub page_cache_hit_ratio: f64,
+    /// The time spent in the operator in nanoseconds, 0 if it was not measured.
+    #[serde(default)]
+    pub time: i64,
+    /// The operators whose output is the input of this operator.
+    #[serde(default)]
+    pub children: Vec<ProfiledPlan>,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(from = "SummaryBuilder")]
 pub enum Streaming {
@@ -114,7 +167,7 @@ pub enum Streaming {
     Done(Box<ResultSummary>),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct ResultSummary {
     pub bookmark: Option<String>,
     t_first: Option<u64>,
@@ -122,9 +175,10 @@ pub struct ResultSummary {
     pub r#type: Option<Type>,
     pub db: Option<String>,
     pub stats: Counters,
-    pub(crate) plan: Option<Map>,
-    pub(crate) profile: Option<Map>,
+    pub(crate) plan: Option<Plan>,
+    pub(crate) profile: Option<ProfiledPlan>,
     pub notifications: Vec<Notification>,
+    pub(crate) gql_status_objects: Vec<GqlStatusObject>,
 }
 
 impl ResultSummary {
@@ -152,6 +206,27 @@ impl ResultSummary {
         &self.notifications
     }
 
+    /// The statuses of the query, empty if the server does not support Bolt 5.5 or later.
+    ///
+    /// Since Bolt 5.5, the [`notifications`](Self::notifications) are taken from these statuses.
+    pub fn gql_status_objects(&self) -> &[GqlStatusObject] {
+        &self.gql_status_objects
+    }
+
+    /// The execution plan of the query, if it was prefixed with `EXPLAIN`.
+    ///
+    /// A plan that cannot be read is logged as a warning and not returned.
+    pub fn plan(&self) -> Option<&Plan> {
+        self.plan.as_ref()
+    }
+
+    /// The execution plan of the query with its statistics, if it was prefixed with `PROFILE`.
+    ///
+    /// A profile that cannot be read is logged as a warning and not returned.
+    pub fn profile(&self) -> Option<&ProfiledPlan> {
+        self.profile.as_ref()
+    }
+
     pub fn nodes_created(&self) -> u64 {
         self.stats.nodes_created
     }
@@ -225,6 +300,49 @@ struct NotificationWire {
     position: Option<InputPosition>,
 }
 
+#[derive(Deserialize)]
+struct GqlStatusObjectWire {
+    #[serde(default)]
+    gql_status: String,
+    #[serde(default)]
+    status_description: String,
+    neo4j_code: Option<String>,
+    title: Option<String>,
+    #[serde(default)]
+    diagnostic_record: HashMap<String, BoltType>,
+}
+
+impl From<GqlStatusObjectWire> for GqlStatusObject {
+    fn from(value: GqlStatusObjectWire) -> Self {
+        let diagnostic = |key: &str| value.diagnostic_record.get(key);
+        GqlStatusObject {
+            severity: diagnostic("_severity").and_then(|s| s.to().ok()),
+            classification: diagnostic("_classification").and_then(|c| c.to().ok()),
+            position: diagnostic("_position").and_then(|p| p.to().ok()),
+            gql_status: value.gql_status,
+            status_description: value.status_description,
+            neo4j_code: value.neo4j_code,
+            title: value.title,
+            diagnostic_record: value.diagnostic_record,
+        }
+    }
+}
+
+impl GqlStatusObject {
+    /// The status as a notification, if it is one.
+    fn notification(&self) -> Option<Notification> {
+        let code = self.neo4j_code.clone()?;
+        Some(Notification {
+            code: Some(code),
+            title: self.title.clone(),
+            description: Some(self.status_description.clone()),
+            severity: self.severity,
+            category: self.classification,
+            position: self.position,
+        })
+    }
+}
+
 impl From<NotificationWire> for Notification {
     fn from(value: NotificationWire) -> Self {
         Notification {
@@ -249,6 +367,7 @@ struct SummaryBuilder {
     plan: Option<Map>,
     profile: Option<Map>,
     notifications: Option<Vec<Notification>>,
+    statuses: Option<Vec<GqlStatusObject>>,
 }
 
 impl From<SummaryBuilder> for Streaming {
@@ -263,6 +382,7 @@ impl From<SummaryBuilder> for Streaming {
             plan,
             profile,
             notifications,
+            statuses,
         } = value;
 
         if has_more.unwrap_or(false) {
@@ -275,14 +395,68 @@ impl From<SummaryBuilder> for Streaming {
                 r#type,
                 db,
                 stats: stats.unwrap_or_default(),
-                plan,
-                profile,
-                notifications: notifications.unwrap_or_default(),
+                plan: plan.and_then(typed_plan),
+                profile: profile.and_then(typed_plan),
+                // since Bolt 5.5, the notifications are only sent as part of the statuses
+                notifications: notifications.unwrap_or_else(|| {
+                    statuses
+                        .iter()
+                        .flatten()
+                        .filter_map(GqlStatusObject::notification)
+                        .collect()
+                }),
+                gql_status_objects: statuses.unwrap_or_default(),
             }))
         }
     }
 }
 
+#[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+impl Streaming {
+    /// Reads the summary of a SUCCESS. If the summary cannot be read as a whole,
+    /// the entries that can be read are kept, so that the query does not fail because of its summary.
+    pub(crate) fn from_success(success: &crate::Success) -> Self {
+        match success.to::<Streaming>() {
+            Ok(streaming) => streaming,
+            Err(e) => {
+                log::warn!(
+                    "Could not read the complete result summary, some entries are missing: {e}"
+                );
+                Streaming::from(SummaryBuilder {
+                    has_more: success.get("has_more").ok(),
+                    bookmark: success.get("bookmark").ok(),
+                    t_last: success.get("t_last").ok(),
+                    r#type: success.get("type").ok(),
+                    db: success.get("db").ok(),
+                    stats: success.get("stats").ok(),
+                    plan: success.get("plan").ok(),
+                    profile: success.get("profile").ok(),
+                    notifications: success.get("notifications").ok(),
+                    statuses: success.get("statuses").ok(),
+                })
+            }
+        }
+    }
+}
+
+/// Converts the plan from the summary into a [`Plan`] or a [`ProfiledPlan`].
+/// Plans that do not have the expected shape are skipped with a warning.
+fn typed_plan<T: DeserializeOwned>(plan: Map) -> Option<T> {
+    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+    let plan = plan
+        .into_iter()
+        .map(|(key, value)| (key.into(), BoltType::from(value)))
+        .collect::<crate::BoltMap>();
+
+    match BoltType::Map(plan).to::<T>() {
+        Ok(plan) => Some(plan),
+        Err(e) => {
+            log::warn!("Could not read the query plan of the result summary: {e}");
+            None
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Type {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -329,128 +503,6 @@ impl<'de> Deserialize<'de> for Type {
     }
 }
 
-impl<'de> Deserialize<'de> for NotificationSeverity {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        struct TheVisitor;
-
-        impl<'de> Visitor<'de> for TheVisitor {
-            type Value = NotificationSeverity;
-            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                formatter.write_str("a valid NotificationSeverity")
-            }
-
-            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-            where
-                E: ::serde::de::Error,
-            {
-                if v.eq_ignore_ascii_case("information") {
-                    Ok(NotificationSeverity::Information)
-                } else if v.eq_ignore_ascii_case("warning") {
-                    Ok(NotificationSeverity::Warning)
-                } else if v.eq_ignore_ascii_case("off") {
-                    Ok(NotificationSeverity::Off)
-                } else {
-                    Err(de::Error::unknown_variant(
-                        v,
-                        &["INFORMATION", "WARNING", "OFF"],
-                    ))
-                }
-            }
-
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                self.visit_str(&v)
-            }
-
-            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                self.visit_str(v)
-            }
-        }
-
-        deserializer.deserialize_any(TheVisitor)
-    }
-}
-
-impl<'de> Deserialize<'de> for NotificationClassification {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        struct TheVisitor;
-
-        impl<'de> Visitor<'de> for TheVisitor {
-            type Value = NotificationClassification;
-            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                formatter.write_str("a valid NotificationClassification")
-            }
-
-            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-            where
-                E: ::serde::de::Error,
-            {
-                if v.eq_ignore_ascii_case("hint") {
-                    Ok(NotificationClassification::Hint)
-                } else if v.eq_ignore_ascii_case("unrecognized") {
-                    Ok(NotificationClassification::Unrecognized)
-                } else if v.eq_ignore_ascii_case("unsupported") {
-                    Ok(NotificationClassification::Unsupported)
-                } else if v.eq_ignore_ascii_case("performance") {
-                    Ok(NotificationClassification::Performance)
-                } else if v.eq_ignore_ascii_case("deprecation") {
-                    Ok(NotificationClassification::Deprecation)
-                } else if v.eq_ignore_ascii_case("security") {
-                    Ok(NotificationClassification::Security)
-                } else if v.eq_ignore_ascii_case("topology") {
-                    Ok(NotificationClassification::Topology)
-                } else if v.eq_ignore_ascii_case("generic") {
-                    Ok(NotificationClassification::Generic)
-                } else if v.eq_ignore_ascii_case("schema") {
-                    Ok(NotificationClassification::Schema)
-                } else {
-                    Err(de::Error::unknown_variant(
-                        v,
-                        &[
-                            "HINT",
-                            "UNRECOGNIZED",
-                            "UNSUPPORTED",
-                            "PERFORMANCE",
-                            "DEPRECATION",
-                            "SECURITY",
-                            "TOPOLOGY",
-                            "GENERIC",
-                            "SCHEMA",
-                        ],
-                    ))
-                }
-            }
-
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                self.visit_str(&v)
-            }
-
-            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                self.visit_str(v)
-            }
-        }
-
-        deserializer.deserialize_any(TheVisitor)
-    }
-}
-
 impl<'de, T: Deserialize<'de>> Deserialize<'de> for Optional<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -555,6 +607,7 @@ impl<'de> Deserialize<'de> for SummaryBuilder {
                     plan,
                     profile,
                     notifications,
+                    statuses,
                 );
 
                 Ok(SummaryBuilder {
@@ -567,6 +620,7 @@ impl<'de> Deserialize<'de> for SummaryBuilder {
                     plan,
                     profile,
                     notifications,
+                    statuses,
                 })
             }
         }
@@ -583,13 +637,14 @@ impl<'de> Deserialize<'de> for SummaryBuilder {
                 "plan",
                 "profile",
                 "notifications",
+                "statuses",
             ],
             Visit,
         )
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "unstable-serde-packstream-format"))]
 mod tests {
     use super::*;
     use crate::packstream::{bolt, from_bytes};
@@ -644,6 +699,7 @@ mod tests {
             plan: None,
             profile: None,
             notifications: Vec::new(),
+            gql_status_objects: Vec::new(),
         };
 
         let actual = from_bytes::<Streaming>(data).unwrap();
@@ -655,6 +711,234 @@ mod tests {
         assert_eq!(*actual, expected);
     }
 
+    #[test]
+    fn parse_plan() {
+        let data = bolt()
+            .tiny_map(1)
+            .tiny_string("plan")
+            .tiny_map(4)
+            .tiny_string("operatorType")
+            .tiny_string("ProduceResults")
+            .tiny_string("args")
+            .tiny_map(1)
+            .tiny_string("planner")
+            .tiny_string("COST")
+            .tiny_string("identifiers")
+            .tiny_list(1)
+            .tiny_string("n")
+            .tiny_string("children")
+            .tiny_list(1)
+            .tiny_map(2)
+            .tiny_string("operatorType")
+            .tiny_string("AllNodesScan")
+            .tiny_string("identifiers")
+            .tiny_list(1)
+            .tiny_string("n")
+            .build();
+
+        let actual = match from_bytes::<Streaming>(data).unwrap() {
+            Streaming::Done(actual) => actual,
+            _ => panic!("Expected done"),
+        };
+
+        let expected = Plan {
+            operator_type: "ProduceResults".to_owned(),
+            arguments: HashMap::from([("planner".to_owned(), BoltType::from("COST"))]),
+            identifiers: vec!["n".to_owned()],
+            children: vec![Plan {
+                operator_type: "AllNodesScan".to_owned(),
+                arguments: HashMap::new(),
+                identifiers: vec!["n".to_owned()],
+                children: Vec::new(),
+            }],
+        };
+
+        assert_eq!(actual.plan(), Some(&expected));
+        assert_eq!(actual.profile(), None);
+    }
+
+    #[test]
+    fn parse_profile() {
+        let data = bolt()
+            .tiny_map(1)
+            .tiny_string("profile")
+            .tiny_map(9)
+            .tiny_string("operatorType")
+            .tiny_string("NodeIndexSeek")
+            .tiny_string("args")
+            .tiny_map(1)
+            .tiny_string("EstimatedRows")
+            .float(1.0)
+            .tiny_string("identifiers")
+            .tiny_list(1)
+            .tiny_string("n")
+            .tiny_string("dbHits")
+            .tiny_int(2)
+            .tiny_string("rows")
+            .tiny_int(1)
+            .tiny_string("pageCacheHits")
+            .tiny_int(3)
+            .tiny_string("pageCacheMisses")
+            .tiny_int(1)
+            .string8("pageCacheHitRatio")
+            .float(0.75)
+            .tiny_string("time")
+            .int16(1234)
+            .build();
+
+        let actual = match from_bytes::<Streaming>(data).unwrap() {
+            Streaming::Done(actual) => actual,
+            _ => panic!("Expected done"),
+        };
+
+        let expected = ProfiledPlan {
+            operator_type: "NodeIndexSeek".to_owned(),
+            arguments: HashMap::from([(
+                "EstimatedRows".to_owned(),
+                BoltType::Float(crate::BoltFloat::new(1.0)),
+            )]),
+            identifiers: vec!["n".to_owned()],
+            db_hits: 2,
+            rows: 1,
+            page_cache_hits: 3,
+            page_cache_misses: 1,
+            page_cache_hit_ratio: 0.75,
+            time: 1234,
+            children: Vec::new(),
+        };
+
+        assert_eq!(actual.profile(), Some(&expected));
+        assert_eq!(actual.plan(), None);
+    }
+
+    #[test]
+    fn parse_gql_status_objects() {
+        let data = bolt()
+            .tiny_map(1)
+            .tiny_string("statuses")
+            .tiny_list(2)
+            .tiny_map(5)
+            .tiny_string("gql_status")
+            .tiny_string("01N00")
+            .string8("status_description")
+            .string8("warn: feature deprecated.")
+            .tiny_string("neo4j_code")
+            .string8("Neo.ClientNotification.Statement.FeatureDeprecationWarning")
+            .tiny_string("title")
+            .string8("This feature is deprecated.")
+            .string8("diagnostic_record")
+            .tiny_map(4)
+            .tiny_string("OPERATION")
+            .tiny_string("")
+            .tiny_string("_severity")
+            .tiny_string("WARNING")
+            .string8("_classification")
+            .tiny_string("DEPRECATION")
+            .tiny_string("_position")
+            .tiny_map(3)
+            .tiny_string("offset")
+            .tiny_int(0)
+            .tiny_string("line")
+            .tiny_int(1)
+            .tiny_string("column")
+            .tiny_int(1)
+            .tiny_map(2)
+            .tiny_string("gql_status")
+            .tiny_string("00000")
+            .string8("status_description")
+            .string8("note: successful completion")
+            .build();
+
+        let actual = match from_bytes::<Streaming>(data).unwrap() {
+            Streaming::Done(actual) => actual,
+            _ => panic!("Expected done"),
+        };
+
+        let statuses = actual.gql_status_objects();
+        assert_eq!(statuses.len(), 2);
+
+        let deprecation = &statuses[0];
+        assert_eq!(deprecation.gql_status, "01N00");
+        assert_eq!(deprecation.status_description, "warn: feature deprecated.");
+        assert_eq!(
+            deprecation.neo4j_code.as_deref(),
+            Some("Neo.ClientNotification.Statement.FeatureDeprecationWarning")
+        );
+        assert_eq!(
+            deprecation.title.as_deref(),
+            Some("This feature is deprecated.")
+        );
+        assert_eq!(deprecation.severity, Some(NotificationSeverity::Warning));
+        assert_eq!(
+            deprecation.classification,
+            Some(NotificationClassification::Deprecation)
+        );
+        assert_eq!(
+            deprecation.position,
+            Some(InputPosition {
+                offset: 0,
+                line: 1,
+                column: 1,
+            })
+        );
+        assert_eq!(
+            deprecation.diagnostic_record.get("OPERATION"),
+            Some(&BoltType::from(""))
+        );
+
+        let success = &statuses[1];
+        assert_eq!(success.gql_status, "00000");
+        assert_eq!(success.neo4j_code, None);
+        assert_eq!(success.severity, None);
+        assert!(success.diagnostic_record.is_empty());
+    }
+
+    #[test]
+    fn derive_notifications_from_gql_status_objects() {
+        let data = bolt()
+            .tiny_map(1)
+            .tiny_string("statuses")
+            .tiny_list(2)
+            .tiny_map(5)
+            .tiny_string("gql_status")
+            .tiny_string("03N31")
+            .string8("status_description")
+            .string8("info: cartesian product.")
+            .tiny_string("neo4j_code")
+            .string8("Neo.ClientNotification.Statement.CartesianProduct")
+            .tiny_string("title")
+            .string8("This query builds a cartesian product.")
+            .string8("diagnostic_record")
+            .tiny_map(2)
+            .tiny_string("_severity")
+            .string8("INFORMATION")
+            .string8("_classification")
+            .tiny_string("PERFORMANCE")
+            .tiny_map(2)
+            .tiny_string("gql_status")
+            .tiny_string("00000")
+            .string8("status_description")
+            .string8("note: successful completion")
+            .build();
+
+        let actual = match from_bytes::<Streaming>(data).unwrap() {
+            Streaming::Done(actual) => actual,
+            _ => panic!("Expected done"),
+        };
+
+        assert_eq!(
+            actual.notifications(),
+            [Notification {
+                code: Some("Neo.ClientNotification.Statement.CartesianProduct".into()),
+                title: Some("This query builds a cartesian product.".into()),
+                description: Some("info: cartesian product.".into()),
+                severity: Some(NotificationSeverity::Information),
+                category: Some(NotificationClassification::Performance),
+                position: None,
+            }]
+        );
+    }
+
     #[test]
     fn parse_severity() {
         let data = bolt().tiny_string("WARNING").build();
@@ -0,0 +1,560 @@
+use super::{Plan, ProfiledPlan};
+use crate::BoltType;
+use std::{collections::HashMap, fmt};
+
+/// The parts of an execution plan that are shared by [`Plan`] and [`ProfiledPlan`].
+trait PlanNode: Sized {
+    fn operator_type(&self) -> &str;
+
+    fn arguments(&self) -> &HashMap<String, BoltType>;
+
+    fn identifiers(&self) -> &[String];
+
+    fn children(&self) -> &[Self];
+
+    /// The statistics of the operator, in the order of [`PROFILE_HEADERS`].
+    fn statistics(&self) -> Option<[String; 4]> {
+        None
+    }
+
+    fn operator_name(&self) -> &str {
+        // Neo4j suffixes the operator with the database it runs on, e.g. `AllNodesScan@neo4j`
+        let operator = self.operator_type();
+        operator.split_once('@').map_or(operator, |(name, _)| name)
+    }
+
+    fn details(&self) -> Option<&str> {
+        match self.arguments().get("Details")? {
+            BoltType::String(details) => Some(&details.value),
+            _ => None,
+        }
+    }
+
+    fn estimated_rows(&self) -> Option<f64> {
+        match self.arguments().get("EstimatedRows")? {
+            BoltType::Float(rows) => Some(rows.value),
+            BoltType::Integer(rows) => Some(rows.value as f64),
+            _ => None,
+        }
+    }
+
+    fn operators(&self) -> impl Iterator<Item = &Self> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let plan = stack.pop()?;
+            stack.extend(plan.children().iter().rev());
+            Some(plan)
+        })
+    }
+
+    fn contains_operator(&self, name: &str) -> bool {
+        self.operators().any(|plan| {
+            plan.operator_name().eq_ignore_ascii_case(name)
+                || plan.operator_type().eq_ignore_ascii_case(name)
+        })
+    }
+}
+
+impl PlanNode for Plan {
+    fn operator_type(&self) -> &str {
+        &self.operator_type
+    }
+
+    fn arguments(&self) -> &HashMap<String, BoltType> {
+        &self.arguments
+    }
+
+    fn identifiers(&self) -> &[String] {
+        &self.identifiers
+    }
+
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+}
+
+impl PlanNode for ProfiledPlan {
+    fn operator_type(&self) -> &str {
+        &self.operator_type
+    }
+
+    fn arguments(&self) -> &HashMap<String, BoltType> {
+        &self.arguments
+    }
+
+    fn identifiers(&self) -> &[String] {
+        &self.identifiers
+    }
+
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    fn statistics(&self) -> Option<[String; 4]> {
+        let page_cache = if self.page_cache_hits == 0 && self.page_cache_misses == 0 {
+            String::new()
+        } else {
+            format!("{}/{}", self.page_cache_hits, self.page_cache_misses)
+        };
+        let time = if self.time == 0 {
+            String::new()
+        } else {
+            format!("{:.3}", self.time as f64 / 1_000_000.0)
+        };
+        Some([
+            self.rows.to_string(),
+            self.db_hits.to_string(),
+            page_cache,
+            time,
+        ])
+    }
+}
+
+impl Plan {
+    /// The name of the operator without the database it runs on, e.g. `NodeIndexSeek`.
+    pub fn operator_name(&self) -> &str {
+        PlanNode::operator_name(self)
+    }
+
+    /// The description of the operator, such as the index or the predicate it uses.
+    pub fn details(&self) -> Option<&str> {
+        PlanNode::details(self)
+    }
+
+    /// The number of rows the planner estimated for the operator.
+    pub fn estimated_rows(&self) -> Option<f64> {
+        PlanNode::estimated_rows(self)
+    }
+
+    /// Iterates over this operator and all operators below it, depth first.
+    pub fn operators(&self) -> impl Iterator<Item = &Plan> {
+        PlanNode::operators(self)
+    }
+
+    /// Whether this operator or any operator below it has the given name,
+    /// e.g. `plan.contains_operator("NodeIndexSeek")`.
+    ///
+    /// The name is compared case-insensitively, with or without the database suffix.
+    pub fn contains_operator(&self, name: &str) -> bool {
+        PlanNode::contains_operator(self, name)
+    }
+
+    /// Exports the plan as JSON, using the same field names as the server.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut json = json_object(self);
+        json.insert(
+            "children".to_owned(),
+            self.children.iter().map(Plan::to_json).collect(),
+        );
+        json.into()
+    }
+}
+
+impl ProfiledPlan {
+    /// The name of the operator without the database it runs on, e.g. `NodeIndexSeek`.
+    pub fn operator_name(&self) -> &str {
+        PlanNode::operator_name(self)
+    }
+
+    /// The description of the operator, such as the index or the predicate it uses.
+    pub fn details(&self) -> Option<&str> {
+        PlanNode::details(self)
+    }
+
+    /// The number of rows the planner estimated for the operator.
+    pub fn estimated_rows(&self) -> Option<f64> {
+        PlanNode::estimated_rows(self)
+    }
+
+    /// Iterates over this operator and all operators below it, depth first.
+    pub fn operators(&self) -> impl Iterator<Item = &ProfiledPlan> {
+        PlanNode::operators(self)
+    }
+
+    /// Whether this operator or any operator below it has the given name,
+    /// e.g. `profile.contains_operator("NodeIndexSeek")`.
+    ///
+    /// The name is compared case-insensitively, with or without the database suffix.
+    pub fn contains_operator(&self, name: &str) -> bool {
+        PlanNode::contains_operator(self, name)
+    }
+
+    /// The number of storage accesses of this operator and all operators below it.
+    pub fn total_db_hits(&self) -> i64 {
+        self.operators().map(|plan| plan.db_hits).sum()
+    }
+
+    /// Exports the profile as JSON, using the same field names as the server.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut json = json_object(self);
+        json.insert("dbHits".to_owned(), self.db_hits.into());
+        json.insert("rows".to_owned(), self.rows.into());
+        json.insert("pageCacheHits".to_owned(), self.page_cache_hits.into());
+        json.insert("pageCacheMisses".to_owned(), self.page_cache_misses.into());
+        json.insert(
+            "pageCacheHitRatio".to_owned(),
+            self.page_cache_hit_ratio.into(),
+        );
+        json.insert("time".to_owned(), self.time.into());
+        json.insert(
+            "children".to_owned(),
+            self.children.iter().map(ProfiledPlan::to_json).collect(),
+        );
+        json.into()
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_object(plan: &impl PlanNode) -> serde_json::Map<String, serde_json::Value> {
+    let arguments = plan
+        .arguments()
+        .iter()
+        .map(|(key, value)| {
+            let value = value
+                .to::<serde_json::Value>()
+                .unwrap_or(serde_json::Value::Null);
+            (key.clone(), value)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let mut json = serde_json::Map::new();
+    json.insert("operatorType".to_owned(), plan.operator_type().into());
+    json.insert("args".to_owned(), arguments.into());
+    json.insert("identifiers".to_owned(), plan.identifiers().into());
+    json
+}
+
+/// Renders the plan as a table, in the same format as Neo4j Browser and cypher-shell.
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        PlanTable::new(self).fmt(f)
+    }
+}
+
+/// Renders the profile as a table, in the same format as Neo4j Browser and cypher-shell,
+/// followed by the total number of database accesses.
+impl fmt::Display for ProfiledPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        PlanTable::new(self).fmt(f)?;
+        writeln!(f)?;
+        write!(f, "Total database accesses: {}", self.total_db_hits())
+    }
+}
+
+const PLAN_HEADERS: [&str; 4] = ["Operator", "Id", "Details", "Estimated Rows"];
+const PROFILE_HEADERS: [&str; 4] = ["Rows", "DB Hits", "Page Cache Hits/Misses", "Time (ms)"];
+
+enum TableRow {
+    Operator(Vec<String>),
+    /// The line between two operators that continues the tree in the first column.
+    Connector(String),
+}
+
+struct PlanTable {
+    headers: Vec<&'static str>,
+    rows: Vec<TableRow>,
+}
+
+impl PlanTable {
+    fn new<P: PlanNode>(plan: &P) -> Self {
+        let mut headers = PLAN_HEADERS.to_vec();
+        if plan.statistics().is_some() {
+            headers.extend(PROFILE_HEADERS);
+        }
+        let mut table = Self {
+            headers,
+            rows: Vec::new(),
+        };
+        table.add(plan, "");
+        table
+    }
+
+    /// Adds the operator and the operators below it, where `indent` is the tree
+    /// that continues to the left of the operator.
+    ///
+    /// For operators with two inputs, the right-hand side is rendered first,
+    /// indented by one level, followed by the left-hand side.
+    fn add<P: PlanNode>(&mut self, plan: &P, indent: &str) {
+        let details = plan
+            .details()
+            .map_or_else(|| plan.identifiers().join(", "), str::to_owned);
+        let id = match plan.arguments().get("Id") {
+            Some(BoltType::Integer(id)) => id.value.to_string(),
+            _ => String::new(),
+        };
+        let estimated_rows = plan
+            .estimated_rows()
+            .map_or_else(String::new, |rows| format!("{rows:.0}"));
+
+        let mut cells = vec![
+            format!("{indent}+{}", plan.operator_name()),
+            id,
+            details,
+            estimated_rows,
+        ];
+        cells.extend(plan.statistics().into_iter().flatten());
+        self.rows.push(TableRow::Operator(cells));
+
+        match plan.children() {
+            [] => {}
+            [input] => {
+                self.rows.push(TableRow::Connector(format!("{indent}|")));
+                self.add(input, indent);
+            }
+            [lhs, rhs, ..] => {
+                self.rows.push(TableRow::Connector(format!("{indent}|\\")));
+                self.add(rhs, &format!("{indent}| "));
+                self.rows.push(TableRow::Connector(format!("{indent}|")));
+                self.add(lhs, indent);
+            }
+        }
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths = self
+            .headers
+            .iter()
+            .map(|header| header.chars().count())
+            .collect::<Vec<_>>();
+        for row in &self.rows {
+            match row {
+                TableRow::Operator(cells) => {
+                    for (width, cell) in widths.iter_mut().zip(cells) {
+                        *width = (*width).max(cell.chars().count());
+                    }
+                }
+                TableRow::Connector(tree) => {
+                    widths[0] = widths[0].max(tree.chars().count());
+                }
+            }
+        }
+        widths
+    }
+}
+
+impl fmt::Display for PlanTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>();
+        let separator = format!("+{}+", separator.join("+"));
+        // the operator and details are aligned left, all numbers are aligned right
+        let left_aligned = |column: usize| column == 0 || column == 2;
+
+        writeln!(f, "{separator}")?;
+        for (column, (header, width)) in self.headers.iter().zip(&widths).enumerate() {
+            if left_aligned(column) {
+                write!(f, "| {header:<width$} ")?;
+            } else {
+                write!(f, "| {header:>width$} ")?;
+            }
+        }
+        writeln!(f, "|")?;
+        writeln!(f, "{separator}")?;
+
+        for row in &self.rows {
+            match row {
+                TableRow::Operator(cells) => {
+                    for (column, (cell, width)) in cells.iter().zip(&widths).enumerate() {
+                        if left_aligned(column) {
+                            write!(f, "| {cell:<width$} ")?;
+                        } else {
+                            write!(f, "| {cell:>width$} ")?;
+                        }
+                    }
+                    writeln!(f, "|")?;
+                }
+                TableRow::Connector(tree) => {
+                    write!(f, "| {tree:<width$} ", width = widths[0])?;
+                    for width in &widths[1..] {
+                        write!(f, "+{}", "-".repeat(width + 2))?;
+                    }
+                    writeln!(f, "+")?;
+                }
+            }
+        }
+
+        write!(f, "{separator}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(operator_type: &str, arguments: &[(&str, BoltType)], children: Vec<Plan>) -> Plan {
+        Plan {
+            operator_type: operator_type.to_owned(),
+            arguments: arguments
+                .iter()
+                .map(|(key, value)| ((*key).to_owned(), value.clone()))
+                .collect(),
+            identifiers: vec!["n".to_owned()],
+            children,
+        }
+    }
+
+    fn profile(operator_type: &str, db_hits: i64, children: Vec<ProfiledPlan>) -> ProfiledPlan {
+        ProfiledPlan {
+            operator_type: operator_type.to_owned(),
+            arguments: HashMap::new(),
+            identifiers: vec!["n".to_owned()],
+            db_hits,
+            rows: 10,
+            page_cache_hits: 0,
+            page_cache_misses: 0,
+            page_cache_hit_ratio: 0.0,
+            time: 0,
+            children,
+        }
+    }
+
+    fn apply_plan() -> Plan {
+        plan(
+            "ProduceResults@neo4j",
+            &[("Id", 0.into()), ("Details", "n, m".into())],
+            vec![plan(
+                "Apply@neo4j",
+                &[("Id", 1.into()), ("EstimatedRows", 100.0.into())],
+                vec![
+                    plan("AllNodesScan@neo4j", &[("Id", 2.into())], vec![]),
+                    plan(
+                        "NodeIndexSeek@neo4j",
+                        &[
+                            ("Id", 3.into()),
+                            ("Details", "RANGE INDEX m:Person(id)".into()),
+                        ],
+                        vec![],
+                    ),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn plan_helpers() {
+        let plan = apply_plan();
+
+        assert_eq!(plan.operator_name(), "ProduceResults");
+        assert_eq!(plan.details(), Some("n, m"));
+        assert_eq!(plan.estimated_rows(), None);
+        assert_eq!(plan.children[0].estimated_rows(), Some(100.0));
+
+        let operators = plan
+            .operators()
+            .map(Plan::operator_name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operators,
+            ["ProduceResults", "Apply", "AllNodesScan", "NodeIndexSeek"]
+        );
+
+        assert!(plan.contains_operator("NodeIndexSeek"));
+        assert!(plan.contains_operator("nodeindexseek"));
+        assert!(plan.contains_operator("AllNodesScan@neo4j"));
+        assert!(!plan.contains_operator("NodeByLabelScan"));
+        assert!(!plan.contains_operator("Node"));
+    }
+
+    #[test]
+    fn total_db_hits() {
+        let profile = profile(
+            "ProduceResults",
+            0,
+            vec![profile(
+                "Filter",
+                20,
+                vec![profile("NodeByLabelScan", 11, vec![])],
+            )],
+        );
+
+        assert_eq!(profile.total_db_hits(), 31);
+        assert_eq!(profile.children[0].total_db_hits(), 31);
+        assert!(profile.contains_operator("Filter"));
+    }
+
+    #[test]
+    fn render_plan() {
+        let expected = r"
++------------------+----+--------------------------+----------------+
+| Operator         | Id | Details                  | Estimated Rows |
++------------------+----+--------------------------+----------------+
+| +ProduceResults  |  0 | n, m                     |                |
+| |                +----+--------------------------+----------------+
+| +Apply           |  1 | n                        |            100 |
+| |\               +----+--------------------------+----------------+
+| | +NodeIndexSeek |  3 | RANGE INDEX m:Person(id) |                |
+| |                +----+--------------------------+----------------+
+| +AllNodesScan    |  2 | n                        |                |
++------------------+----+--------------------------+----------------+";
+
+        assert_eq!(apply_plan().to_string(), expected.trim_start());
+    }
+
+    #[test]
+    fn render_profile() {
+        let mut scan = profile("NodeByLabelScan", 11, vec![]);
+        scan.page_cache_hits = 3;
+        scan.time = 1_234_567;
+        let profile = profile("ProduceResults", 20, vec![scan]);
+
+        let expected = r"
++------------------+----+---------+----------------+------+---------+------------------------+-----------+
+| Operator         | Id | Details | Estimated Rows | Rows | DB Hits | Page Cache Hits/Misses | Time (ms) |
++------------------+----+---------+----------------+------+---------+------------------------+-----------+
+| +ProduceResults  |    | n       |                |   10 |      20 |                        |           |
+| |                +----+---------+----------------+------+---------+------------------------+-----------+
+| +NodeByLabelScan |    | n       |                |   10 |      11 |                    3/0 |     1.235 |
++------------------+----+---------+----------------+------+---------+------------------------+-----------+
+Total database accesses: 31";
+
+        assert_eq!(profile.to_string(), expected.trim_start());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn export_json() {
+        let profile = profile(
+            "ProduceResults",
+            20,
+            vec![profile("AllNodesScan", 11, vec![])],
+        );
+
+        let expected = serde_json::json!({
+            "operatorType": "ProduceResults",
+            "args": {},
+            "identifiers": ["n"],
+            "dbHits": 20,
+            "rows": 10,
+            "pageCacheHits": 0,
+            "pageCacheMisses": 0,
+            "pageCacheHitRatio": 0.0,
+            "time": 0,
+            "children": [{
+                "operatorType": "AllNodesScan",
+                "args": {},
+                "identifiers": ["n"],
+                "dbHits": 11,
+                "rows": 10,
+                "pageCacheHits": 0,
+                "pageCacheMisses": 0,
+                "pageCacheHitRatio": 0.0,
+                "time": 0,
+                "children": [],
+            }],
+        });
+        assert_eq!(profile.to_json(), expected);
+
+        let plan = apply_plan().to_json();
+        assert_eq!(plan["args"]["Id"], serde_json::json!(0));
+        assert_eq!(plan["args"]["Details"], serde_json::json!("n, m"));
+        assert_eq!(
+            plan["children"][0]["children"][1]["operatorType"],
+            serde_json::json!("NodeIndexSeek@neo4j")
+        );
+    }
+}
@@ -0,0 +1,1100 @@
+//! Utilities to test applications without a running Neo4j server.
+//!
+//! A [`StubServer`] listens on a local port and plays a [`Script`] of the requests
+//! it expects from the driver and the responses it sends back.
+//! A [`MockExecutor`] does not use the network at all and returns canned results
+//! to code that runs its queries through a [`QueryExecutor`].
+//!
+//! Instead of writing a script by hand, the messages of a real session can be recorded with a
+//! [`crate::WireRecorder`] and replayed later with [`StubServer::replay`].
+//!
+//! ```
+//! use neo4rs::{query, testing::{Request, Response, Script, StubServer}, BoltType, Graph};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let script = Script::new()
+//!     .expect(Request::hello())
+//!     .reply(Response::success([("server", "Neo4j/4.4.0"), ("connection_id", "bolt-1")]))
+//!     .expect(Request::run("RETURN $x AS x").with_params([("x", 42)]))
+//!     .reply(Response::success([("fields", BoltType::from(vec!["x"]))]))
+//!     .expect(Request::pull())
+//!     .reply(Response::record([42]))
+//!     .reply(Response::success([("type", "r")]));
+//!
+//! let server = StubServer::start(script).await.unwrap();
+//! let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+//!
+//! let mut stream = graph.execute(query("RETURN $x AS x").param("x", 42)).await.unwrap();
+//! let row = stream.next().await.unwrap().unwrap();
+//! assert_eq!(row.get::<i64>("x").unwrap(), 42);
+//! drop(stream);
+//!
+//! server.finish().await.unwrap();
+//! # }
+//! ```
+use crate::{
+    connection::MAX_CHUNK_SIZE,
+    errors::{Error, Neo4jError, Result},
+    executor::{QueryExecutor, Records, TransactionExecutor},
+    recorder::unhex,
+    row::Row,
+    summary::ResultSummary,
+    types::{BoltList, BoltMap, BoltString, BoltType},
+    version::Version,
+    Query,
+};
+use bytes::{BufMut, Bytes, BytesMut};
+use std::{collections::VecDeque, fmt, net::SocketAddr, path::Path};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::{JoinHandle, JoinSet},
+};
+
+const HELLO: u8 = 0x01;
+const LOGON: u8 = 0x6A;
+const GOODBYE: u8 = 0x02;
+const RESET: u8 = 0x0F;
+const RUN: u8 = 0x10;
+const BEGIN: u8 = 0x11;
+const COMMIT: u8 = 0x12;
+const ROLLBACK: u8 = 0x13;
+const DISCARD: u8 = 0x2F;
+const PULL: u8 = 0x3F;
+const ROUTE: u8 = 0x66;
+const SUCCESS: u8 = 0x70;
+const RECORD: u8 = 0x71;
+const IGNORED: u8 = 0x7E;
+const FAILURE: u8 = 0x7F;
+
+/// A Bolt server on a local port that plays a [`Script`] for the first connection it accepts,
+/// or one script for each of several connections, see [`StubServer::start_all`].
+///
+/// The server only speaks the Bolt protocol, it does not run any queries.
+/// Every request of the driver is checked against the script and
+/// the scripted responses are sent back, which makes tests deterministic.
+///
+/// Note that connections are reset when they are returned to the connection pool and used again,
+/// so a script for several queries on a [`crate::Graph`] has to expect the `RESET` in between.
+pub struct StubServer {
+    address: SocketAddr,
+    handle: JoinHandle<Result<()>>,
+}
+
+impl StubServer {
+    /// Starts a server on a free local port that plays the given script.
+    pub async fn start(script: Script) -> Result<Self> {
+        Self::start_all([script]).await
+    }
+
+    /// Starts a server on a free local port that plays one script per connection,
+    /// in the order in which the connections are accepted.
+    ///
+    /// The scripts are played concurrently, so that the driver can use several connections at once.
+    pub async fn start_all(scripts: impl IntoIterator<Item = Script>) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let mut scripts = scripts.into_iter().collect::<VecDeque<_>>();
+        let handle = tokio::spawn(async move {
+            let mut connections = JoinSet::new();
+            loop {
+                tokio::select! {
+                    accepted = listener.accept(), if !scripts.is_empty() => {
+                        let (stream, _) = accepted?;
+                        if let Some(script) = scripts.pop_front() {
+                            connections.spawn(script.play(stream));
+                        }
+                    }
+                    Some(played) = connections.join_next() => {
+                        played.map_err(|e| {
+                            Error::UnexpectedMessage(format!("the stub server failed: {e}"))
+                        })??;
+                    }
+                    else => return Ok(()),
+                }
+            }
+        });
+        Ok(StubServer { address, handle })
+    }
+
+    /// Starts a server that replays a recording of a [`crate::WireRecorder`],
+    /// see [`Script::from_recording`].
+    pub async fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        Self::start_all(Script::from_recording(path)?).await
+    }
+
+    /// The address that the server listens on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// The `bolt://` URI of the server, which can be passed to [`crate::Graph::new`].
+    pub fn uri(&self) -> String {
+        format!("bolt://{}", self.address)
+    }
+
+    /// Waits until all scripts have been played to the end.
+    ///
+    /// Returns an error if the driver sent a request that a script did not expect,
+    /// or if a connection was closed before its script was complete.
+    pub async fn finish(mut self) -> Result<()> {
+        match (&mut self.handle).await {
+            Ok(result) => result,
+            Err(e) => Err(Error::UnexpectedMessage(format!(
+                "the stub server failed: {e}"
+            ))),
+        }
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+impl fmt::Debug for StubServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StubServer")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The requests that a [`StubServer`] expects and the responses that it sends, in order.
+#[derive(Clone, Debug)]
+pub struct Script {
+    version: Version,
+    steps: Vec<Step>,
+}
+
+#[derive(Clone, Debug)]
+enum Step {
+    Expect(Request),
+    Reply(Response),
+    Disconnect,
+}
+
+impl Script {
+    /// Creates an empty script that negotiates Bolt 4.4 during the handshake.
+    pub fn new() -> Self {
+        Script {
+            version: Version::V4_4,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Sets the Bolt version that is negotiated during the handshake.
+    ///
+    /// If the driver does not offer the version, the handshake is answered with no version
+    /// and playing the script fails.
+    pub fn with_version(self, version: Version) -> Self {
+        Script { version, ..self }
+    }
+
+    /// Expects the next request from the driver to match the given request.
+    pub fn expect(mut self, request: Request) -> Self {
+        self.steps.push(Step::Expect(request));
+        self
+    }
+
+    /// Sends the given response to the driver.
+    pub fn reply(mut self, response: Response) -> Self {
+        self.steps.push(Step::Reply(response));
+        self
+    }
+
+    /// Closes the connection, for example to test how the application handles a lost connection.
+    pub fn disconnect(mut self) -> Self {
+        self.steps.push(Step::Disconnect);
+        self
+    }
+
+    /// Reads the scripts of all connections of a recording of a [`crate::WireRecorder`],
+    /// in the order in which the connections were opened.
+    ///
+    /// Every recorded request is expected exactly as it was sent, except for the `HELLO`
+    /// and `LOGON`, whose credentials have not been recorded. The responses are sent as they were recorded.
+    pub fn from_recording(path: impl AsRef<Path>) -> Result<Vec<Script>> {
+        let recording = std::fs::read_to_string(path)?;
+        let mut scripts: Vec<(&str, Script)> = Vec::new();
+        for (number, line) in recording.lines().enumerate() {
+            let malformed = || {
+                Error::UnexpectedMessage(format!(
+                    "malformed recording in line {}: {line}",
+                    number + 1
+                ))
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (id, line) = line.split_once(' ').ok_or_else(malformed)?;
+            let (tag, content) = line.split_once(": ").ok_or_else(malformed)?;
+            let index = match scripts.iter().position(|(script, _)| *script == id) {
+                Some(index) => index,
+                None => {
+                    scripts.push((id, Script::new()));
+                    scripts.len() - 1
+                }
+            };
+            let script = &mut scripts[index].1;
+            match tag {
+                "V" => {
+                    let (major, minor) = content.split_once('.').ok_or_else(malformed)?;
+                    let major = major.parse().map_err(|_| malformed())?;
+                    let minor = minor.parse().map_err(|_| malformed())?;
+                    script.version = Version::parse([0, 0, minor, major])?;
+                }
+                "C" => {
+                    let message = unhex(content).ok_or_else(malformed)?;
+                    let (signature, fields) = decode(script.version, message)?;
+                    script
+                        .steps
+                        .push(Step::Expect(Request::recorded(signature, fields)));
+                }
+                "S" => {
+                    let message = unhex(content).ok_or_else(malformed)?;
+                    script.steps.push(Step::Reply(Response {
+                        message: ResponseMessage::Recorded(message),
+                    }));
+                }
+                _ => return Err(malformed()),
+            }
+        }
+        Ok(scripts.into_iter().map(|(_, script)| script).collect())
+    }
+
+    async fn play(self, mut stream: TcpStream) -> Result<()> {
+        let version = self.handshake(&mut stream).await?;
+
+        for step in self.steps {
+            match step {
+                Step::Expect(request) => {
+                    let (signature, fields) =
+                        read_message(&mut stream, version).await?.ok_or_else(|| {
+                            Error::UnexpectedMessage(format!(
+                                "the connection was closed, but the script expected {request}"
+                            ))
+                        })?;
+                    if !request.matches(signature, &fields) {
+                        return Err(Error::UnexpectedMessage(format!(
+                            "the script expected {request}, but received {}",
+                            Message(signature, &fields)
+                        )));
+                    }
+                }
+                Step::Reply(response) => {
+                    write_message(&mut stream, version, &response).await?;
+                }
+                Step::Disconnect => return Ok(()),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handshake(&self, stream: &mut TcpStream) -> Result<Version> {
+        let mut handshake = [0; 20];
+        stream.read_exact(&mut handshake).await?;
+        if handshake[..4] != [0x60, 0x60, 0xB0, 0x17] {
+            return Err(Error::UnexpectedMessage(format!(
+                "invalid handshake: {handshake:02X?}"
+            )));
+        }
+
+        if !offers(&handshake[4..], self.version) {
+            stream.write_all(&[0, 0, 0, 0]).await?;
+            return Err(Error::UnexpectedMessage(format!(
+                "the driver does not offer Bolt {}",
+                self.version
+            )));
+        }
+        let (major, minor) = self.version.major_minor();
+        stream.write_all(&[0, 0, minor, major]).await?;
+        stream.flush().await?;
+
+        Ok(self.version)
+    }
+}
+
+impl Default for Script {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A request that is expected by a [`Script`].
+///
+/// Only the type of the request is checked, unless the request is further specified,
+/// such as the query and parameters of a [`Request::run`].
+#[derive(Clone, Debug)]
+pub struct Request {
+    signature: u8,
+    query: Option<String>,
+    params: Option<BoltMap>,
+    extra: Option<BoltMap>,
+    fields: Option<Vec<BoltType>>,
+}
+
+impl Request {
+    fn new(signature: u8) -> Self {
+        Request {
+            signature,
+            query: None,
+            params: None,
+            extra: None,
+            fields: None,
+        }
+    }
+
+    /// Expects exactly the fields of a recorded request.
+    /// The credentials of a recorded `HELLO` or `LOGON` are not known, so any `HELLO` or `LOGON` is accepted.
+    fn recorded(signature: u8, fields: Vec<BoltType>) -> Self {
+        if signature == HELLO || signature == LOGON {
+            return Self::new(signature);
+        }
+        Request {
+            fields: Some(fields),
+            ..Self::new(signature)
+        }
+    }
+
+    /// Expects a `HELLO` with any credentials.
+    pub fn hello() -> Self {
+        Self::new(HELLO)
+    }
+
+    /// Expects a `LOGON` with any credentials, which follows the `HELLO` since Bolt 5.1.
+    pub fn logon() -> Self {
+        Self::new(LOGON)
+    }
+
+    /// Expects a `GOODBYE`.
+    pub fn goodbye() -> Self {
+        Self::new(GOODBYE)
+    }
+
+    /// Expects a `RESET`.
+    pub fn reset() -> Self {
+        Self::new(RESET)
+    }
+
+    /// Expects a `RUN` of the given query with any parameters.
+    pub fn run(query: impl Into<String>) -> Self {
+        Request {
+            query: Some(query.into()),
+            ..Self::new(RUN)
+        }
+    }
+
+    /// Expects a `BEGIN`.
+    pub fn begin() -> Self {
+        Self::new(BEGIN)
+    }
+
+    /// Expects a `COMMIT`.
+    pub fn commit() -> Self {
+        Self::new(COMMIT)
+    }
+
+    /// Expects a `ROLLBACK`.
+    pub fn rollback() -> Self {
+        Self::new(ROLLBACK)
+    }
+
+    /// Expects a `DISCARD`.
+    pub fn discard() -> Self {
+        Self::new(DISCARD)
+    }
+
+    /// Expects a `PULL`.
+    pub fn pull() -> Self {
+        Self::new(PULL)
+    }
+
+    /// Expects a `ROUTE`.
+    pub fn route() -> Self {
+        Self::new(ROUTE)
+    }
+
+    /// Expects exactly the given parameters for a [`Request::run`].
+    pub fn with_params<K, V>(self, params: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<BoltString>,
+        V: Into<BoltType>,
+    {
+        let params = params
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        Request {
+            params: Some(params),
+            ..self
+        }
+    }
+
+    /// Expects that the extra map of the request, such as the metadata of a `HELLO`, `BEGIN`
+    /// or `RUN`, contains the given entries.
+    pub fn with_extra<K, V>(self, extra: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<BoltString>,
+        V: Into<BoltType>,
+    {
+        let extra = extra
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        Request {
+            extra: Some(extra),
+            ..self
+        }
+    }
+
+    fn matches(&self, signature: u8, fields: &[BoltType]) -> bool {
+        if signature != self.signature {
+            return false;
+        }
+        if let Some(query) = &self.query {
+            if !matches!(fields.first(), Some(BoltType::String(s)) if s.value == *query) {
+                return false;
+            }
+        }
+        if let Some(params) = &self.params {
+            if !matches!(fields.get(1), Some(BoltType::Map(m)) if m == params) {
+                return false;
+            }
+        }
+        if let Some(extra) = &self.extra {
+            let Some(BoltType::Map(actual)) = fields.last() else {
+                return false;
+            };
+            if extra
+                .value
+                .iter()
+                .any(|(key, value)| actual.value.get(key) != Some(value))
+            {
+                return false;
+            }
+        }
+        if let Some(expected) = &self.fields {
+            return expected == fields;
+        }
+        true
+    }
+}
+
+impl fmt::Display for Request {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name(self.signature))?;
+        if let Some(query) = &self.query {
+            write!(f, " {query:?}")?;
+        }
+        if let Some(params) = &self.params {
+            write!(f, " {:?}", params.value)?;
+        }
+        if let Some(extra) = &self.extra {
+            write!(f, " {:?}", extra.value)?;
+        }
+        if let Some(fields) = &self.fields {
+            write!(f, " {fields:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A response that is sent by a [`Script`].
+#[derive(Clone, Debug)]
+pub struct Response {
+    message: ResponseMessage,
+}
+
+#[derive(Clone, Debug)]
+enum ResponseMessage {
+    Fields {
+        signature: u8,
+        fields: Vec<BoltType>,
+    },
+    Recorded(Bytes),
+}
+
+impl Response {
+    /// A `SUCCESS` with the given metadata.
+    pub fn success<K, V>(metadata: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<BoltString>,
+        V: Into<BoltType>,
+    {
+        let metadata = metadata
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect::<BoltMap>();
+        Self::new(SUCCESS, vec![BoltType::Map(metadata)])
+    }
+
+    /// A `RECORD` with the given values.
+    pub fn record<V: Into<BoltType>>(values: impl IntoIterator<Item = V>) -> Self {
+        let values = values.into_iter().map(Into::into).collect::<Vec<_>>();
+        Self::new(RECORD, vec![BoltType::List(BoltList::from(values))])
+    }
+
+    /// A `FAILURE` with the given error code and message.
+    pub fn failure(code: impl Into<String>, message: impl Into<String>) -> Self {
+        let metadata = [("code", code.into()), ("message", message.into())]
+            .into_iter()
+            .map(|(key, value)| (key.into(), BoltType::from(value)))
+            .collect::<BoltMap>();
+        Self::new(FAILURE, vec![BoltType::Map(metadata)])
+    }
+
+    /// An `IGNORED`.
+    pub fn ignored() -> Self {
+        Self::new(IGNORED, Vec::new())
+    }
+
+    fn new(signature: u8, fields: Vec<BoltType>) -> Self {
+        Response {
+            message: ResponseMessage::Fields { signature, fields },
+        }
+    }
+}
+
+/// Whether the versions of a handshake, each of them a range of minor versions below
+/// a `major.minor` version, contain the given version.
+fn offers(versions: &[u8], version: Version) -> bool {
+    let (major, minor) = version.major_minor();
+    versions.chunks_exact(4).any(|offered| {
+        let (range, highest, offered_major) = (offered[1], offered[2], offered[3]);
+        offered_major == major && (highest.saturating_sub(range)..=highest).contains(&minor)
+    })
+}
+
+fn name(signature: u8) -> &'static str {
+    match signature {
+        HELLO => "HELLO",
+        LOGON => "LOGON",
+        GOODBYE => "GOODBYE",
+        RESET => "RESET",
+        RUN => "RUN",
+        BEGIN => "BEGIN",
+        COMMIT => "COMMIT",
+        ROLLBACK => "ROLLBACK",
+        DISCARD => "DISCARD",
+        PULL => "PULL",
+        ROUTE => "ROUTE",
+        _ => "an unknown message",
+    }
+}
+
+struct Message<'a>(u8, &'a [BoltType]);
+
+impl fmt::Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:?}", name(self.0), self.1)
+    }
+}
+
+/// Reads a chunked message and returns its signature and fields,
+/// or `None` if the connection has been closed.
+async fn read_message(
+    stream: &mut TcpStream,
+    version: Version,
+) -> Result<Option<(u8, Vec<BoltType>)>> {
+    let mut message = BytesMut::new();
+    loop {
+        let size = match stream.read_u16().await {
+            Ok(size) => usize::from(size),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        if size == 0 {
+            if message.is_empty() {
+                // a NOOP chunk
+                continue;
+            }
+            break;
+        }
+        let start = message.len();
+        message.resize(start + size, 0);
+        stream.read_exact(&mut message[start..]).await?;
+    }
+
+    decode(version, message.freeze()).map(Some)
+}
+
+/// Decodes a message into its signature and fields.
+fn decode(version: Version, mut message: Bytes) -> Result<(u8, Vec<BoltType>)> {
+    if message.len() < 2 || message[0] & 0xF0 != 0xB0 {
+        return Err(Error::UnexpectedMessage(format!(
+            "invalid message: {:02X?}",
+            &message[..]
+        )));
+    }
+    let len = message[0] & 0x0F;
+    let signature = message[1];
+    let mut fields: Bytes = message.split_off(2);
+    let fields = (0..len)
+        .map(|_| BoltType::parse(version, &mut fields))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((signature, fields))
+}
+
+async fn write_message(
+    stream: &mut TcpStream,
+    version: Version,
+    response: &Response,
+) -> Result<()> {
+    let message = match &response.message {
+        ResponseMessage::Fields { signature, fields } => {
+            let mut message = BytesMut::new();
+            message.put_u8(0xB0 | fields.len() as u8);
+            message.put_u8(*signature);
+            for field in fields {
+                field.write_into(version, &mut message)?;
+            }
+            message.freeze()
+        }
+        ResponseMessage::Recorded(message) => message.clone(),
+    };
+
+    let chunks = message.len().div_ceil(MAX_CHUNK_SIZE);
+    let mut chunked = BytesMut::with_capacity(message.len() + 2 * chunks + 2);
+    for chunk in message.chunks(MAX_CHUNK_SIZE) {
+        chunked.put_u16(chunk.len() as u16);
+        chunked.put_slice(chunk);
+    }
+    chunked.put_u16(0);
+    stream.write_all(&chunked).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+/// An in-memory [`QueryExecutor`] that returns canned results for the queries it runs.
+///
+/// The results are keyed by the query text and the parameters of the query,
+/// running a query without a result fails with [`Error::UnexpectedMessage`].
+/// All queries are recorded, so that tests can assert on them with [`MockExecutor::queries`].
+///
+/// ```
+/// use neo4rs::{query, testing::MockExecutor, BoltList, BoltType, QueryExecutor, Row};
+///
+/// async fn names(executor: &mut impl QueryExecutor) -> neo4rs::Result<Vec<String>> {
+///     let records = executor.execute(query("MATCH (p:Person) RETURN p.name AS name")).await?;
+///     records.rows().iter().map(|row| Ok(row.get("name")?)).collect()
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let row = |name: &str| Row::new(BoltList::from(vec![BoltType::from("name")]), BoltList::from(vec![BoltType::from(name)]));
+/// let mut executor = MockExecutor::new()
+///     .with_rows(query("MATCH (p:Person) RETURN p.name AS name"), [row("Alice"), row("Bob")]);
+///
+/// assert_eq!(names(&mut executor).await.unwrap(), ["Alice", "Bob"]);
+/// assert_eq!(executor.queries().len(), 1);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockExecutor {
+    results: Vec<Canned>,
+    queries: Vec<Query>,
+    commits: usize,
+    rollbacks: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Canned {
+    query: String,
+    params: BoltMap,
+    result: std::result::Result<Records, Neo4jError>,
+}
+
+impl Canned {
+    fn matches(&self, query: &Query) -> bool {
+        self.query == query.query() && self.params == *query.get_params()
+    }
+}
+
+impl MockExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the given rows with an empty summary for the query.
+    pub fn with_rows(self, query: impl Into<Query>, rows: impl IntoIterator<Item = Row>) -> Self {
+        let records = Records::new(rows.into_iter().collect(), ResultSummary::default());
+        self.with_records(query, records)
+    }
+
+    /// Returns the given summary without any rows for the query.
+    pub fn with_summary(self, query: impl Into<Query>, summary: ResultSummary) -> Self {
+        self.with_records(query, Records::new(Vec::new(), summary))
+    }
+
+    /// Returns the given rows and summary for the query.
+    pub fn with_records(self, query: impl Into<Query>, records: Records) -> Self {
+        self.with_result(query.into(), Ok(records))
+    }
+
+    /// Fails the query with a [`Error::Neo4j`] error with the given code and message.
+    pub fn with_failure(
+        self,
+        query: impl Into<Query>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        let error = Neo4jError::new(code.into(), message.into());
+        self.with_result(query.into(), Err(error))
+    }
+
+    fn with_result(
+        mut self,
+        query: Query,
+        result: std::result::Result<Records, Neo4jError>,
+    ) -> Self {
+        self.results.retain(|canned| !canned.matches(&query));
+        self.results.push(Canned {
+            query: query.query().to_owned(),
+            params: query.get_params().clone(),
+            result,
+        });
+        self
+    }
+
+    /// The queries that have been run, including the queries of committed transactions.
+    pub fn queries(&self) -> &[Query] {
+        &self.queries
+    }
+
+    /// The number of transactions that have been committed.
+    pub fn commits(&self) -> usize {
+        self.commits
+    }
+
+    /// The number of transactions that have been rolled back.
+    pub fn rollbacks(&self) -> usize {
+        self.rollbacks
+    }
+
+    fn result_for(results: &[Canned], query: &Query) -> Result<Records> {
+        let canned = results
+            .iter()
+            .find(|canned| canned.matches(query))
+            .ok_or_else(|| {
+                Error::UnexpectedMessage(format!(
+                    "no mock result for the query `{}` with the parameters {:?}",
+                    query.query(),
+                    query.get_params().value
+                ))
+            })?;
+        canned.result.clone().map_err(Error::Neo4j)
+    }
+}
+
+impl QueryExecutor for MockExecutor {
+    async fn run(&mut self, query: impl Into<Query> + Send) -> Result<ResultSummary> {
+        self.execute(query)
+            .await
+            .map(|records| records.into_parts().1)
+    }
+
+    async fn execute(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let query = query.into();
+        let result = Self::result_for(&self.results, &query);
+        self.queries.push(query);
+        result
+    }
+
+    async fn execute_read(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        self.execute(query).await
+    }
+}
+
+impl TransactionExecutor for MockExecutor {
+    type Txn = MockTxn;
+
+    async fn start_txn(&mut self) -> Result<MockTxn> {
+        Ok(MockTxn {
+            results: self.results.clone(),
+            queries: Vec::new(),
+        })
+    }
+
+    async fn commit(&mut self, txn: MockTxn) -> Result<()> {
+        self.queries.extend(txn.queries);
+        self.commits += 1;
+        Ok(())
+    }
+
+    async fn rollback(&mut self, _txn: MockTxn) -> Result<()> {
+        self.rollbacks += 1;
+        Ok(())
+    }
+}
+
+/// A transaction of a [`MockExecutor`], which returns the same results as the executor.
+#[derive(Clone, Debug)]
+pub struct MockTxn {
+    results: Vec<Canned>,
+    queries: Vec<Query>,
+}
+
+impl MockTxn {
+    /// The queries that have been run in this transaction.
+    pub fn queries(&self) -> &[Query] {
+        &self.queries
+    }
+}
+
+impl QueryExecutor for MockTxn {
+    async fn run(&mut self, query: impl Into<Query> + Send) -> Result<ResultSummary> {
+        self.execute(query)
+            .await
+            .map(|records| records.into_parts().1)
+    }
+
+    async fn execute(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        let query = query.into();
+        let result = MockExecutor::result_for(&self.results, &query);
+        self.queries.push(query);
+        result
+    }
+
+    async fn execute_read(&mut self, query: impl Into<Query> + Send) -> Result<Records> {
+        self.execute(query).await
+    }
+}
+
+/// A script that expects the `HELLO` of a Bolt 4.4 connection, for the tests of the driver.
+#[cfg(test)]
+pub(crate) fn hello() -> Script {
+    Script::new()
+        .expect(Request::hello())
+        .reply(Response::success([
+            ("server", "Neo4j/4.4.0"),
+            ("connection_id", "bolt-1"),
+        ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{query, Graph};
+
+    #[test]
+    fn should_only_negotiate_offered_versions() {
+        let versions = [0, 7, 7, 5, 0, 1, 4, 4, 0, 0, 1, 4, 0, 0, 0, 0];
+        assert!(offers(&versions, Version::V5_7));
+        assert!(offers(&versions, Version::V5_0));
+        assert!(offers(&versions, Version::V4_3));
+        assert!(offers(&versions, Version::V4_1));
+        assert!(!offers(&versions, Version::V4));
+
+        let versions = [0, 1, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert!(!offers(&versions, Version::V5_2));
+    }
+
+    #[tokio::test]
+    async fn should_play_script() {
+        let script = hello()
+            .expect(Request::run("CREATE (n:Node {id: $id})").with_params([("id", 1)]))
+            .reply(Response::success([(
+                "fields",
+                BoltType::from(Vec::<String>::new()),
+            )]))
+            .expect(Request::discard())
+            .reply(Response::success([(
+                "stats",
+                BoltType::from(std::collections::HashMap::from([("nodes-created", 1)])),
+            )]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let summary = graph
+            .run(query("CREATE (n:Node {id: $id})").param("id", 1))
+            .await
+            .unwrap();
+        assert_eq!(summary.nodes_created(), 1);
+
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_reply_with_failure() {
+        let script = hello()
+            .expect(Request::run("RETURN 1"))
+            .reply(Response::failure(
+                "Neo.ClientError.Statement.SyntaxError",
+                "Invalid input",
+            ))
+            .expect(Request::discard())
+            .reply(Response::ignored());
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let Err(Error::Neo4j(error)) = graph.run("RETURN 1").await else {
+            panic!("expected a failure");
+        };
+        assert_eq!(error.code(), "Neo.ClientError.Statement.SyntaxError");
+        assert_eq!(error.message(), "Invalid input");
+
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_unexpected_request() {
+        let script = hello()
+            .expect(Request::run("RETURN 2"))
+            .reply(Response::success([("fields", BoltType::from(vec!["x"]))]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        assert!(graph.run("RETURN 1").await.is_err());
+
+        let error = server.finish().await.unwrap_err().to_string();
+        assert!(error.contains("expected RUN \"RETURN 2\""), "{error}");
+    }
+
+    #[tokio::test]
+    async fn should_chunk_large_responses() {
+        let large = "x".repeat(3 * MAX_CHUNK_SIZE);
+        let script = hello()
+            .expect(Request::run("RETURN $x AS x"))
+            .reply(Response::success([("fields", BoltType::from(vec!["x"]))]))
+            .expect(Request::pull())
+            .reply(Response::record([large.as_str()]))
+            .reply(Response::success([("type", "r")]));
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let mut stream = graph
+            .execute(query("RETURN $x AS x").param("x", large.as_str()))
+            .await
+            .unwrap();
+        let row = stream.next().await.unwrap().unwrap();
+        assert_eq!(row.get::<String>("x").unwrap(), large);
+        drop(stream);
+
+        server.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_replay_recording() {
+        let script = hello()
+            .expect(Request::run("RETURN $x AS x").with_params([("x", 42)]))
+            .reply(Response::success([("fields", BoltType::from(vec!["x"]))]))
+            .expect(Request::pull())
+            .reply(Response::record([42]))
+            .reply(Response::success([("type", "r")]));
+        let server = StubServer::start(script).await.unwrap();
+        let path = std::env::temp_dir().join(format!("neo4rs-replay-{}.bolt", std::process::id()));
+        let recorder = crate::WireRecorder::create(&path).unwrap();
+        let config = crate::ConfigBuilder::default()
+            .uri(server.uri())
+            .user("neo4j")
+            .password("secret")
+            .record_wire(recorder.clone())
+            .build()
+            .unwrap();
+
+        async fn execute(graph: Graph) -> i64 {
+            let mut stream = graph
+                .execute(query("RETURN $x AS x").param("x", 42))
+                .await
+                .unwrap();
+            let row = stream.next().await.unwrap().unwrap();
+            row.get("x").unwrap()
+        }
+
+        assert_eq!(execute(Graph::connect(config).unwrap()).await, 42);
+        server.finish().await.unwrap();
+        recorder.flush().await.unwrap();
+
+        let recording = std::fs::read_to_string(&path).unwrap();
+        // The hex encoding of "secret".
+        assert!(!recording.contains("736563726574"));
+
+        let server = StubServer::replay(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "other").unwrap();
+        assert_eq!(execute(graph).await, 42);
+        server.finish().await.unwrap();
+    }
+
+    fn row(name: &str) -> Row {
+        Row::new(
+            BoltList::from(vec![BoltType::from("name")]),
+            BoltList::from(vec![BoltType::from(name)]),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_return_canned_results() {
+        let mut summary = ResultSummary::default();
+        summary.stats.nodes_created = 1;
+        let mut executor = MockExecutor::new()
+            .with_rows(query("MATCH (n) RETURN n.name AS name"), [row("Alice")])
+            .with_summary(query("CREATE (n {id: $id})").param("id", 1), summary);
+
+        let records = executor
+            .execute_read("MATCH (n) RETURN n.name AS name")
+            .await
+            .unwrap();
+        assert_eq!(records.rows_as::<String>().unwrap(), ["Alice"]);
+
+        let summary = executor
+            .run(query("CREATE (n {id: $id})").param("id", 1))
+            .await
+            .unwrap();
+        assert_eq!(summary.nodes_created(), 1);
+
+        let error = executor
+            .run(query("CREATE (n {id: $id})").param("id", 2))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::UnexpectedMessage(_)));
+
+        let queries = executor.queries();
+        assert_eq!(queries.len(), 3);
+        assert_eq!(queries[2].get_params().get::<i64>("id").unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_return_canned_failures() {
+        let mut executor = MockExecutor::new().with_failure(
+            "RETURN 1",
+            "Neo.TransientError.Transaction.DeadlockDetected",
+            "deadlock",
+        );
+
+        let Err(Error::Neo4j(error)) = executor.run("RETURN 1").await else {
+            panic!("expected a failure");
+        };
+        assert_eq!(
+            error.code(),
+            "Neo.TransientError.Transaction.DeadlockDetected"
+        );
+        assert!(error.can_retry());
+    }
+
+    #[tokio::test]
+    async fn should_record_queries_of_committed_transactions() {
+        let mut executor = MockExecutor::new()
+            .with_summary("CREATE (n)", ResultSummary::default())
+            .with_summary("DELETE n", ResultSummary::default());
+
+        let mut txn = executor.start_txn().await.unwrap();
+        txn.run("DELETE n").await.unwrap();
+        executor.rollback(txn).await.unwrap();
+
+        let mut txn = executor.start_txn().await.unwrap();
+        txn.run("CREATE (n)").await.unwrap();
+        assert_eq!(txn.queries().len(), 1);
+        executor.commit(txn).await.unwrap();
+
+        assert_eq!(executor.commits(), 1);
+        assert_eq!(executor.rollbacks(), 1);
+        let queries = executor.queries();
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].query(), "CREATE (n)");
+    }
+}
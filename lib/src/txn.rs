@@ -2,14 +2,20 @@
 use crate::messages::{BoltRequest, BoltResponse};
 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
 use {
-    crate::bolt::{Begin, Commit, Rollback, Summary},
+    crate::bolt::{Begin, Commit, ExpectedResponse, Rollback, Summary},
     crate::bookmarks::Bookmark,
     log::debug,
 };
 
 use crate::config::ImpersonateUser;
 use crate::{
-    config::Database, errors::Result, pool::ManagedConnection, query::Query, stream::RowStream,
+    config::Database,
+    errors::Result,
+    notification::NotificationFilter,
+    pool::ManagedConnection,
+    query::Query,
+    retry::Retry,
+    stream::{OpenStreams, RowStream},
     Operation, RunResult,
 };
 
@@ -17,12 +23,24 @@ use crate::{
 ///
 /// When a transaction is started, a dedicated connection is reserved and moved into the handle which
 /// will be released to the connection pool when the [`Txn`] handle is dropped.
+///
+/// The BEGIN of the transaction is sent together with its first query, or when it is committed
+/// or rolled back, unless [`crate::ConfigBuilder::pipeline_begin`] is disabled.
+/// An error when starting the transaction is then returned by the first operation on the handle.
+///
+/// Multiple [`RowStream`]s of a transaction can be open and consumed in any order.
+/// When a stream is consumed or another query is executed while the next batch of a different stream
+/// is still being transferred, for example because of [`RowStream::with_prefetch`],
+/// that batch is received first and kept in the transaction until its stream is consumed again.
 pub struct Txn {
     db: Option<Database>,
     fetch_size: usize,
     connection: ManagedConnection,
     operation: Operation,
     imp_user: Option<ImpersonateUser>,
+    /// Whether the BEGIN has been written and its response has not been received yet.
+    pending_begin: bool,
+    streams: OpenStreams,
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     bookmark: Option<String>,
 }
@@ -35,18 +53,19 @@ impl Txn {
         mut connection: ManagedConnection,
         operation: Operation,
         imp_user: Option<ImpersonateUser>,
+        notifications: &NotificationFilter,
     ) -> Result<Self> {
-        let begin = BoltRequest::begin(db.as_deref());
-        match connection.send_recv(begin).await? {
-            BoltResponse::Success(_) => Ok(Txn {
-                db,
-                fetch_size,
-                connection,
-                operation,
-                imp_user,
-            }),
-            msg => Err(msg.into_error("BEGIN")),
-        }
+        let begin = BoltRequest::begin(db.as_deref(), notifications, connection.version());
+        connection.write(begin).await?;
+        Ok(Txn {
+            db,
+            fetch_size,
+            connection,
+            operation,
+            imp_user,
+            pending_begin: true,
+            streams: OpenStreams::new(),
+        })
     }
 
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
@@ -57,26 +76,83 @@ impl Txn {
         operation: Operation,
         imp_user: Option<ImpersonateUser>,
         bookmarks: &[String],
+        notifications: &NotificationFilter,
     ) -> Result<Self> {
         debug!("Starting transaction with bookmarks: {:?}", bookmarks);
         let begin = Begin::builder(db.as_deref())
             .with_bookmarks(bookmarks.to_vec())
+            .with_notifications(notifications)
             .build(connection.version());
-        match connection.send_recv_as(begin).await? {
-            Summary::Success(response) => Ok(Txn {
-                db: response.metadata.db.or(db),
-                fetch_size,
-                connection,
-                operation,
-                bookmark: None,
-                imp_user,
-            }),
+        connection.write_as(begin).await?;
+        Ok(Txn {
+            db,
+            fetch_size,
+            connection,
+            operation,
+            bookmark: None,
+            imp_user,
+            pending_begin: true,
+            streams: OpenStreams::new(),
+        })
+    }
+
+    /// Receives the response to the BEGIN, if it has not been received yet.
+    ///
+    /// If the transaction could not be started, the server ignores the requests
+    /// that were pipelined after the BEGIN, and their responses are skipped.
+    async fn recv_begin(&mut self) -> Result<()> {
+        if !std::mem::take(&mut self.pending_begin) {
+            return Ok(());
+        }
+
+        #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
+        let result = match self.connection.recv().await? {
+            BoltResponse::Success(_) => Ok(()),
+            msg => Err(msg.into_error("BEGIN")),
+        };
+
+        #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+        let result = match self
+            .connection
+            .recv_as::<<Begin<'static> as ExpectedResponse>::Response>()
+            .await?
+        {
+            Summary::Success(response) => {
+                if let Some(db) = response.metadata.db {
+                    self.db = Some(db);
+                }
+                Ok(())
+            }
             Summary::Ignored => Err(crate::errors::Error::Ignored("Failed to start transaction")),
             Summary::Failure(failure) => Err(failure.into_error()),
+        };
+
+        if result.is_err() {
+            self.connection.skip_responses(0).await?;
         }
+        result
+    }
+
+    /// Sends the BEGIN, if it has not been sent with a query, and receives its response.
+    /// Also receives the batch of a stream that is still being transferred.
+    pub(crate) async fn flush_begin(&mut self) -> Result<()> {
+        if self.pending_begin {
+            self.connection.flush().await?;
+        }
+        self.recv_begin().await?;
+        self.streams.drain(&mut self.connection).await
+    }
+
+    /// Receives the response to a pipelined BEGIN before the first query if no database has been given,
+    /// so that the queries are sent with the database that the server has chosen.
+    async fn recv_db(&mut self) -> Result<()> {
+        #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
+        if self.pending_begin && self.db.is_none() {
+            self.flush_begin().await?;
+        }
+        Ok(())
     }
 
-    #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     /// Runs multiple queries one after the other in the same connection,
     /// merging all counters from each result summary.
     pub async fn run_queries<Q: Into<Query>>(
@@ -88,26 +164,13 @@ impl Txn {
             let q = query.into().imp_user(self.imp_user.clone());
             let summary = self.run(q).await?;
             counters += summary.stats();
-            self.save_bookmark_state(&summary);
         }
         Ok(counters)
     }
 
-    #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
-    /// Runs multiple queries one after the other in the same connection
-    pub async fn run_queries<Q: Into<Query>>(
-        &mut self,
-        queries: impl IntoIterator<Item = Q>,
-    ) -> Result<()> {
-        for query in queries {
-            let q = query.into().imp_user(self.imp_user.clone());
-            self.run(q).await?;
-        }
-        Ok(())
-    }
-
     /// Runs a single query and discards the stream.
     pub async fn run(&mut self, q: impl Into<Query>) -> Result<RunResult> {
+        self.recv_db().await?;
         let mut query = q.into();
         if let Some(db) = self.db.as_ref() {
             query = query.extra("db", db.to_string());
@@ -119,7 +182,14 @@ impl Txn {
                 Operation::Write => "w",
             },
         );
-        match query.run(&mut self.connection).await {
+        self.streams.drain(&mut self.connection).await?;
+        let stream = query.write_run(&mut self.connection).await?;
+        self.connection.flush().await?;
+        self.recv_begin().await?;
+        let stream = Query::recv_run(stream, &mut self.connection)
+            .await
+            .map_err(Retry::into_inner)?;
+        match stream.finish(&mut self.connection).await {
             Ok(result) => {
                 #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
                 self.save_bookmark_state(&result);
@@ -131,6 +201,7 @@ impl Txn {
 
     /// Executes a query and returns a [`RowStream`]
     pub async fn execute(&mut self, q: impl Into<Query>) -> Result<RowStream> {
+        self.recv_db().await?;
         let mut query = q.into();
         if let Some(db) = self.db.as_ref() {
             query = query.extra("db", db.to_string());
@@ -142,14 +213,22 @@ impl Txn {
                 Operation::Write => "w",
             },
         );
-        query
-            .execute_mut(self.fetch_size, &mut self.connection)
+        self.streams.drain(&mut self.connection).await?;
+        let stream = query
+            .write_execute(self.fetch_size, &mut self.connection)
+            .await?;
+        self.connection.flush().await?;
+        self.recv_begin().await?;
+        Query::recv_execute(stream, &mut self.connection)
             .await
+            .map(|stream| stream.in_transaction(&self.streams))
+            .map_err(Retry::into_inner)
     }
 
     /// Commits the transaction in progress
     #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
     pub async fn commit(mut self) -> Result<()> {
+        self.flush_begin().await?;
         let commit = BoltRequest::commit();
         match self.connection.send_recv(commit).await? {
             BoltResponse::Success(_) => Ok(()),
@@ -159,6 +238,7 @@ impl Txn {
 
     #[cfg(feature = "unstable-bolt-protocol-impl-v2")]
     pub async fn commit(mut self) -> Result<Option<String>> {
+        self.flush_begin().await?;
         match self.connection.send_recv_as(Commit).await? {
             Summary::Success(resp) => {
                 self.save_bookmark_state(&resp.metadata);
@@ -170,6 +250,7 @@ impl Txn {
 
     /// rollback/abort the current transaction
     pub async fn rollback(mut self) -> Result<()> {
+        self.flush_begin().await?;
         #[cfg(not(feature = "unstable-bolt-protocol-impl-v2"))]
         {
             let rollback = BoltRequest::rollback();
@@ -219,16 +300,25 @@ impl TransactionHandle for ManagedConnection {}
 impl<T: TransactionHandle> TransactionHandle for &mut T {}
 
 pub(crate) mod private {
-    use crate::{pool::ManagedConnection, Txn};
+    use crate::{pool::ManagedConnection, stream::OpenStreams, Txn};
 
     pub trait Handle {
         fn connection(&mut self) -> &mut ManagedConnection;
+
+        /// The connection together with the bookkeeping of the streams that share it, if any.
+        fn parts(&mut self) -> (&mut ManagedConnection, Option<&mut OpenStreams>) {
+            (self.connection(), None)
+        }
     }
 
     impl Handle for Txn {
         fn connection(&mut self) -> &mut ManagedConnection {
             &mut self.connection
         }
+
+        fn parts(&mut self) -> (&mut ManagedConnection, Option<&mut OpenStreams>) {
+            (&mut self.connection, Some(&mut self.streams))
+        }
     }
 
     impl Handle for ManagedConnection {
@@ -241,5 +331,56 @@ pub(crate) mod private {
         fn connection(&mut self) -> &mut ManagedConnection {
             (**self).connection()
         }
+
+        fn parts(&mut self) -> (&mut ManagedConnection, Option<&mut OpenStreams>) {
+            (**self).parts()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use crate::{
+        testing::{hello, Request, Response, StubServer},
+        ConfigBuilder, Graph,
+    };
+
+    #[tokio::test]
+    async fn should_pipeline_begin_unless_disabled() {
+        let not_found = || {
+            Response::failure(
+                "Neo.ClientError.Database.DatabaseNotFound",
+                "Database does not exist",
+            )
+        };
+        let script = hello()
+            .expect(Request::begin().with_extra([("db", "missing")]))
+            .expect(Request::run("RETURN 1"))
+            .expect(Request::discard())
+            .reply(not_found())
+            .reply(Response::ignored())
+            .reply(Response::ignored());
+        let server = StubServer::start(script).await.unwrap();
+        let graph = Graph::new(server.uri(), "neo4j", "neo").unwrap();
+
+        let mut txn = graph.start_txn_on("missing").await.unwrap();
+        assert!(txn.run("RETURN 1").await.is_err());
+        server.finish().await.unwrap();
+
+        let script = hello()
+            .expect(Request::begin().with_extra([("db", "missing")]))
+            .reply(not_found());
+        let server = StubServer::start(script).await.unwrap();
+        let config = ConfigBuilder::default()
+            .uri(server.uri())
+            .user("neo4j")
+            .password("neo")
+            .pipeline_begin(false)
+            .build()
+            .unwrap();
+        let graph = Graph::connect(config).unwrap();
+
+        assert!(graph.start_txn_on("missing").await.is_err());
+        server.finish().await.unwrap();
     }
 }
@@ -1,11 +1,17 @@
 use crate::errors::Error;
 use crate::types::*;
-use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, Timelike, Utc};
+use crate::version::Version;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use chrono::{
+    DateTime, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeDelta, TimeZone, Timelike, Utc,
+};
 use neo4rs_macros::BoltStruct;
 use std::convert::TryInto;
 
-#[derive(Debug, PartialEq, Eq, Clone, BoltStruct)]
-#[signature(0xB3, 0x46)]
+/// A date and time with a fixed offset.
+///
+/// The seconds are kept in local time, since Bolt 5 they are sent as UTC.
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct BoltDateTime {
     pub(crate) seconds: BoltInteger,
     pub(crate) nanoseconds: BoltInteger,
@@ -19,12 +25,103 @@ pub struct BoltLocalDateTime {
     pub(crate) nanoseconds: BoltInteger,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, BoltStruct)]
-#[signature(0xB3, 0x66)]
+/// A date and time in a time zone.
+///
+/// The seconds are kept in local time, since Bolt 5 they are sent as UTC.
+/// A local time can occur twice or not at all around a transition of the time zone,
+/// so the seconds in UTC are kept as well.
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct BoltDateTimeZoneId {
     pub(crate) seconds: BoltInteger,
     pub(crate) nanoseconds: BoltInteger,
     pub(crate) tz_id: BoltString,
+    /// The seconds in UTC, unless the local time could not be resolved in the time zone.
+    pub(crate) utc_seconds: Option<i64>,
+}
+
+impl BoltWireFormat for BoltDateTime {
+    fn can_parse(version: Version, input: &[u8]) -> bool {
+        let signature = if version >= Version::V5_0 { 0x49 } else { 0x46 };
+        input.len() >= 2 && input[0] == 0xB3 && input[1] == signature
+    }
+
+    fn parse(version: Version, input: &mut Bytes) -> Result<Self> {
+        input.advance(2);
+        let seconds = BoltInteger::parse(version, input)?;
+        let nanoseconds = BoltInteger::parse(version, input)?;
+        let tz_offset_seconds = BoltInteger::parse(version, input)?;
+        let seconds = if version >= Version::V5_0 {
+            seconds
+                .value
+                .checked_add(tz_offset_seconds.value)
+                .ok_or(Error::ConversionError)?
+                .into()
+        } else {
+            seconds
+        };
+        Ok(BoltDateTime {
+            seconds,
+            nanoseconds,
+            tz_offset_seconds,
+        })
+    }
+
+    fn write_into(&self, version: Version, bytes: &mut BytesMut) -> Result<()> {
+        bytes.reserve(2);
+        bytes.put_u8(0xB3);
+        if version >= Version::V5_0 {
+            bytes.put_u8(0x49);
+            let seconds = self.seconds.value.checked_sub(self.tz_offset_seconds.value);
+            BoltInteger::new(seconds.ok_or(Error::ConversionError)?).write_into(version, bytes)?;
+        } else {
+            bytes.put_u8(0x46);
+            self.seconds.write_into(version, bytes)?;
+        }
+        self.nanoseconds.write_into(version, bytes)?;
+        self.tz_offset_seconds.write_into(version, bytes)
+    }
+}
+
+impl BoltWireFormat for BoltDateTimeZoneId {
+    fn can_parse(version: Version, input: &[u8]) -> bool {
+        let signature = if version >= Version::V5_0 { 0x69 } else { 0x66 };
+        input.len() >= 2 && input[0] == 0xB3 && input[1] == signature
+    }
+
+    fn parse(version: Version, input: &mut Bytes) -> Result<Self> {
+        input.advance(2);
+        let seconds = BoltInteger::parse(version, input)?;
+        let nanoseconds = BoltInteger::parse(version, input)?;
+        let tz_id = BoltString::parse(version, input)?;
+        if version >= Version::V5_0 {
+            Ok(BoltDateTimeZoneId::from_utc(
+                seconds.value,
+                nanoseconds.value,
+                tz_id,
+            ))
+        } else {
+            Ok(BoltDateTimeZoneId::from_local(
+                seconds.value,
+                nanoseconds.value,
+                tz_id,
+            ))
+        }
+    }
+
+    fn write_into(&self, version: Version, bytes: &mut BytesMut) -> Result<()> {
+        bytes.reserve(2);
+        bytes.put_u8(0xB3);
+        if version >= Version::V5_0 {
+            bytes.put_u8(0x69);
+            let seconds = self.utc_seconds.ok_or(Error::ConversionError)?;
+            BoltInteger::new(seconds).write_into(version, bytes)?;
+        } else {
+            bytes.put_u8(0x66);
+            self.seconds.write_into(version, bytes)?;
+        }
+        self.nanoseconds.write_into(version, bytes)?;
+        self.tz_id.write_into(version, bytes)
+    }
 }
 
 impl BoltDateTime {
@@ -40,6 +137,43 @@ impl BoltLocalDateTime {
 }
 
 impl BoltDateTimeZoneId {
+    /// Creates a date time from the seconds of its local time.
+    ///
+    /// A local time that occurs twice is resolved to the earlier one,
+    /// a local time that is skipped is moved forward by the length of the gap,
+    /// the same way the server resolves them.
+    pub(crate) fn from_local(seconds: i64, nanoseconds: i64, tz_id: BoltString) -> Self {
+        let utc_seconds = tz_id
+            .value
+            .parse::<chrono_tz::Tz>()
+            .ok()
+            .and_then(|tz| resolve_local(tz, seconds));
+        BoltDateTimeZoneId {
+            seconds: seconds.into(),
+            nanoseconds: nanoseconds.into(),
+            tz_id,
+            utc_seconds,
+        }
+    }
+
+    /// Creates a date time from its seconds in UTC.
+    ///
+    /// The local time is the same as UTC if the time zone is unknown.
+    pub(crate) fn from_utc(utc_seconds: i64, nanoseconds: i64, tz_id: BoltString) -> Self {
+        let offset = tz_id
+            .value
+            .parse::<chrono_tz::Tz>()
+            .ok()
+            .and_then(|tz| tz.timestamp_opt(utc_seconds, 0).single())
+            .map_or(0, |dt| dt.offset().fix().local_minus_utc());
+        BoltDateTimeZoneId {
+            seconds: utc_seconds.saturating_add(offset.into()).into(),
+            nanoseconds: nanoseconds.into(),
+            tz_id,
+            utc_seconds: Some(utc_seconds),
+        }
+    }
+
     pub(crate) fn try_to_chrono(&self) -> Result<DateTime<FixedOffset>> {
         self.try_into()
     }
@@ -49,15 +183,25 @@ impl BoltDateTimeZoneId {
     }
 }
 
+/// The seconds in UTC of a local time in a time zone, see [`BoltDateTimeZoneId::from_local`].
+fn resolve_local(tz: chrono_tz::Tz, seconds: i64) -> Option<i64> {
+    let local = DateTime::from_timestamp(seconds, 0)?.naive_utc();
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Some(dt.timestamp()),
+        LocalResult::None => {
+            // the offset before the gap, transitions are much further apart than a day
+            let before = local.checked_sub_signed(TimeDelta::days(1))?;
+            let offset = tz.offset_from_utc_datetime(&before).fix();
+            seconds.checked_sub(offset.local_minus_utc().into())
+        }
+    }
+}
+
 impl From<(NaiveDateTime, &str)> for BoltDateTimeZoneId {
     fn from(value: (NaiveDateTime, &str)) -> Self {
-        let seconds = value.0.and_utc().timestamp().into();
-        let nanoseconds = (value.0.and_utc().timestamp_subsec_nanos() as i64).into();
-        BoltDateTimeZoneId {
-            seconds,
-            nanoseconds,
-            tz_id: value.1.into(),
-        }
+        let seconds = value.0.and_utc().timestamp();
+        let nanoseconds = value.0.and_utc().timestamp_subsec_nanos() as i64;
+        BoltDateTimeZoneId::from_local(seconds, nanoseconds, value.1.into())
     }
 }
 
@@ -91,11 +235,11 @@ impl TryFrom<&BoltDateTimeZoneId> for DateTime<FixedOffset> {
             .parse()
             .map_err(|_| Error::ConversionError)?;
 
-        let seconds = value.seconds.value;
+        let seconds = value.utc_seconds.ok_or(Error::ConversionError)?;
         let nanoseconds = value.nanoseconds.value as u32;
 
-        let dt = DateTime::from_timestamp(seconds, nanoseconds).ok_or(Error::ConversionError)?;
-        let dt = chrono::TimeZone::from_local_datetime(&tz, &dt.naive_utc())
+        let dt = tz
+            .timestamp_opt(seconds, nanoseconds)
             .single()
             .ok_or(Error::ConversionError)?;
         Ok(dt.fixed_offset())
@@ -321,4 +465,110 @@ mod tests {
         assert_eq!(datetime.to_string(), "2015-07-01 08:59:60.123");
         assert_eq!(zone_id, "Europe/Paris");
     }
+
+    #[test]
+    fn should_convert_datetimes_to_utc_since_bolt_5() {
+        let date: BoltDateTime = DateTime::parse_from_rfc2822("Wed, 24 Jun 2015 12:50:35 +0100")
+            .unwrap()
+            .into();
+        let bytes = date.clone().into_bytes(Version::V5_0).unwrap();
+        assert_eq!(
+            bytes,
+            Bytes::from_static(&[
+                0xB3, 0x49, 0xCA, 0x55, 0x8A, 0x99, 0x8B, 0x00, 0xC9, 0x0E, 0x10,
+            ])
+        );
+        assert!(!BoltDateTime::can_parse(Version::V4_4, &bytes));
+        assert_eq!(
+            BoltDateTime::parse(Version::V5_0, &mut bytes.clone()).unwrap(),
+            date
+        );
+    }
+
+    #[test]
+    fn should_convert_datetimes_with_zoneid_to_utc_since_bolt_5() {
+        let datetime =
+            NaiveDateTime::parse_from_str("2015-07-01 08:59:59.123", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap();
+        let date: BoltDateTimeZoneId = (datetime, "Europe/Paris").into();
+
+        let bytes = date.clone().into_bytes(Version::V5_0).unwrap();
+        let mut input = bytes.clone();
+        input.advance(2);
+        // 2015-07-01 06:59:59 UTC, Paris is 2 hours ahead in summer
+        assert_eq!(
+            BoltInteger::parse(Version::V5_0, &mut input).unwrap(),
+            BoltInteger::new(1_435_733_999)
+        );
+        assert!(!BoltDateTimeZoneId::can_parse(Version::V4_4, &bytes));
+        let parsed = BoltDateTimeZoneId::parse(Version::V5_0, &mut bytes.clone()).unwrap();
+        assert_eq!(parsed, date);
+        assert_eq!(
+            parsed.try_to_chrono().unwrap().to_rfc3339(),
+            "2015-07-01T08:59:59.123+02:00"
+        );
+    }
+
+    #[test]
+    fn should_keep_the_instant_of_a_local_time_that_occurs_twice() {
+        // 2024-10-27 01:30:00 UTC is the second 02:30 in Paris, after the clocks went back
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(0xB3);
+        bytes.put_u8(0x69);
+        BoltInteger::new(1_729_992_600)
+            .write_into(Version::V5_0, &mut bytes)
+            .unwrap();
+        BoltInteger::new(0)
+            .write_into(Version::V5_0, &mut bytes)
+            .unwrap();
+        BoltString::from("Europe/Paris")
+            .write_into(Version::V5_0, &mut bytes)
+            .unwrap();
+        let bytes = bytes.freeze();
+
+        let date = BoltDateTimeZoneId::parse(Version::V5_0, &mut bytes.clone()).unwrap();
+        assert_eq!(
+            date.try_to_chrono().unwrap().to_rfc3339(),
+            "2024-10-27T02:30:00+01:00"
+        );
+        assert_eq!(date.clone().into_bytes(Version::V5_0).unwrap(), bytes);
+
+        let earlier: BoltDateTimeZoneId = (
+            NaiveDateTime::parse_from_str("2024-10-27 02:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            "Europe/Paris",
+        )
+            .into();
+        assert_eq!(earlier.seconds, date.seconds);
+        assert_ne!(earlier, date);
+        assert_eq!(
+            earlier.try_to_chrono().unwrap().to_rfc3339(),
+            "2024-10-27T02:30:00+02:00"
+        );
+    }
+
+    #[test]
+    fn should_move_a_skipped_local_time_past_the_gap() {
+        // the clocks in Paris went from 02:00 to 03:00 on 2024-03-31
+        let datetime =
+            NaiveDateTime::parse_from_str("2024-03-31 02:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let date: BoltDateTimeZoneId = (datetime, "Europe/Paris").into();
+
+        let mut input = date.clone().into_bytes(Version::V5_0).unwrap();
+        input.advance(2);
+        assert_eq!(
+            BoltInteger::parse(Version::V5_0, &mut input).unwrap(),
+            BoltInteger::new(1_711_848_600)
+        );
+        assert_eq!(
+            date.try_to_chrono().unwrap().to_rfc3339(),
+            "2024-03-31T03:30:00+02:00"
+        );
+
+        let mut input = date.into_bytes(Version::V4_4).unwrap();
+        input.advance(2);
+        assert_eq!(
+            BoltInteger::parse(Version::V4_4, &mut input).unwrap(),
+            BoltInteger::new(datetime.and_utc().timestamp())
+        );
+    }
 }
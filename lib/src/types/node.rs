@@ -1,13 +1,24 @@
-use crate::types::{serde::DeError, BoltInteger, BoltList, BoltMap, BoltType, Result};
+use crate::{
+    types::{
+        serde::DeError, BoltInteger, BoltList, BoltMap, BoltNull, BoltString, BoltType,
+        BoltWireFormat, Result,
+    },
+    version::Version,
+};
 use ::serde::Deserialize;
-use neo4rs_macros::BoltStruct;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 
-#[derive(Debug, PartialEq, Clone, BoltStruct)]
-#[signature(0xB3, 0x4E)]
+const SIGNATURE: u8 = 0x4E;
+
+/// A node of the graph.
+///
+/// The element id is only sent by the server since Bolt 5.
+#[derive(Debug, PartialEq, Clone)]
 pub struct BoltNode {
     pub id: BoltInteger,
     pub labels: BoltList,
     pub properties: BoltMap,
+    pub(crate) element_id: Option<BoltString>,
 }
 
 impl BoltNode {
@@ -16,8 +27,14 @@ impl BoltNode {
             id,
             labels,
             properties,
+            element_id: None,
         }
     }
+
+    /// Element id of the node, only sent by the server since Bolt 5
+    pub fn element_id(&self) -> Option<&str> {
+        self.element_id.as_ref().map(|id| id.value.as_str())
+    }
 }
 
 impl BoltNode {
@@ -29,6 +46,64 @@ impl BoltNode {
     }
 }
 
+impl BoltWireFormat for BoltNode {
+    fn can_parse(version: Version, input: &[u8]) -> bool {
+        let marker = if version >= Version::V5_0 { 0xB4 } else { 0xB3 };
+        input.len() >= 2 && input[0] == marker && input[1] == SIGNATURE
+    }
+
+    fn parse(version: Version, input: &mut Bytes) -> Result<Self> {
+        input.advance(2);
+        let node = BoltNode {
+            id: BoltInteger::parse(version, input)?,
+            labels: BoltList::parse(version, input)?,
+            properties: BoltMap::parse(version, input)?,
+            element_id: parse_element_id(version, input)?,
+        };
+        Ok(node)
+    }
+
+    fn write_into(&self, version: Version, bytes: &mut BytesMut) -> Result<()> {
+        bytes.reserve(2);
+        if version >= Version::V5_0 {
+            bytes.put_u8(0xB4);
+        } else {
+            bytes.put_u8(0xB3);
+        }
+        bytes.put_u8(SIGNATURE);
+        self.id.write_into(version, bytes)?;
+        self.labels.write_into(version, bytes)?;
+        self.properties.write_into(version, bytes)?;
+        write_element_id(self.element_id.as_ref(), version, bytes)
+    }
+}
+
+/// Reads the element id that follows the fields of a node or relationship since Bolt 5.
+pub(super) fn parse_element_id(version: Version, input: &mut Bytes) -> Result<Option<BoltString>> {
+    if version < Version::V5_0 {
+        return Ok(None);
+    }
+    match BoltType::parse(version, input)? {
+        BoltType::String(element_id) => Ok(Some(element_id)),
+        _ => Ok(None),
+    }
+}
+
+/// Writes the element id of a node or relationship since Bolt 5, or null if it is not known.
+pub(super) fn write_element_id(
+    element_id: Option<&BoltString>,
+    version: Version,
+    bytes: &mut BytesMut,
+) -> Result<()> {
+    if version < Version::V5_0 {
+        return Ok(());
+    }
+    match element_id {
+        Some(element_id) => element_id.write_into(version, bytes),
+        None => BoltNull.write_into(version, bytes),
+    }
+}
+
 impl From<BoltNode> for BoltType {
     fn from(value: BoltNode) -> Self {
         BoltType::Node(value)
@@ -38,7 +113,6 @@ impl From<BoltNode> for BoltType {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{types::BoltWireFormat, version::Version};
     use bytes::Bytes;
 
     #[test]
@@ -67,6 +141,7 @@ mod tests {
             id,
             labels,
             properties,
+            element_id: None,
         };
 
         let bytes: Bytes = node.into_bytes(Version::V4_1).unwrap();
@@ -79,4 +154,37 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn should_deserialize_a_node_with_element_id() {
+        let mut input = Bytes::from_static(&[
+            0xB4, 0x4E, 0x13, 0x91, 0x86, 0x50, 0x65, 0x72, 0x73, 0x6F, 0x6E, 0xA1, 0x84, 0x6E,
+            0x61, 0x6D, 0x65, 0x84, 0x4D, 0x61, 0x72, 0x6B, 0x84, 0x34, 0x3A, 0x31, 0x39,
+        ]);
+
+        assert!(!BoltNode::can_parse(Version::V4_4, &input));
+        let node: BoltNode = BoltNode::parse(Version::V5_0, &mut input).unwrap();
+
+        assert_eq!(node.id, BoltInteger::new(19));
+        assert_eq!(node.labels, vec!["Person".into()].into());
+        assert_eq!(node.element_id(), Some("4:19"));
+        assert!(input.is_empty());
+        assert_eq!(
+            node.into_bytes(Version::V5_0).unwrap(),
+            Bytes::from_static(&[
+                0xB4, 0x4E, 0x13, 0x91, 0x86, 0x50, 0x65, 0x72, 0x73, 0x6F, 0x6E, 0xA1, 0x84, 0x6E,
+                0x61, 0x6D, 0x65, 0x84, 0x4D, 0x61, 0x72, 0x6B, 0x84, 0x34, 0x3A, 0x31, 0x39,
+            ])
+        );
+    }
+
+    #[test]
+    fn should_serialize_a_node_without_element_id_since_bolt_5() {
+        let node = BoltNode::new(BoltInteger::new(19), BoltList::new(), BoltMap::new());
+
+        assert_eq!(
+            node.into_bytes(Version::V5_0).unwrap(),
+            Bytes::from_static(&[0xB4, 0x4E, 0x13, 0x90, 0xA0, 0xC0])
+        );
+    }
 }
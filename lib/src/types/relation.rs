@@ -1,23 +1,81 @@
-use crate::types::{serde::DeError, BoltInteger, BoltMap, BoltString, BoltType, Result};
+use crate::{
+    types::{
+        node::{parse_element_id, write_element_id},
+        serde::DeError,
+        BoltInteger, BoltMap, BoltString, BoltType, BoltWireFormat, Result,
+    },
+    version::Version,
+};
 use ::serde::Deserialize;
-use neo4rs_macros::BoltStruct;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 
-#[derive(Debug, PartialEq, Clone, BoltStruct)]
-#[signature(0xB5, 0x52)]
+const SIGNATURE: u8 = 0x52;
+const UNBOUNDED_SIGNATURE: u8 = 0x72;
+
+/// A relationship of the graph.
+///
+/// The element ids of the relationship and its nodes are only sent by the server since Bolt 5.
+#[derive(Debug, PartialEq, Clone)]
 pub struct BoltRelation {
     pub id: BoltInteger,
     pub start_node_id: BoltInteger,
     pub end_node_id: BoltInteger,
     pub typ: BoltString,
     pub properties: BoltMap,
+    pub(crate) element_id: Option<BoltString>,
+    pub(crate) start_node_element_id: Option<BoltString>,
+    pub(crate) end_node_element_id: Option<BoltString>,
 }
 
-#[derive(Debug, PartialEq, Clone, BoltStruct)]
-#[signature(0xB3, 0x72)]
+/// A relationship of a path, without its nodes.
+///
+/// The element id is only sent by the server since Bolt 5.
+#[derive(Debug, PartialEq, Clone)]
 pub struct BoltUnboundedRelation {
     pub id: BoltInteger,
     pub typ: BoltString,
     pub properties: BoltMap,
+    pub(crate) element_id: Option<BoltString>,
+}
+
+impl BoltRelation {
+    pub fn new(
+        id: BoltInteger,
+        start_node_id: BoltInteger,
+        end_node_id: BoltInteger,
+        typ: BoltString,
+        properties: BoltMap,
+    ) -> Self {
+        BoltRelation {
+            id,
+            start_node_id,
+            end_node_id,
+            typ,
+            properties,
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
+        }
+    }
+
+    /// Element id of the relationship, only sent by the server since Bolt 5
+    pub fn element_id(&self) -> Option<&str> {
+        self.element_id.as_ref().map(|id| id.value.as_str())
+    }
+
+    /// Element id of the start node, only sent by the server since Bolt 5
+    pub fn start_node_element_id(&self) -> Option<&str> {
+        self.start_node_element_id
+            .as_ref()
+            .map(|id| id.value.as_str())
+    }
+
+    /// Element id of the end node, only sent by the server since Bolt 5
+    pub fn end_node_element_id(&self) -> Option<&str> {
+        self.end_node_element_id
+            .as_ref()
+            .map(|id| id.value.as_str())
+    }
 }
 
 impl BoltUnboundedRelation {
@@ -26,8 +84,14 @@ impl BoltUnboundedRelation {
             id,
             typ,
             properties,
+            element_id: None,
         }
     }
+
+    /// Element id of the relationship, only sent by the server since Bolt 5
+    pub fn element_id(&self) -> Option<&str> {
+        self.element_id.as_ref().map(|id| id.value.as_str())
+    }
 }
 
 impl BoltRelation {
@@ -48,6 +112,78 @@ impl BoltUnboundedRelation {
     }
 }
 
+impl BoltWireFormat for BoltRelation {
+    fn can_parse(version: Version, input: &[u8]) -> bool {
+        let marker = if version >= Version::V5_0 { 0xB8 } else { 0xB5 };
+        input.len() >= 2 && input[0] == marker && input[1] == SIGNATURE
+    }
+
+    fn parse(version: Version, input: &mut Bytes) -> Result<Self> {
+        input.advance(2);
+        let relation = BoltRelation {
+            id: BoltInteger::parse(version, input)?,
+            start_node_id: BoltInteger::parse(version, input)?,
+            end_node_id: BoltInteger::parse(version, input)?,
+            typ: BoltString::parse(version, input)?,
+            properties: BoltMap::parse(version, input)?,
+            element_id: parse_element_id(version, input)?,
+            start_node_element_id: parse_element_id(version, input)?,
+            end_node_element_id: parse_element_id(version, input)?,
+        };
+        Ok(relation)
+    }
+
+    fn write_into(&self, version: Version, bytes: &mut BytesMut) -> Result<()> {
+        bytes.reserve(2);
+        if version >= Version::V5_0 {
+            bytes.put_u8(0xB8);
+        } else {
+            bytes.put_u8(0xB5);
+        }
+        bytes.put_u8(SIGNATURE);
+        self.id.write_into(version, bytes)?;
+        self.start_node_id.write_into(version, bytes)?;
+        self.end_node_id.write_into(version, bytes)?;
+        self.typ.write_into(version, bytes)?;
+        self.properties.write_into(version, bytes)?;
+        write_element_id(self.element_id.as_ref(), version, bytes)?;
+        write_element_id(self.start_node_element_id.as_ref(), version, bytes)?;
+        write_element_id(self.end_node_element_id.as_ref(), version, bytes)
+    }
+}
+
+impl BoltWireFormat for BoltUnboundedRelation {
+    fn can_parse(version: Version, input: &[u8]) -> bool {
+        let marker = if version >= Version::V5_0 { 0xB4 } else { 0xB3 };
+        input.len() >= 2 && input[0] == marker && input[1] == UNBOUNDED_SIGNATURE
+    }
+
+    fn parse(version: Version, input: &mut Bytes) -> Result<Self> {
+        input.advance(2);
+        let relation = BoltUnboundedRelation {
+            id: BoltInteger::parse(version, input)?,
+            typ: BoltString::parse(version, input)?,
+            properties: BoltMap::parse(version, input)?,
+            element_id: parse_element_id(version, input)?,
+        };
+        Ok(relation)
+    }
+
+    fn write_into(&self, version: Version, bytes: &mut BytesMut) -> Result<()> {
+        bytes.reserve(2);
+        if version >= Version::V5_0 {
+            bytes.put_u8(0xB4);
+        } else {
+            bytes.put_u8(0xB3);
+        }
+        bytes.put_u8(UNBOUNDED_SIGNATURE);
+        self.id.write_into(version, bytes)?;
+        self.typ.write_into(version, bytes)?;
+        self.properties.write_into(version, bytes)?;
+        write_element_id(self.element_id.as_ref(), version, bytes)
+    }
+}
+
 impl From<BoltRelation> for BoltType {
     fn from(value: BoltRelation) -> Self {
         BoltType::Relation(value)
@@ -63,7 +199,6 @@ impl From<BoltUnboundedRelation> for BoltType {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{types::BoltWireFormat, Version};
     use bytes::Bytes;
 
     #[test]
@@ -80,6 +215,9 @@ mod tests {
             end_node_id,
             typ,
             properties,
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
         };
 
         let bytes: Bytes = relation.into_bytes(Version::V4_1).unwrap();
@@ -147,4 +285,33 @@ mod tests {
             vec![("name".into(), "Mark".into())].into_iter().collect()
         );
     }
+
+    #[test]
+    fn should_deserialize_relations_with_element_ids() {
+        let bytes = Bytes::from_static(&[
+            0xB8, 0x52, 0x2A, 0x01, 0x02, 0x83, 0x72, 0x65, 0x6C, 0xA0, 0x84, 0x35, 0x3A, 0x34,
+            0x32, 0x83, 0x34, 0x3A, 0x31, 0x83, 0x34, 0x3A, 0x32,
+        ]);
+        assert!(!BoltRelation::can_parse(Version::V4_4, &bytes));
+        let mut input = bytes.clone();
+        let relation = BoltRelation::parse(Version::V5_0, &mut input).unwrap();
+        assert_eq!(relation.id, BoltInteger::new(42));
+        assert_eq!(relation.end_node_id, BoltInteger::new(2));
+        assert_eq!(relation.element_id(), Some("5:42"));
+        assert_eq!(relation.start_node_element_id(), Some("4:1"));
+        assert_eq!(relation.end_node_element_id(), Some("4:2"));
+        assert!(input.is_empty());
+        assert_eq!(relation.into_bytes(Version::V5_0).unwrap(), bytes);
+
+        let bytes = Bytes::from_static(&[
+            0xB4, 0x72, 0x2A, 0x83, 0x72, 0x65, 0x6C, 0xA0, 0x84, 0x35, 0x3A, 0x34, 0x32,
+        ]);
+        assert!(!BoltUnboundedRelation::can_parse(Version::V4_4, &bytes));
+        let mut input = bytes.clone();
+        let unbounded = BoltUnboundedRelation::parse(Version::V5_0, &mut input).unwrap();
+        assert_eq!(unbounded.typ, BoltString::new("rel"));
+        assert_eq!(unbounded.element_id(), Some("5:42"));
+        assert!(input.is_empty());
+        assert_eq!(unbounded.into_bytes(Version::V5_0).unwrap(), bytes);
+    }
 }
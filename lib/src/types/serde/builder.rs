@@ -19,6 +19,7 @@ impl BoltNodeBuilder {
             pub fn id<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltInteger, E>) -> Result<(), E>;
             pub fn labels<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltList, E>) -> Result<(), E>;
             pub fn properties<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltMap, E>) -> Result<(), E>;
+            pub fn element_id<E: Error>(&mut self, read: impl FnOnce() -> Result<Option<BoltString>, E>) -> Result<(), E>;
         }
     }
 
@@ -29,11 +30,13 @@ impl BoltNodeBuilder {
             .labels
             .ok_or_else(|| Error::missing_field("labels"))?;
         let properties = self.inner.properties.or_else(Default::default);
+        let element_id = self.inner.element_id.or_else(Default::default);
 
         Ok(BoltNode {
             id,
             labels,
             properties,
+            element_id,
         })
     }
 }
@@ -51,6 +54,9 @@ impl BoltRelationBuilder {
             pub fn end_node_id<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltInteger, E>) -> Result<(), E>;
             pub fn typ<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltString, E>) -> Result<(), E>;
             pub fn properties<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltMap, E>) -> Result<(), E>;
+            pub fn element_id<E: Error>(&mut self, read: impl FnOnce() -> Result<Option<BoltString>, E>) -> Result<(), E>;
+            pub fn start_node_element_id<E: Error>(&mut self, read: impl FnOnce() -> Result<Option<BoltString>, E>) -> Result<(), E>;
+            pub fn end_node_element_id<E: Error>(&mut self, read: impl FnOnce() -> Result<Option<BoltString>, E>) -> Result<(), E>;
         }
     }
 
@@ -66,6 +72,9 @@ impl BoltRelationBuilder {
             .ok_or_else(|| Error::missing_field("end_node_id"))?;
         let typ = self.inner.typ.ok_or_else(|| Error::missing_field("type"))?;
         let properties = self.inner.properties.or_else(Default::default);
+        let element_id = self.inner.element_id.or_else(Default::default);
+        let start_node_element_id = self.inner.start_node_element_id.or_else(Default::default);
+        let end_node_element_id = self.inner.end_node_element_id.or_else(Default::default);
 
         Ok(BoltRelation {
             id,
@@ -73,6 +82,9 @@ impl BoltRelationBuilder {
             end_node_id,
             typ,
             properties,
+            element_id,
+            start_node_element_id,
+            end_node_element_id,
         })
     }
 }
@@ -88,6 +100,7 @@ impl BoltUnboundedRelationBuilder {
             pub fn id<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltInteger, E>) -> Result<(), E>;
             pub fn typ<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltString, E>) -> Result<(), E>;
             pub fn properties<E: Error>(&mut self, read: impl FnOnce() -> Result<BoltMap, E>) -> Result<(), E>;
+            pub fn element_id<E: Error>(&mut self, read: impl FnOnce() -> Result<Option<BoltString>, E>) -> Result<(), E>;
         }
     }
 
@@ -95,11 +108,13 @@ impl BoltUnboundedRelationBuilder {
         let id = self.inner.id.ok_or_else(|| Error::missing_field("id"))?;
         let typ = self.inner.typ.ok_or_else(|| Error::missing_field("type"))?;
         let properties = self.inner.properties.or_else(Default::default);
+        let element_id = self.inner.element_id.or_else(Default::default);
 
         Ok(BoltUnboundedRelation {
             id,
             typ,
             properties,
+            element_id,
         })
     }
 }
@@ -143,6 +158,9 @@ impl BoltPathBuilder {
 pub struct Id(pub BoltInteger);
 pub struct StartNodeId(pub BoltInteger);
 pub struct EndNodeId(pub BoltInteger);
+pub struct ElementId(pub Option<BoltString>);
+pub struct StartNodeElementId(pub Option<BoltString>);
+pub struct EndNodeElementId(pub Option<BoltString>);
 
 impl<'de> Deserialize<'de> for Id {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -172,6 +190,58 @@ impl<'de> Deserialize<'de> for EndNodeId {
     }
 }
 
+/// Reads an element id that is missing before Bolt 5.
+fn element_id<'de, D>(deserializer: D, name: &'static str) -> Result<Option<BoltString>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct Visitor;
+
+    impl<'de> serde::de::Visitor<'de> for Visitor {
+        type Value = Option<BoltString>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("an element id")
+        }
+
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer).map(|id| id.map(BoltString::from))
+        }
+    }
+
+    deserializer.deserialize_newtype_struct(name, Visitor)
+}
+
+impl<'de> Deserialize<'de> for ElementId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        element_id(deserializer, "ElementId").map(ElementId)
+    }
+}
+
+impl<'de> Deserialize<'de> for StartNodeElementId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        element_id(deserializer, "StartNodeElementId").map(StartNodeElementId)
+    }
+}
+
+impl<'de> Deserialize<'de> for EndNodeElementId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        element_id(deserializer, "EndNodeElementId").map(EndNodeElementId)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 struct ElementBuilder {
     id: SetOnce<BoltInteger>,
@@ -183,6 +253,9 @@ struct ElementBuilder {
     nodes: SetOnce<BoltList>,
     rels: SetOnce<BoltList>,
     indices: SetOnce<BoltList>,
+    element_id: SetOnce<Option<BoltString>>,
+    start_node_element_id: SetOnce<Option<BoltString>>,
+    end_node_element_id: SetOnce<Option<BoltString>>,
 }
 
 impl ElementBuilder {
@@ -254,6 +327,36 @@ impl ElementBuilder {
             Err(_) => Err(Error::duplicate_field("indices")),
         }
     }
+
+    fn element_id<E: Error>(
+        &mut self,
+        read: impl FnOnce() -> Result<Option<BoltString>, E>,
+    ) -> Result<(), E> {
+        match self.element_id.try_insert_with(read)? {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::duplicate_field("element_id")),
+        }
+    }
+
+    fn start_node_element_id<E: Error>(
+        &mut self,
+        read: impl FnOnce() -> Result<Option<BoltString>, E>,
+    ) -> Result<(), E> {
+        match self.start_node_element_id.try_insert_with(read)? {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::duplicate_field("start_node_element_id")),
+        }
+    }
+
+    fn end_node_element_id<E: Error>(
+        &mut self,
+        read: impl FnOnce() -> Result<Option<BoltString>, E>,
+    ) -> Result<(), E> {
+        match self.end_node_element_id.try_insert_with(read)? {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Error::duplicate_field("end_node_element_id")),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Default)]
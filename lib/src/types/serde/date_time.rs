@@ -1,8 +1,9 @@
 use core::fmt;
 use std::{iter::Peekable, marker::PhantomData};
 
+use chrono::{Datelike, NaiveDateTime, Offset, Timelike};
 use serde::de::{
-    value::{BorrowedStrDeserializer, MapDeserializer},
+    value::{BorrowedStrDeserializer, MapDeserializer, SeqDeserializer},
     DeserializeSeed, Error, IntoDeserializer, MapAccess, SeqAccess, Visitor,
 };
 
@@ -35,6 +36,18 @@ impl BoltDateTime {
             .into_iter(),
         )
     }
+
+    /// Components of the local date time, and optionally its offset, see [`datetime_components`].
+    pub(crate) fn components_seq_access(
+        &self,
+        with_offset: bool,
+    ) -> Result<impl SeqAccess<'_, Error = DeError>, DeError> {
+        let datetime = self
+            .try_to_chrono()
+            .map_err(|_| Error::custom("Could not convert Neo4j DateTime into a date time"))?;
+        let offset = with_offset.then(|| datetime.offset().fix().local_minus_utc());
+        Ok(datetime_components(datetime.naive_local(), offset))
+    }
 }
 
 impl BoltLocalDateTime {
@@ -47,6 +60,16 @@ impl BoltLocalDateTime {
             .into_iter(),
         )
     }
+
+    /// Components of the local date time, see [`datetime_components`].
+    pub(crate) fn components_seq_access(
+        &self,
+    ) -> Result<impl SeqAccess<'_, Error = DeError>, DeError> {
+        let datetime = self
+            .try_to_chrono()
+            .map_err(|_| Error::custom("Could not convert Neo4j LocalDateTime into a date time"))?;
+        Ok(datetime_components(datetime, None))
+    }
 }
 
 impl BoltDateTimeZoneId {
@@ -57,6 +80,40 @@ impl BoltDateTimeZoneId {
     pub(crate) fn map_access(&self) -> impl MapAccess<'_, Error = DeError> {
         BoltDateTimeZoneIdAccess::fields(self)
     }
+
+    /// Components of the local date time, and optionally its offset, see [`datetime_components`].
+    pub(crate) fn components_seq_access(
+        &self,
+        with_offset: bool,
+    ) -> Result<impl SeqAccess<'_, Error = DeError>, DeError> {
+        let datetime = self.try_to_chrono().map_err(|_| {
+            Error::custom("Could not convert Neo4j DateTimeZoneId into a date time")
+        })?;
+        let offset = with_offset.then(|| datetime.offset().fix().local_minus_utc());
+        Ok(datetime_components(datetime.naive_local(), offset))
+    }
+}
+
+/// The year, day of the year, hour, minute, second, and nanosecond of a date time,
+/// optionally followed by the hours, minutes, and seconds of the offset.
+///
+/// This is the compact representation that is used by the serde implementations of the `time` crate.
+fn datetime_components(
+    datetime: NaiveDateTime,
+    offset_seconds: Option<i32>,
+) -> SeqDeserializer<std::vec::IntoIter<i64>, DeError> {
+    let mut components = vec![
+        i64::from(datetime.year()),
+        i64::from(datetime.ordinal()),
+        i64::from(datetime.hour()),
+        i64::from(datetime.minute()),
+        i64::from(datetime.second()),
+        i64::from(datetime.nanosecond()),
+    ];
+    if let Some(offset) = offset_seconds.map(i64::from) {
+        components.extend([offset / 3600, offset % 3600 / 60, offset % 60]);
+    }
+    SeqDeserializer::new(components.into_iter())
 }
 
 struct BoltDateTimeZoneIdAccess<'a, const N: usize>(
@@ -320,19 +377,22 @@ impl DateTimeIsh for BoltLocalDateTime {
 
 impl DateTimeIsh for BoltDateTimeZoneId {
     fn build<E: Error>(builder: &mut DateTimeIshBuilder) -> Result<Self, E> {
-        Ok(BoltDateTimeZoneId {
-            seconds: builder
-                .seconds
-                .take()
-                .ok_or_else(|| Error::missing_field("seconds"))?,
-            nanoseconds: builder
-                .nanoseconds
-                .take()
-                .ok_or_else(|| Error::missing_field("nanoseconds"))?,
-            tz_id: builder
-                .tz_id
-                .take()
-                .ok_or_else(|| Error::missing_field("tz_id"))?,
-        })
+        let seconds = builder
+            .seconds
+            .take()
+            .ok_or_else(|| Error::missing_field("seconds"))?;
+        let nanoseconds = builder
+            .nanoseconds
+            .take()
+            .ok_or_else(|| Error::missing_field("nanoseconds"))?;
+        let tz_id = builder
+            .tz_id
+            .take()
+            .ok_or_else(|| Error::missing_field("tz_id"))?;
+        Ok(BoltDateTimeZoneId::from_local(
+            seconds.value,
+            nanoseconds.value,
+            tz_id,
+        ))
     }
 }
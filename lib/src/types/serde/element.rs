@@ -4,7 +4,7 @@ use crate::types::{
     BoltUnboundedRelation,
 };
 
-use std::{iter, marker::PhantomData, result::Result};
+use std::{array, iter, marker::PhantomData, result::Result};
 
 use delegate::delegate;
 use serde::{
@@ -34,6 +34,9 @@ crate::cenum!(ElementDataKey {
     Nodes,
     Relationships,
     Indices,
+    ElementId,
+    StartNodeElementId,
+    EndNodeElementId,
 });
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -180,6 +183,18 @@ impl<'de, T: ElementData<'de>> ElementDataDeserializer<'de, T> {
                 };
                 visitation.visit(IterDeserializer(ids.iter()), visitor)
             }
+            "ElementId" | "StartNodeElementId" | "EndNodeElementId" => {
+                let key = match name {
+                    "ElementId" => ElementDataKey::ElementId,
+                    "StartNodeElementId" => ElementDataKey::StartNodeElementId,
+                    _ => ElementDataKey::EndNodeElementId,
+                };
+                let id = match self.data.value(key) {
+                    Some(ElementDataValue::Str(BoltString { value: id })) => Some(id.as_str()),
+                    _ => None,
+                };
+                visitation.visit(ElementIdDeserializer(id, key.name()), visitor)
+            }
             _ => Err(DeError::invalid_type(
                 Unexpected::Other(&format!("struct `{name}`")),
                 &concat!(
@@ -487,6 +502,47 @@ impl<'de> Deserializer<'de> for NoEnumBorrowedStrDeserializer<'de> {
     }
 }
 
+/// An element id, which is only sent by the server since Bolt 5.
+struct ElementIdDeserializer<'de>(Option<&'de str>, &'static str);
+
+impl<'de> Deserializer<'de> for ElementIdDeserializer<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Some(id) => visitor.visit_borrowed_str(id),
+            None => Err(DeError::missing_field(self.1)),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Some(id) => visitor.visit_some(BorrowedStrDeserializer::new(id)),
+            None => visitor.visit_none(),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any enum
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for ElementIdDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
 struct BorrowedStr<'de>(&'de str);
 
 impl<'de> IntoDeserializer<'de, DeError> for BorrowedStr<'de> {
@@ -561,17 +617,32 @@ impl<'de, T: ElementData<'de>> IntoDeserializer<'de, DeError> for AdditionalData
     }
 }
 
+/// The fields of an element, followed by those of its element ids that are known.
+type ElementIdItems<'de, const N: usize, const M: usize> = iter::Chain<
+    array::IntoIter<(ElementDataKey, ElementDataValue<'de>), N>,
+    iter::Flatten<array::IntoIter<Option<(ElementDataKey, ElementDataValue<'de>)>, M>>,
+>;
+
+fn element_ids<'de, const M: usize>(
+    ids: [(ElementDataKey, &'de Option<BoltString>); M],
+) -> iter::Flatten<array::IntoIter<Option<(ElementDataKey, ElementDataValue<'de>)>, M>> {
+    ids.map(|(key, id)| id.as_ref().map(|id| (key, ElementDataValue::Str(id))))
+        .into_iter()
+        .flatten()
+}
+
 impl<'de> ElementData<'de> for &'de BoltNode {
     fn value(self, key: ElementDataKey) -> Option<ElementDataValue<'de>> {
         match key {
             ElementDataKey::Id => Some(ElementDataValue::Int(&self.id)),
             ElementDataKey::Labels => Some(ElementDataValue::Lst(&self.labels)),
             ElementDataKey::Properties => Some(ElementDataValue::Map(&self.properties)),
+            ElementDataKey::ElementId => self.element_id.as_ref().map(ElementDataValue::Str),
             _ => None,
         }
     }
 
-    type Items = [(ElementDataKey, ElementDataValue<'de>); 3];
+    type Items = ElementIdItems<'de, 3, 1>;
 
     fn items(self) -> Self::Items {
         [
@@ -582,6 +653,8 @@ impl<'de> ElementData<'de> for &'de BoltNode {
                 ElementDataValue::Map(&self.properties),
             ),
         ]
+        .into_iter()
+        .chain(element_ids([(ElementDataKey::ElementId, &self.element_id)]))
     }
 }
 
@@ -593,11 +666,19 @@ impl<'de> ElementData<'de> for &'de BoltRelation {
             ElementDataKey::EndNodeId => Some(ElementDataValue::Int(&self.end_node_id)),
             ElementDataKey::Type => Some(ElementDataValue::Str(&self.typ)),
             ElementDataKey::Properties => Some(ElementDataValue::Map(&self.properties)),
+            ElementDataKey::ElementId => self.element_id.as_ref().map(ElementDataValue::Str),
+            ElementDataKey::StartNodeElementId => self
+                .start_node_element_id
+                .as_ref()
+                .map(ElementDataValue::Str),
+            ElementDataKey::EndNodeElementId => {
+                self.end_node_element_id.as_ref().map(ElementDataValue::Str)
+            }
             _ => None,
         }
     }
 
-    type Items = [(ElementDataKey, ElementDataValue<'de>); 5];
+    type Items = ElementIdItems<'de, 5, 3>;
 
     fn items(self) -> Self::Items {
         [
@@ -616,6 +697,15 @@ impl<'de> ElementData<'de> for &'de BoltRelation {
                 ElementDataValue::Map(&self.properties),
             ),
         ]
+        .into_iter()
+        .chain(element_ids([
+            (ElementDataKey::ElementId, &self.element_id),
+            (
+                ElementDataKey::StartNodeElementId,
+                &self.start_node_element_id,
+            ),
+            (ElementDataKey::EndNodeElementId, &self.end_node_element_id),
+        ]))
     }
 }
 
@@ -625,11 +715,12 @@ impl<'de> ElementData<'de> for &'de BoltUnboundedRelation {
             ElementDataKey::Id => Some(ElementDataValue::Int(&self.id)),
             ElementDataKey::Type => Some(ElementDataValue::Str(&self.typ)),
             ElementDataKey::Properties => Some(ElementDataValue::Map(&self.properties)),
+            ElementDataKey::ElementId => self.element_id.as_ref().map(ElementDataValue::Str),
             _ => None,
         }
     }
 
-    type Items = [(ElementDataKey, ElementDataValue<'de>); 3];
+    type Items = ElementIdItems<'de, 3, 1>;
 
     fn items(self) -> Self::Items {
         [
@@ -640,6 +731,8 @@ impl<'de> ElementData<'de> for &'de BoltUnboundedRelation {
                 ElementDataValue::Map(&self.properties),
             ),
         ]
+        .into_iter()
+        .chain(element_ids([(ElementDataKey::ElementId, &self.element_id)]))
     }
 }
 
@@ -711,7 +804,7 @@ mod tests {
         assert_eq!(node.value(ElementDataKey::Relationships), None);
         assert_eq!(node.value(ElementDataKey::Indices), None);
 
-        let mut items = node.items().into_iter();
+        let mut items = node.items();
         assert_eq!(
             items.next(),
             Some((
@@ -744,6 +837,9 @@ mod tests {
             end_node_id: BoltInteger::new(2),
             typ: BoltString::from("KNOWS"),
             properties: [("since".into(), 2017.into())].into_iter().collect(),
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
         };
 
         assert_eq!(
@@ -773,7 +869,7 @@ mod tests {
         assert_eq!(rel.value(ElementDataKey::Relationships), None);
         assert_eq!(rel.value(ElementDataKey::Indices), None);
 
-        let mut items = rel.items().into_iter();
+        let mut items = rel.items();
         assert_eq!(
             items.next(),
             Some((
@@ -838,7 +934,7 @@ mod tests {
         assert_eq!(unbounded_rel.value(ElementDataKey::EndNodeId), None);
         assert_eq!(unbounded_rel.value(ElementDataKey::Labels), None);
 
-        let mut items = unbounded_rel.items().into_iter();
+        let mut items = unbounded_rel.items();
         assert_eq!(
             items.next(),
             Some((
@@ -973,6 +1069,9 @@ mod tests {
             end_node_id: BoltInteger::new(2),
             typ: BoltString::from("KNOWS"),
             properties: [("since".into(), 2017.into())].into_iter().collect(),
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
         };
 
         let id = Id::deserialize(ElementDataDeserializer::new(&rel)).unwrap();
@@ -1074,6 +1173,9 @@ mod tests {
             end_node_id: BoltInteger::new(2),
             typ: BoltString::from("KNOWS"),
             properties: [("since".into(), 2017.into())].into_iter().collect(),
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
         };
 
         let knows = HashMap::<ElementDataKey, BoltType>::deserialize(MapAccessDeserializer::new(
@@ -1,7 +1,7 @@
 use crate::{
     types::{
         serde::{
-            builder::{BoltNodeBuilder, Id},
+            builder::{BoltNodeBuilder, ElementId, Id},
             element::{ElementDataDeserializer, ElementDataKey},
             BoltKind, Properties,
         },
@@ -46,8 +46,9 @@ impl<'de> Deserialize<'de> for BoltNode {
         const ID: &str = "42.<id>";
         const LABELS: &str = "42.<labels>";
         const PROPERTIES: &str = "42.<properties>";
+        const ELEMENT_ID: &str = "42.<element_id>";
 
-        const FIELDS: &[&str] = &[ID, LABELS, PROPERTIES];
+        const FIELDS: &[&str] = &[ID, LABELS, PROPERTIES, ELEMENT_ID];
 
         struct BoltNodeVisitor;
 
@@ -72,6 +73,9 @@ impl<'de> Deserialize<'de> for BoltNode {
                         }
                         PROPERTIES => builder
                             .properties(|| map.next_value::<Properties<BoltMap>>().map(|l| l.0))?,
+                        ELEMENT_ID => {
+                            builder.element_id(|| map.next_value::<ElementId>().map(|i| i.0))?
+                        }
                         otherwise => Err(Error::unknown_field(otherwise, FIELDS))?,
                     }
                 }
@@ -105,6 +109,7 @@ impl<'de> Visitor<'de> for BoltNodeVisitor {
                 ElementDataKey::Id => builder.id(|| map.next_value())?,
                 ElementDataKey::Labels => builder.labels(|| map.next_value())?,
                 ElementDataKey::Properties => builder.properties(|| map.next_value())?,
+                ElementDataKey::ElementId => builder.element_id(|| map.next_value().map(Some))?,
                 otherwise => {
                     return Err(Error::unknown_field(
                         otherwise.name(),
@@ -247,6 +252,7 @@ mod tests {
             id,
             labels,
             properties,
+            element_id: None,
         }
     }
 
@@ -573,4 +579,17 @@ mod tests {
                 .tap_mut(|v| v.sort_unstable())
         );
     }
+
+    #[test]
+    fn node_with_element_id() {
+        let node = BoltNode {
+            element_id: Some("4:db:1337".into()),
+            ..test_node()
+        };
+
+        assert_eq!(node.to::<BoltType>().unwrap(), BoltType::Node(node.clone()));
+        assert_eq!(node.to::<BoltNode>().unwrap(), node);
+        assert_eq!(node.to::<Node>().unwrap().element_id(), Some("4:db:1337"));
+        assert_eq!(test_node().to::<Node>().unwrap().element_id(), None);
+    }
 }
@@ -1,7 +1,10 @@
 use crate::{
     types::{
         serde::{
-            builder::{BoltRelationBuilder, EndNodeId, Id, StartNodeId},
+            builder::{
+                BoltRelationBuilder, ElementId, EndNodeElementId, EndNodeId, Id,
+                StartNodeElementId, StartNodeId,
+            },
             element::{ElementDataDeserializer, ElementDataKey},
             BoltKind, Properties,
         },
@@ -48,8 +51,20 @@ impl<'de> Deserialize<'de> for BoltRelation {
         const EID: &str = "42.<end_node_id>";
         const TYP: &str = "42.<type>";
         const PROPS: &str = "42.<properties>";
-
-        const FIELDS: &[&str] = &[ID, SID, EID, TYP, PROPS];
+        const ELEMENT_ID: &str = "42.<element_id>";
+        const START_ELEMENT_ID: &str = "42.<start_node_element_id>";
+        const END_ELEMENT_ID: &str = "42.<end_node_element_id>";
+
+        const FIELDS: &[&str] = &[
+            ID,
+            SID,
+            EID,
+            TYP,
+            PROPS,
+            ELEMENT_ID,
+            START_ELEMENT_ID,
+            END_ELEMENT_ID,
+        ];
 
         struct BoltRelationVisitor;
 
@@ -77,6 +92,15 @@ impl<'de> Deserialize<'de> for BoltRelation {
                         TYP => builder.typ(|| map.next_value::<Type<BoltString>>().map(|t| t.0))?,
                         PROPS => builder
                             .properties(|| map.next_value::<Properties<BoltMap>>().map(|t| t.0))?,
+                        ELEMENT_ID => {
+                            builder.element_id(|| map.next_value::<ElementId>().map(|i| i.0))?
+                        }
+                        START_ELEMENT_ID => builder.start_node_element_id(|| {
+                            map.next_value::<StartNodeElementId>().map(|i| i.0)
+                        })?,
+                        END_ELEMENT_ID => builder.end_node_element_id(|| {
+                            map.next_value::<EndNodeElementId>().map(|i| i.0)
+                        })?,
                         otherwise => Err(Error::unknown_field(otherwise, FIELDS))?,
                     }
                 }
@@ -112,6 +136,13 @@ impl<'de> Visitor<'de> for BoltRelationVisitor {
                 ElementDataKey::EndNodeId => builder.end_node_id(|| map.next_value())?,
                 ElementDataKey::Type => builder.typ(|| map.next_value())?,
                 ElementDataKey::Properties => builder.properties(|| map.next_value())?,
+                ElementDataKey::ElementId => builder.element_id(|| map.next_value().map(Some))?,
+                ElementDataKey::StartNodeElementId => {
+                    builder.start_node_element_id(|| map.next_value().map(Some))?
+                }
+                ElementDataKey::EndNodeElementId => {
+                    builder.end_node_element_id(|| map.next_value().map(Some))?
+                }
                 otherwise => {
                     return Err(Error::unknown_field(
                         otherwise.name(),
@@ -258,6 +289,9 @@ mod tests {
             end_node_id,
             properties,
             typ,
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
         }
     }
 
@@ -613,4 +647,26 @@ mod tests {
                 .tap_mut(|v| v.sort_unstable())
         );
     }
+
+    #[test]
+    fn relation_with_element_ids() {
+        let relation = BoltRelation {
+            element_id: Some("5:db:1337".into()),
+            start_node_element_id: Some("4:db:21".into()),
+            end_node_element_id: Some("4:db:84".into()),
+            ..test_relation()
+        };
+
+        assert_eq!(
+            relation.to::<BoltType>().unwrap(),
+            BoltType::Relation(relation.clone())
+        );
+        assert_eq!(relation.to::<BoltRelation>().unwrap(), relation);
+
+        let actual = relation.to::<Relation>().unwrap();
+        assert_eq!(actual.element_id(), Some("5:db:1337"));
+        assert_eq!(actual.start_node_element_id(), Some("4:db:21"));
+        assert_eq!(actual.end_node_element_id(), Some("4:db:84"));
+        assert_eq!(test_relation().to::<Relation>().unwrap().element_id(), None);
+    }
 }
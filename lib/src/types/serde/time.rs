@@ -1,5 +1,9 @@
+use chrono::{Datelike, NaiveTime, Timelike};
 use serde::{
-    de::{value::MapDeserializer, Error, IntoDeserializer, MapAccess, SeqAccess},
+    de::{
+        value::{MapDeserializer, SeqDeserializer},
+        Error, IntoDeserializer, MapAccess, SeqAccess,
+    },
     forward_to_deserialize_any, Deserializer,
 };
 
@@ -32,6 +36,11 @@ impl BoltTime {
             .into_iter(),
         )
     }
+
+    /// Hour, minute, second, and nanosecond of the time, ignoring the offset.
+    pub(crate) fn components_seq_access(&self) -> impl SeqAccess<'_, Error = DeError> {
+        time_components(self.to_chrono().0)
+    }
 }
 
 impl BoltLocalTime {
@@ -45,12 +54,41 @@ impl BoltLocalTime {
     pub(crate) fn map_access(&self) -> impl MapAccess<'_, Error = DeError> {
         MapDeserializer::new([(Fields::NanoSeconds, self.nanoseconds.value)].into_iter())
     }
+
+    /// Hour, minute, second, and nanosecond of the time.
+    pub(crate) fn components_seq_access(&self) -> impl SeqAccess<'_, Error = DeError> {
+        time_components(self.to_chrono())
+    }
 }
 
 impl BoltDate {
     pub(crate) fn map_access(&self) -> impl MapAccess<'_, Error = DeError> {
         MapDeserializer::new([(Fields::Days, self.days.value)].into_iter())
     }
+
+    /// Year and day of the year of the date.
+    pub(crate) fn components_seq_access(
+        &self,
+    ) -> Result<impl SeqAccess<'_, Error = DeError>, DeError> {
+        let date = self
+            .try_to_chrono()
+            .map_err(|_| Error::custom("Could not convert Neo4j Date into a calendar date"))?;
+        Ok(SeqDeserializer::new(
+            [i64::from(date.year()), i64::from(date.ordinal())].into_iter(),
+        ))
+    }
+}
+
+fn time_components(time: NaiveTime) -> SeqDeserializer<std::array::IntoIter<i64, 4>, DeError> {
+    SeqDeserializer::new(
+        [
+            i64::from(time.hour()),
+            i64::from(time.minute()),
+            i64::from(time.second()),
+            i64::from(time.nanosecond()),
+        ]
+        .into_iter(),
+    )
 }
 
 struct BoltTimeSeq {
@@ -384,6 +384,18 @@ impl<'de> Deserializer<'de> for BoltTypeDeserializer<'de> {
             BoltType::Point3D(p) => p.into_deserializer().deserialize_tuple(len, visitor),
             BoltType::Duration(d) if len == 2 => visitor.visit_seq(d.seq_access_external()),
             BoltType::Duration(d) if len == 4 => visitor.visit_seq(d.seq_access_bolt()),
+            BoltType::Date(d) if len == 2 => visitor.visit_seq(d.components_seq_access()?),
+            BoltType::LocalTime(t) if len == 4 => visitor.visit_seq(t.components_seq_access()),
+            BoltType::Time(t) if len == 4 => visitor.visit_seq(t.components_seq_access()),
+            BoltType::LocalDateTime(ldt) if len == 6 => {
+                visitor.visit_seq(ldt.components_seq_access()?)
+            }
+            BoltType::DateTime(dt) if len == 6 || len == 9 => {
+                visitor.visit_seq(dt.components_seq_access(len == 9)?)
+            }
+            BoltType::DateTimeZoneId(dtz) if len == 6 || len == 9 => {
+                visitor.visit_seq(dtz.components_seq_access(len == 9)?)
+            }
             BoltType::DateTimeZoneId(dtz) => visitor.visit_seq(
                 dtz.seq_access(
                     std::any::type_name::<V>()
@@ -412,8 +424,27 @@ impl<'de> Deserializer<'de> for BoltTypeDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+        if let Some(value) = decimal_string(std::any::type_name::<V>(), self.value) {
+            return visitor.visit_string(value?);
+        }
+
         match self.value {
             BoltType::String(v) => visitor.visit_borrowed_str(&v.value),
+            #[cfg(feature = "jiff")]
+            value @ (BoltType::Date(_)
+            | BoltType::Time(_)
+            | BoltType::LocalTime(_)
+            | BoltType::DateTime(_)
+            | BoltType::LocalDateTime(_)
+            | BoltType::DateTimeZoneId(_)
+            | BoltType::Duration(_))
+                if std::any::type_name::<V>().starts_with("<jiff::") =>
+            {
+                let value = crate::convert::jiff::to_jiff_string(value, std::any::type_name::<V>())
+                    .map_err(|_| Error::custom("Could not convert Neo4j value into a jiff type"))?;
+                visitor.visit_string(value)
+            }
             BoltType::Date(_)
             | BoltType::Time(_)
             | BoltType::LocalTime(_)
@@ -660,6 +691,15 @@ impl<'de> Deserializer<'de> for BoltTypeDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+        if let Some(value) = decimal_string(std::any::type_name::<V>(), self.value) {
+            return visitor.visit_string(value?);
+        }
+
+        if let Some(len) = time_tuple_len::<V>() {
+            return self.deserialize_tuple(len, visitor);
+        }
+
         match self.value {
             BoltType::String(_) => self.deserialize_str(visitor),
             BoltType::Boolean(_) => self.deserialize_bool(visitor),
@@ -777,6 +817,44 @@ impl<'de> BoltTypeDeserializer<'de> {
     }
 }
 
+/// Decimal types parse their own string representation, which is produced here
+/// so that strings and floats are checked for loss of precision.
+///
+/// The decimal types are recognized by the type name of their serde visitor,
+/// which is checked against the actual visitors in the tests.
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+fn decimal_string(visitor: &str, value: &BoltType) -> Option<Result<String, DeError>> {
+    match visitor {
+        #[cfg(feature = "rust_decimal")]
+        "rust_decimal::serde::DecimalVisitor" => {
+            crate::convert::rust_decimal::to_decimal_string(value)
+        }
+        #[cfg(feature = "bigdecimal")]
+        "bigdecimal::impl_serde::BigDecimalVisitor" => {
+            crate::convert::bigdecimal::to_decimal_string(value)
+        }
+        _ => None,
+    }
+}
+
+/// The length of the compact tuple that the serde visitor of a type of the `time` crate accepts.
+///
+/// With the `serde-human-readable` feature of the time crate, its types deserialize through
+/// `deserialize_any`, since this deserializer is human readable, and otherwise through
+/// `deserialize_tuple`. Their visitors accept the compact tuple in both cases.
+fn time_tuple_len<'de, V: Visitor<'de>>() -> Option<usize> {
+    let typ = std::any::type_name::<V>()
+        .strip_prefix("time::serde::visitor::Visitor<")?
+        .strip_suffix('>')?;
+    match typ.rsplit("::").next()? {
+        "Date" | "Duration" => Some(2),
+        "Time" => Some(4),
+        "PrimitiveDateTime" | "PlainDateTime" => Some(6),
+        "OffsetDateTime" => Some(9),
+        _ => None,
+    }
+}
+
 impl BoltType {
     fn unexpected<T, E>(&self, expected: &E) -> Result<T, DeError>
     where
@@ -1188,6 +1266,7 @@ mod tests {
             id,
             labels,
             properties,
+            element_id: None,
         };
         let node = BoltType::Node(node);
 
@@ -1232,6 +1311,9 @@ mod tests {
             end_node_id,
             properties,
             typ,
+            element_id: None,
+            start_node_element_id: None,
+            end_node_element_id: None,
         };
         let relation = BoltType::Relation(relation);
 
@@ -1272,6 +1354,7 @@ mod tests {
             id,
             properties,
             typ,
+            element_id: None,
         };
         let relation = BoltType::UnboundedRelation(relation);
 
@@ -2123,6 +2206,235 @@ mod tests {
         );
     }
 
+    #[test]
+    fn time_crate_date() {
+        let bolt = BoltType::Date(BoltDate::from(test_date()));
+
+        let actual = bolt.to::<time::Date>().unwrap();
+        assert_eq!(actual, time::macros::date!(1999 - 07 - 14));
+    }
+
+    #[test]
+    fn time_crate_time() {
+        let expected = time::macros::time!(13:37:42.421337420);
+
+        let bolt = BoltType::LocalTime(BoltLocalTime::from(test_time()));
+        assert_eq!(bolt.to::<time::Time>().unwrap(), expected);
+
+        let bolt = BoltTime::from((test_time(), FixedOffset::east_opt(2 * 3600).unwrap()));
+        let bolt = BoltType::Time(bolt);
+        assert_eq!(bolt.to::<time::Time>().unwrap(), expected);
+    }
+
+    #[test]
+    fn time_crate_primitive_datetime() {
+        let bolt = BoltType::LocalDateTime(BoltLocalDateTime::from(test_local_datetime()));
+
+        let actual = bolt.to::<time::PrimitiveDateTime>().unwrap();
+        assert_eq!(
+            actual,
+            time::macros::datetime!(1999-07-14 13:37:42.421337420)
+        );
+    }
+
+    #[test]
+    fn time_crate_offset_datetime() {
+        let expected = time::macros::datetime!(1999-07-14 13:37:42 +02:00);
+
+        let bolt = BoltType::DateTime(BoltDateTime::from(test_datetime()));
+        assert_eq!(bolt.to::<time::OffsetDateTime>().unwrap(), expected);
+
+        let bolt = BoltDateTimeZoneId::from((test_local_datetime(), "Europe/Paris"));
+        let bolt = BoltType::DateTimeZoneId(bolt);
+        assert_eq!(
+            bolt.to::<time::OffsetDateTime>().unwrap(),
+            time::macros::datetime!(1999-07-14 13:37:42.421337420 +02:00)
+        );
+    }
+
+    #[test]
+    fn time_crate_duration() {
+        let bolt = BoltType::Duration(BoltDuration::new(0.into(), 2.into(), 30.into(), 700.into()));
+
+        let actual = bolt.to::<time::Duration>().unwrap();
+        assert_eq!(
+            actual,
+            time::Duration::days(2)
+                + time::Duration::seconds(30)
+                + time::Duration::nanoseconds(700)
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_civil() {
+        let bolt = BoltType::Date(BoltDate::from(test_date()));
+        assert_eq!(
+            bolt.to::<jiff::civil::Date>().unwrap(),
+            jiff::civil::date(1999, 7, 14)
+        );
+
+        let bolt = BoltType::LocalTime(BoltLocalTime::from(test_time()));
+        assert_eq!(
+            bolt.to::<jiff::civil::Time>().unwrap(),
+            jiff::civil::time(13, 37, 42, 421337420)
+        );
+
+        let bolt = BoltType::LocalDateTime(BoltLocalDateTime::from(test_local_datetime()));
+        assert_eq!(
+            bolt.to::<jiff::civil::DateTime>().unwrap(),
+            jiff::civil::date(1999, 7, 14).at(13, 37, 42, 421337420)
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_zoned() {
+        let bolt = BoltDateTimeZoneId::from((test_local_datetime(), "Europe/Paris"));
+        let bolt = BoltType::DateTimeZoneId(bolt);
+
+        let actual = bolt.to::<jiff::Zoned>().unwrap();
+        assert_eq!(
+            actual.to_string(),
+            "1999-07-14T13:37:42.42133742+02:00[Europe/Paris]"
+        );
+
+        let actual = bolt.to::<jiff::Timestamp>().unwrap();
+        assert_eq!(actual.to_string(), "1999-07-14T11:37:42.42133742Z");
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_span() {
+        let bolt = BoltType::Duration(BoltDuration::new(
+            14.into(),
+            2.into(),
+            30.into(),
+            700.into(),
+        ));
+
+        let actual = bolt.to::<jiff::Span>().unwrap();
+        assert_eq!(
+            actual.fieldwise(),
+            jiff::Span::new()
+                .months(14)
+                .days(2)
+                .seconds(30)
+                .nanoseconds(700)
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_string_is_passed_through() {
+        let bolt = BoltType::from("1999-07-14T13:37:42+02:00[Europe/Paris]");
+        let actual = bolt.to::<jiff::Zoned>().unwrap();
+        assert_eq!(actual.time_zone().iana_name(), Some("Europe/Paris"));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal() {
+        use rust_decimal::Decimal;
+
+        #[derive(Debug, Deserialize)]
+        struct Account {
+            balance: Decimal,
+            rate: Decimal,
+            count: Decimal,
+        }
+
+        let data = [
+            (BoltString::from("balance"), BoltType::from("1234.5600")),
+            (BoltString::from("rate"), BoltType::from(0.1)),
+            (BoltString::from("count"), BoltType::from(42)),
+        ]
+        .into_iter()
+        .collect::<BoltMap>();
+
+        let actual = data.to::<Account>().unwrap();
+        assert_eq!(actual.balance.to_string(), "1234.5600");
+        assert_eq!(actual.rate.to_string(), "0.1");
+        assert_eq!(actual.count, Decimal::from(42));
+
+        let actual = data.get::<Decimal>("balance").unwrap();
+        assert_eq!(actual.to_string(), "1234.5600");
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_precision_loss() {
+        let bolt = BoltType::from("0.00000000000000000000000000001");
+        let err = bolt.to::<rust_decimal::Decimal>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid value: string \"0.00000000000000000000000000001\", \
+             expected a decimal number that fits into a Decimal without loss of precision"
+        );
+
+        let bolt = BoltType::from(1e-30);
+        let err = bolt.to::<rust_decimal::Decimal>().unwrap_err();
+        assert!(matches!(err, DeError::InvalidValue { .. }));
+    }
+
+    /// The type name of the visitor that a type deserializes with.
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    fn visitor_name<'de, T: Deserialize<'de>>() -> String {
+        struct VisitorName;
+
+        impl<'de> Deserializer<'de> for VisitorName {
+            type Error = serde::de::value::Error;
+
+            fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+                Err(Error::custom(std::any::type_name::<V>()))
+            }
+
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+                bytes byte_buf option unit unit_struct newtype_struct seq tuple
+                tuple_struct map struct enum identifier ignored_any
+            }
+        }
+
+        match T::deserialize(VisitorName) {
+            Ok(_) => unreachable!("the visitor is never called"),
+            Err(name) => name.to_string(),
+        }
+    }
+
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    #[test]
+    fn decimal_visitors_are_recognized() {
+        let value = BoltType::from(0.1);
+        #[cfg(feature = "rust_decimal")]
+        assert!(decimal_string(&visitor_name::<rust_decimal::Decimal>(), &value).is_some());
+        #[cfg(feature = "bigdecimal")]
+        assert!(decimal_string(&visitor_name::<bigdecimal::BigDecimal>(), &value).is_some());
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn bigdecimal() {
+        use bigdecimal::BigDecimal;
+        use std::str::FromStr;
+
+        let digits = "3.14159265358979323846264338327950288419716939937510";
+        let bolt = BoltType::from(digits);
+        assert_eq!(
+            bolt.to::<BigDecimal>().unwrap(),
+            BigDecimal::from_str(digits).unwrap()
+        );
+
+        let bolt = BoltType::from(0.1);
+        assert_eq!(
+            bolt.to::<BigDecimal>().unwrap(),
+            BigDecimal::from_str("0.1").unwrap()
+        );
+
+        let bolt = BoltType::from(f64::NAN);
+        assert!(bolt.to::<BigDecimal>().is_err());
+    }
+
     #[test]
     fn type_convert() {
         let i = BoltType::from(42);
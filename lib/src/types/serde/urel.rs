@@ -1,7 +1,7 @@
 use crate::{
     types::{
         serde::{
-            builder::{BoltUnboundedRelationBuilder, Id},
+            builder::{BoltUnboundedRelationBuilder, ElementId, Id},
             element::{ElementDataDeserializer, ElementDataKey},
             BoltKind, Properties,
         },
@@ -46,8 +46,9 @@ impl<'de> Deserialize<'de> for BoltUnboundedRelation {
         const ID: &str = "42.<id>";
         const TYP: &str = "42.<type>";
         const PROPS: &str = "42.<properties>";
+        const ELEMENT_ID: &str = "42.<element_id>";
 
-        const FIELDS: &[&str] = &[ID, TYP, PROPS];
+        const FIELDS: &[&str] = &[ID, TYP, PROPS, ELEMENT_ID];
 
         struct BoltUnboundedRelationVisitor;
 
@@ -70,6 +71,9 @@ impl<'de> Deserialize<'de> for BoltUnboundedRelation {
                         TYP => builder.typ(|| map.next_value::<Type<BoltString>>().map(|t| t.0))?,
                         PROPS => builder
                             .properties(|| map.next_value::<Properties<BoltMap>>().map(|o| o.0))?,
+                        ELEMENT_ID => {
+                            builder.element_id(|| map.next_value::<ElementId>().map(|i| i.0))?
+                        }
                         otherwise => Err(Error::unknown_field(otherwise, FIELDS))?,
                     }
                 }
@@ -107,6 +111,7 @@ impl<'de> Visitor<'de> for BoltUnboundedRelationVisitor {
                 ElementDataKey::Id => builder.id(|| map.next_value())?,
                 ElementDataKey::Type => builder.typ(|| map.next_value())?,
                 ElementDataKey::Properties => builder.properties(|| map.next_value())?,
+                ElementDataKey::ElementId => builder.element_id(|| map.next_value().map(Some))?,
                 otherwise => {
                     return Err(Error::unknown_field(
                         otherwise.name(),
@@ -249,6 +254,7 @@ mod tests {
             id,
             properties,
             typ,
+            element_id: None,
         }
     }
 
@@ -543,4 +549,22 @@ mod tests {
                 .tap_mut(|v| v.sort_unstable())
         );
     }
+
+    #[test]
+    fn unbounded_relation_with_element_id() {
+        let relation = BoltUnboundedRelation {
+            element_id: Some("5:db:1337".into()),
+            ..test_unbounded_relation()
+        };
+
+        assert_eq!(
+            relation.to::<BoltType>().unwrap(),
+            BoltType::UnboundedRelation(relation.clone())
+        );
+        assert_eq!(relation.to::<BoltUnboundedRelation>().unwrap(), relation);
+        assert_eq!(
+            relation.to::<UnboundedRelation>().unwrap().element_id(),
+            Some("5:db:1337")
+        );
+    }
 }
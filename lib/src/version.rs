@@ -10,19 +10,52 @@ pub enum Version {
     V4_1,
     V4_3,
     V4_4,
+    V5_0,
+    V5_1,
+    V5_2,
+    V5_3,
+    V5_4,
+    V5_5,
+    V5_6,
+    V5_7,
 }
 
 impl Version {
     pub fn add_supported_versions(bytes: &mut BytesMut) {
         bytes.reserve(16);
-        bytes.put_u32(0x0404); // V4_4
-        bytes.put_u32(0x0304); // V4_3
+        bytes.put_u32(0x070705); // V5_7 down to V5_0
+        bytes.put_u32(0x010404); // V4_4 down to V4_3
         bytes.put_u32(0x0104); // V4_1
         bytes.put_u32(0x0004); // V4
     }
 
+    pub(crate) fn major_minor(self) -> (u8, u8) {
+        match self {
+            Version::V4 => (4, 0),
+            Version::V4_1 => (4, 1),
+            Version::V4_3 => (4, 3),
+            Version::V4_4 => (4, 4),
+            Version::V5_0 => (5, 0),
+            Version::V5_1 => (5, 1),
+            Version::V5_2 => (5, 2),
+            Version::V5_3 => (5, 3),
+            Version::V5_4 => (5, 4),
+            Version::V5_5 => (5, 5),
+            Version::V5_6 => (5, 6),
+            Version::V5_7 => (5, 7),
+        }
+    }
+
     pub fn parse(version_bytes: [u8; 4]) -> Result<Version> {
         match version_bytes {
+            [0, 0, 7, 5] => Ok(Version::V5_7),
+            [0, 0, 6, 5] => Ok(Version::V5_6),
+            [0, 0, 5, 5] => Ok(Version::V5_5),
+            [0, 0, 4, 5] => Ok(Version::V5_4),
+            [0, 0, 3, 5] => Ok(Version::V5_3),
+            [0, 0, 2, 5] => Ok(Version::V5_2),
+            [0, 0, 1, 5] => Ok(Version::V5_1),
+            [0, 0, 0, 5] => Ok(Version::V5_0),
             [0, 0, 4, 4] => Ok(Version::V4_4),
             [0, 0, 3, 4] => Ok(Version::V4_3),
             [0, 0, 1, 4] => Ok(Version::V4_1),
@@ -35,12 +68,8 @@ impl Version {
 
 impl Display for Version {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Version::V4 => write!(f, "4.0"),
-            Version::V4_1 => write!(f, "4.1"),
-            Version::V4_3 => write!(f, "4.3"),
-            Version::V4_4 => write!(f, "4.4"),
-        }
+        let (major, minor) = self.major_minor();
+        write!(f, "{major}.{minor}")
     }
 }
 
@@ -54,5 +83,22 @@ mod tests {
         assert_eq!(Version::parse([0, 0, 3, 4]).unwrap(), Version::V4_3);
         assert_eq!(Version::parse([0, 0, 1, 4]).unwrap(), Version::V4_1);
         assert_eq!(Version::parse([0, 0, 0, 4]).unwrap(), Version::V4);
+        assert_eq!(Version::parse([0, 0, 0, 5]).unwrap(), Version::V5_0);
+        assert_eq!(Version::parse([0, 0, 2, 5]).unwrap(), Version::V5_2);
+        assert_eq!(Version::parse([0, 0, 7, 5]).unwrap(), Version::V5_7);
+        assert!(matches!(
+            Version::parse([0, 0, 8, 5]),
+            Err(Error::UnsupportedVersion(5, 8))
+        ));
+    }
+
+    #[test]
+    fn should_offer_version_ranges() {
+        let mut bytes = BytesMut::new();
+        Version::add_supported_versions(&mut bytes);
+        assert_eq!(
+            &bytes[..],
+            &[0, 7, 7, 5, 0, 1, 4, 4, 0, 0, 1, 4, 0, 0, 0, 4]
+        );
     }
 }
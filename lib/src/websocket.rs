@@ -0,0 +1,449 @@
+//! A minimal WebSocket client (RFC 6455) that carries Bolt in binary messages.
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use bytes::{Buf, BufMut, BytesMut};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::{
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+
+const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+const MAX_HANDSHAKE_SIZE: usize = 8 * 1024;
+/// The largest frame that is accepted, the server splits larger messages into several frames.
+const MAX_FRAME_SIZE: u64 = 4 * 1024 * 1024;
+/// The largest payload of a control frame, as defined by RFC 6455.
+const MAX_CONTROL_FRAME_SIZE: u64 = 125;
+
+const CONTINUATION: u8 = 0x0;
+const TEXT: u8 = 0x1;
+const BINARY: u8 = 0x2;
+const CLOSE: u8 = 0x8;
+const PING: u8 = 0x9;
+const PONG: u8 = 0xA;
+
+/// A stream that sends every write as a binary WebSocket message
+/// and reads the payload of the messages that it receives.
+#[derive(Debug)]
+pub(crate) struct WebSocketStream<S> {
+    inner: S,
+    /// Bytes that have been read from the inner stream but not decoded yet.
+    read_buf: BytesMut,
+    /// Decoded payload that has not been returned to the reader yet.
+    payload: BytesMut,
+    /// An encoded frame that has not been written to the inner stream yet.
+    write_buf: BytesMut,
+    closed: bool,
+    close_sent: bool,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> WebSocketStream<S> {
+    /// Upgrades the given stream to a WebSocket with the HTTP handshake.
+    pub(crate) async fn connect(mut inner: S, host: &str) -> io::Result<Self> {
+        let mut key = [0; 16];
+        fill_random(&mut key)?;
+        let key = STANDARD.encode(key);
+        let request = format!(
+            "GET / HTTP/1.1\r\n\
+             Host: {host}\r\n\
+             Upgrade: websocket\r\n\
+             Connection: Upgrade\r\n\
+             Sec-WebSocket-Key: {key}\r\n\
+             Sec-WebSocket-Version: 13\r\n\r\n"
+        );
+        inner.write_all(request.as_bytes()).await?;
+        inner.flush().await?;
+
+        let mut response = BytesMut::new();
+        let end = loop {
+            if let Some(end) = response.windows(4).position(|w| w == b"\r\n\r\n") {
+                break end + 4;
+            }
+            if response.len() > MAX_HANDSHAKE_SIZE {
+                return Err(handshake_error("the response is too large"));
+            }
+            if inner.read_buf(&mut response).await? == 0 {
+                return Err(handshake_error("the connection was closed"));
+            }
+        };
+        let read_buf = response.split_off(end);
+        let response = String::from_utf8_lossy(&response);
+        let mut lines = response.split("\r\n");
+
+        let status = lines.next().unwrap_or_default();
+        if status.split(' ').nth(1) != Some("101") {
+            return Err(handshake_error(&format!("unexpected response {status:?}")));
+        }
+        let accept = lines
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("sec-websocket-accept"))
+            .map(|(_, value)| value.trim());
+        let expected = ring::digest::digest(
+            &ring::digest::SHA1_FOR_LEGACY_USE_ONLY,
+            format!("{key}{GUID}").as_bytes(),
+        );
+        if accept != Some(&STANDARD.encode(expected)) {
+            return Err(handshake_error("invalid Sec-WebSocket-Accept header"));
+        }
+
+        Ok(WebSocketStream {
+            inner,
+            read_buf,
+            payload: BytesMut::new(),
+            write_buf: BytesMut::new(),
+            closed: false,
+            close_sent: false,
+        })
+    }
+
+    /// Decodes the next complete frame of the read buffer, if there is one.
+    fn decode_frame(&mut self) -> io::Result<bool> {
+        let buf = &self.read_buf[..];
+        if buf.len() < 2 {
+            return Ok(false);
+        }
+        let opcode = buf[0] & 0x0F;
+        let masked = buf[1] & 0x80 != 0;
+        let (len, mut header) = match buf[1] & 0x7F {
+            126 if buf.len() >= 4 => (u16::from_be_bytes([buf[2], buf[3]]) as u64, 4),
+            127 if buf.len() >= 10 => (u64::from_be_bytes(buf[2..10].try_into().unwrap()), 10),
+            126 | 127 => return Ok(false),
+            len => (len as u64, 2),
+        };
+        let mask = if masked {
+            header += 4;
+            buf.get(header - 4..header)
+                .map(|mask| [mask[0], mask[1], mask[2], mask[3]])
+        } else {
+            None
+        };
+        if len > MAX_FRAME_SIZE {
+            return Err(frame_error("frame is too large"));
+        }
+        if opcode & CLOSE != 0 && len > MAX_CONTROL_FRAME_SIZE {
+            return Err(frame_error("control frame is too large"));
+        }
+        let len = usize::try_from(len).map_err(|_| frame_error("frame is too large"))?;
+        let size = header
+            .checked_add(len)
+            .ok_or_else(|| frame_error("frame is too large"))?;
+        if buf.len() < size || (masked && mask.is_none()) {
+            return Ok(false);
+        }
+
+        self.read_buf.advance(header);
+        let mut payload = self.read_buf.split_to(len);
+        if let Some(mask) = mask {
+            apply_mask(&mut payload, mask);
+        }
+        match opcode {
+            CONTINUATION | BINARY | TEXT => self.payload.unsplit(payload),
+            CLOSE => self.closed = true,
+            PING => self.write_buf.extend(encode_frame(PONG, &payload)?),
+            PONG => {}
+            opcode => return Err(frame_error(&format!("unknown opcode {opcode:#X}"))),
+        }
+        Ok(true)
+    }
+
+    fn poll_write_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while !self.write_buf.is_empty() {
+            let written = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.write_buf))?;
+            if written == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.write_buf.advance(written);
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Writes pending frames, such as the PONG for a PING, while the stream is read.
+    /// The read does not wait until the inner stream can be written to,
+    /// the frames are written by a later read, write or flush in that case.
+    fn write_pending_while_reading(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        if self.write_buf.is_empty() {
+            return Ok(());
+        }
+        if let Poll::Ready(written) = self.poll_write_pending(cx) {
+            written?;
+            if let Poll::Ready(flushed) = Pin::new(&mut self.inner).poll_flush(cx) {
+                flushed?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncRead for WebSocketStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            this.write_pending_while_reading(cx)?;
+            if !this.payload.is_empty() {
+                let len = this.payload.len().min(buf.remaining());
+                buf.put_slice(&this.payload.split_to(len));
+                return Poll::Ready(Ok(()));
+            }
+            if this.closed {
+                return Poll::Ready(Ok(()));
+            }
+            if this.decode_frame()? {
+                continue;
+            }
+
+            let mut chunk = [0; 8 * 1024];
+            let mut chunk_buf = ReadBuf::new(&mut chunk);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk_buf))?;
+            if chunk_buf.filled().is_empty() {
+                if this.read_buf.is_empty() {
+                    return Poll::Ready(Ok(()));
+                }
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+            this.read_buf.extend_from_slice(chunk_buf.filled());
+        }
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for WebSocketStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_pending(cx))?;
+        this.write_buf = encode_frame(BINARY, buf)?;
+        // the frame is written by the next write or flush
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_pending(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_pending(cx))?;
+        if !this.close_sent {
+            this.close_sent = true;
+            this.write_buf = encode_frame(CLOSE, &[])?;
+            ready!(this.poll_write_pending(cx))?;
+        }
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+/// Encodes a single final frame, masked as required for frames that are sent by a client.
+fn encode_frame(opcode: u8, payload: &[u8]) -> io::Result<BytesMut> {
+    let mut frame = BytesMut::with_capacity(payload.len() + 14);
+    frame.put_u8(0x80 | opcode);
+    match payload.len() {
+        len @ 0..=125 => frame.put_u8(0x80 | len as u8),
+        len @ 126..=0xFFFF => {
+            frame.put_u8(0x80 | 126);
+            frame.put_u16(len as u16);
+        }
+        len => {
+            frame.put_u8(0x80 | 127);
+            frame.put_u64(len as u64);
+        }
+    }
+    let mut mask = [0; 4];
+    fill_random(&mut mask)?;
+    frame.put_slice(&mask);
+    let start = frame.len();
+    frame.put_slice(payload);
+    apply_mask(&mut frame[start..], mask);
+    Ok(frame)
+}
+
+fn apply_mask(payload: &mut [u8], mask: [u8; 4]) {
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+}
+
+fn fill_random(bytes: &mut [u8]) -> io::Result<()> {
+    SystemRandom::new()
+        .fill(bytes)
+        .map_err(|_| io::Error::other("failed to generate random bytes"))
+}
+
+fn handshake_error(reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("WebSocket handshake failed: {reason}"),
+    )
+}
+
+fn frame_error(reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid WebSocket frame: {reason}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::duplex;
+
+    /// Accepts the handshake on the server side of the stream.
+    async fn accept<S: AsyncRead + AsyncWrite + Unpin>(server: &mut S) {
+        let mut request = BytesMut::new();
+        while !request.ends_with(b"\r\n\r\n") {
+            server.read_buf(&mut request).await.unwrap();
+        }
+        let request = String::from_utf8(request.to_vec()).unwrap();
+        let key = request
+            .lines()
+            .find_map(|line| line.strip_prefix("Sec-WebSocket-Key: "))
+            .unwrap();
+        let accept = ring::digest::digest(
+            &ring::digest::SHA1_FOR_LEGACY_USE_ONLY,
+            format!("{key}{GUID}").as_bytes(),
+        );
+        let response = format!(
+            "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\
+             Connection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+            STANDARD.encode(accept)
+        );
+        server.write_all(response.as_bytes()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_exchange_binary_messages() {
+        let (client, mut server) = duplex(1024);
+        let server = tokio::spawn(async move {
+            accept(&mut server).await;
+
+            // a masked binary frame from the client
+            let mut frame = [0; 9];
+            server.read_exact(&mut frame).await.unwrap();
+            assert_eq!(frame[0], 0x80 | BINARY);
+            assert_eq!(frame[1], 0x80 | 3);
+            let mut payload = frame[6..].to_vec();
+            apply_mask(&mut payload, frame[2..6].try_into().unwrap());
+            assert_eq!(payload, [1, 2, 3]);
+
+            // a ping, and a message that is split into a fragment and a continuation
+            server.write_all(&[0x80 | PING, 0]).await.unwrap();
+            server.write_all(&[BINARY, 2, 4, 5]).await.unwrap();
+            server
+                .write_all(&[0x80 | CONTINUATION, 1, 6])
+                .await
+                .unwrap();
+            server
+        });
+
+        let mut client = WebSocketStream::connect(client, "localhost:7687")
+            .await
+            .unwrap();
+        client.write_all(&[1, 2, 3]).await.unwrap();
+        client.flush().await.unwrap();
+
+        let mut payload = [0; 3];
+        client.read_exact(&mut payload).await.unwrap();
+        assert_eq!(payload, [4, 5, 6]);
+
+        // the pong is sent while the client reads
+        let mut server = server.await.unwrap();
+        let mut pong = [0; 6];
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            server.read_exact(&mut pong),
+        )
+        .await
+        .expect("the pong has not been sent")
+        .unwrap();
+        assert_eq!(pong[..2], [0x80 | PONG, 0x80]);
+    }
+
+    #[test]
+    fn should_reject_oversized_frames() {
+        let decode = |frame: &[u8]| {
+            let mut stream = WebSocketStream {
+                inner: duplex(1).0,
+                read_buf: BytesMut::from(frame),
+                payload: BytesMut::new(),
+                write_buf: BytesMut::new(),
+                closed: false,
+                close_sent: false,
+            };
+            stream.decode_frame()
+        };
+
+        let mut frame = vec![0x80 | BINARY, 127];
+        frame.extend(u64::MAX.to_be_bytes());
+        assert!(decode(&frame).is_err());
+
+        let mut frame = vec![0x80 | BINARY, 127];
+        frame.extend((MAX_FRAME_SIZE + 1).to_be_bytes());
+        assert!(decode(&frame).is_err());
+
+        // an incomplete frame of the maximum size is awaited
+        let mut frame = vec![0x80 | BINARY, 127];
+        frame.extend(MAX_FRAME_SIZE.to_be_bytes());
+        assert!(!decode(&frame).unwrap());
+
+        let mut frame = vec![0x80 | PING, 126];
+        frame.extend(126_u16.to_be_bytes());
+        assert!(decode(&frame).is_err());
+    }
+
+    #[tokio::test]
+    async fn should_connect_with_websocket_uri() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            accept(&mut stream).await;
+            // the Bolt handshake in a masked frame
+            let mut frame = [0; 26];
+            stream.read_exact(&mut frame).await.unwrap();
+            assert_eq!(frame[1], 0x80 | 20);
+            stream
+                .write_all(&[0x80 | BINARY, 4, 0, 0, 4, 4])
+                .await
+                .unwrap();
+        });
+
+        let config = crate::ConfigBuilder::default()
+            .uri(format!("bolt+ws://{address}"))
+            .user("neo4j")
+            .password("neo")
+            .build()
+            .unwrap();
+        let info = crate::connection::ConnectionInfo::new(&config).unwrap();
+        let connection = crate::connection::Connection::prepare(&info.prepare)
+            .await
+            .unwrap();
+        assert_eq!(connection.version(), crate::Version::V4_4);
+    }
+
+    #[tokio::test]
+    async fn should_fail_without_upgrade() {
+        let (client, mut server) = duplex(1024);
+        tokio::spawn(async move {
+            let mut request = [0; 1];
+            server.read_exact(&mut request).await.unwrap();
+            server
+                .write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let error = WebSocketStream::connect(client, "localhost:7687")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("400 Bad Request"), "{error}");
+    }
+}
@@ -4,6 +4,8 @@ use syn::punctuated::Punctuated;
 use syn::DeriveInput;
 use syn::{parse_macro_input, Attribute, LitInt, Token};
 
+mod to_params;
+
 #[proc_macro_derive(BoltStruct, attributes(signature))]
 pub fn derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -13,6 +15,27 @@ pub fn derive(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derives `From<T> for BoltMap` and `From<T> for BoltType` for a struct with named fields,
+/// so that it can be passed as a single map parameter to a query.
+///
+/// Every field must implement `Into<BoltType>`, and is stored under its name.
+///
+/// The following attributes are supported:
+///
+/// - `#[param(rename = "name")]` on a field uses `name` as the key in the map.
+/// - `#[param(skip)]` on a field leaves it out of the map.
+/// - `#[param(skip_none)]` on an `Option` field leaves it out of the map when it is `None`,
+///   instead of adding a `null` value.
+/// - `#[param(skip_none)]` on the struct applies `skip_none` to all `Option` fields.
+#[proc_macro_derive(ToParams, attributes(param))]
+pub fn derive_to_params(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    match to_params::derive_impl(ast) {
+        Ok(data) => data,
+        Err(err) => TokenStream::from(err.into_compile_error()),
+    }
+}
+
 fn derive_impl(ast: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &ast.ident;
 
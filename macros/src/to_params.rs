@@ -0,0 +1,183 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, Attribute, DeriveInput, Field, GenericArgument, LitStr,
+    PathArguments, Token, Type,
+};
+
+pub(crate) fn derive_impl(ast: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+
+    let container = ParamAttrs::from_attrs(&ast.attrs)?;
+    if let Some(rename) = container.rename {
+        return Err(syn::Error::new_spanned(
+            rename,
+            "`rename` is only supported on fields",
+        ));
+    }
+    if container.skip {
+        return Err(syn::Error::new_spanned(
+            &ast,
+            "`skip` is only supported on fields",
+        ));
+    }
+
+    let fields = match &ast.data {
+        syn::Data::Struct(structure) => match &structure.fields {
+            syn::Fields::Named(syn::FieldsNamed { named, .. }) => named.iter().collect(),
+            syn::Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    &ast,
+                    "ToParams: unnamed fields not supported",
+                ))
+            }
+            syn::Fields::Unit => Vec::new(),
+        },
+        _ => return Err(syn::Error::new_spanned(&ast, "ToParams: not a struct")),
+    };
+
+    let mut puts = Vec::with_capacity(fields.len());
+    let mut bounds = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let attrs = ParamAttrs::from_attrs(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("named fields always have an identifier");
+        let key = attrs
+            .rename
+            .map(|name| name.value())
+            .unwrap_or_else(|| ident.unraw().to_string());
+
+        let skip_none = attrs.skip_none || (container.skip_none && option_type(field).is_some());
+
+        if skip_none {
+            let inner = option_type(field).ok_or_else(|| {
+                syn::Error::new_spanned(&field.ty, "`skip_none` requires an `Option` field")
+            })?;
+            bounds.push(quote! { #inner: ::std::convert::Into<::neo4rs::BoltType> });
+            puts.push(quote! {
+                if let ::std::option::Option::Some(value) = value.#ident {
+                    map.put(
+                        ::neo4rs::BoltString::from(#key),
+                        ::std::convert::Into::<::neo4rs::BoltType>::into(value),
+                    );
+                }
+            });
+        } else {
+            let ty = &field.ty;
+            bounds.push(quote! { #ty: ::std::convert::Into<::neo4rs::BoltType> });
+            puts.push(quote! {
+                map.put(
+                    ::neo4rs::BoltString::from(#key),
+                    ::std::convert::Into::<::neo4rs::BoltType>::into(value.#ident),
+                );
+            });
+        }
+    }
+
+    let capacity = puts.len();
+    if ast.generics.params.is_empty() {
+        bounds.clear();
+    }
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let predicates = where_clause.map(|w| &w.predicates);
+
+    let expanded = quote! {
+        impl #impl_generics ::std::convert::From<#struct_name #ty_generics> for ::neo4rs::BoltMap
+        where
+            #(#bounds,)*
+            #predicates
+        {
+            fn from(value: #struct_name #ty_generics) -> Self {
+                let mut map = ::neo4rs::BoltMap::with_capacity(#capacity);
+                #(#puts)*
+                map
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#struct_name #ty_generics> for ::neo4rs::BoltType
+        where
+            #(#bounds,)*
+            #predicates
+        {
+            fn from(value: #struct_name #ty_generics) -> Self {
+                ::neo4rs::BoltType::Map(::std::convert::From::from(value))
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
+
+#[derive(Default)]
+struct ParamAttrs {
+    rename: Option<LitStr>,
+    skip: bool,
+    skip_none: bool,
+}
+
+impl ParamAttrs {
+    fn from_attrs(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("param")) {
+            let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            for meta in nested {
+                match meta {
+                    Meta::Rename(name) => result.rename = Some(name),
+                    Meta::Skip => result.skip = true,
+                    Meta::SkipNone => result.skip_none = true,
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+enum Meta {
+    Rename(LitStr),
+    Skip,
+    SkipNone,
+}
+
+impl syn::parse::Parse for Meta {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name: syn::Ident = input.parse()?;
+        if name == "rename" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Rename(input.parse()?))
+        } else if name == "skip" {
+            Ok(Self::Skip)
+        } else if name == "skip_none" {
+            Ok(Self::SkipNone)
+        } else {
+            Err(syn::Error::new_spanned(
+                name,
+                "Unknown attribute, expected one of `rename = \"...\"`, `skip`, or `skip_none`",
+            ))
+        }
+    }
+}
+
+/// Returns `T` if the field is declared as `Option<T>`.
+fn option_type(field: &Field) -> Option<&Type> {
+    let segment = match &field.ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}